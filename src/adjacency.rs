@@ -0,0 +1,64 @@
+//! A minimal read-only view of the adjacency of a directed graph, so that algorithms run on both a [`SlotGraph`] and
+//! its [`TransposeView`].
+
+use slotmap::Key;
+
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+use crate::transpose::TransposeView;
+
+/// The nodes of a directed graph and the edges pointing from and to each of them, implemented by [`SlotGraph`] and
+/// [`TransposeView`].
+///
+/// Algorithms generic over this trait, such as [`dominators`](crate::algo::dominators), run on a reversed graph
+/// through its view without copying it.
+pub trait Adjacency<K: Key> {
+    /// An iterator over the keys of all nodes in arbitrary order.
+    fn node_keys(&self) -> impl Iterator<Item = NodeKey<K>> + '_;
+
+    /// Returns whether the node is in the graph.
+    fn contains_node(&self, key: NodeKey<K>) -> bool;
+
+    /// The edges pointing from the node and the nodes they point to, in insertion order, empty if the node key is
+    /// stale.
+    fn outgoing(&self, key: NodeKey<K>) -> &[(EdgeKey<K>, NodeKey<K>)];
+
+    /// The edges pointing to the node and the nodes they point from, in insertion order, empty if the node key is
+    /// stale.
+    fn incoming(&self, key: NodeKey<K>) -> &[(EdgeKey<K>, NodeKey<K>)];
+}
+
+impl<K: Key, N, E> Adjacency<K> for SlotGraph<K, N, E> {
+    fn node_keys(&self) -> impl Iterator<Item = NodeKey<K>> + '_ {
+        SlotGraph::node_keys(self)
+    }
+
+    fn contains_node(&self, key: NodeKey<K>) -> bool {
+        self.get_node(key).is_some()
+    }
+
+    fn outgoing(&self, key: NodeKey<K>) -> &[(EdgeKey<K>, NodeKey<K>)] {
+        SlotGraph::outgoing(self, key)
+    }
+
+    fn incoming(&self, key: NodeKey<K>) -> &[(EdgeKey<K>, NodeKey<K>)] {
+        SlotGraph::incoming(self, key)
+    }
+}
+
+impl<K: Key, N, E> Adjacency<K> for TransposeView<'_, K, N, E> {
+    fn node_keys(&self) -> impl Iterator<Item = NodeKey<K>> + '_ {
+        self.graph().node_keys()
+    }
+
+    fn contains_node(&self, key: NodeKey<K>) -> bool {
+        self.graph().get_node(key).is_some()
+    }
+
+    fn outgoing(&self, key: NodeKey<K>) -> &[(EdgeKey<K>, NodeKey<K>)] {
+        self.graph().incoming(key)
+    }
+
+    fn incoming(&self, key: NodeKey<K>) -> &[(EdgeKey<K>, NodeKey<K>)] {
+        self.graph().outgoing(key)
+    }
+}
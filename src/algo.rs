@@ -0,0 +1,46 @@
+//! Graph algorithms on [`SlotGraph`](crate::SlotGraph), as methods, free functions and traversal iterators, and the types
+//! they return.
+
+mod bipartite;
+mod centrality;
+mod classify;
+mod closure;
+mod communities;
+mod components;
+mod connectivity;
+mod cycles;
+mod dominators;
+mod euler;
+mod flow;
+mod isomorphism;
+mod loops;
+mod ordering;
+mod paths;
+#[cfg(feature = "rand")]
+mod random_walk;
+mod reachability;
+mod scc;
+mod shortest_paths;
+mod simplify;
+mod spanning_tree;
+mod traversal;
+mod union_find;
+
+pub use bipartite::{Color, Matching};
+pub use centrality::{Betweenness, Hits};
+pub use classify::{GraphClass, GraphStats};
+pub use closure::{transitive_closure, TransitiveClosure};
+pub use communities::Communities;
+pub use dominators::{dominance_frontiers, dominators, post_dominators};
+pub use euler::eulerian_path;
+pub use flow::{max_flow, min_cost_flow, MinCostFlow};
+pub use isomorphism::{
+    is_isomorphic, is_isomorphic_keyed, is_isomorphic_matching, subgraph_isomorphisms,
+    subgraph_isomorphisms_matching,
+};
+pub use loops::{natural_loops, NaturalLoop};
+pub use ordering::{toposort, Cycle};
+pub use scc::{condensation, kosaraju_scc, tarjan_scc, tarjan_scc_ids};
+pub use shortest_paths::{astar, bellman_ford, dijkstra, NegativeCycle, ShortestPaths};
+pub use spanning_tree::{min_spanning_tree, min_spanning_tree_prim};
+pub use traversal::{Bfs, Dfs, DfsPostOrder};
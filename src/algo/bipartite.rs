@@ -0,0 +1,240 @@
+use std::collections::VecDeque;
+
+use slotmap::{Key, SecondaryMap};
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// One of the two sides of a bipartite graph, as assigned by [`SlotGraph::is_bipartite`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Color {
+    /// The side of the first node of every connected component.
+    Red,
+    /// The other side.
+    Blue,
+}
+
+impl Color {
+    /// Returns the other color.
+    pub fn other(self) -> Self {
+        match self {
+            Self::Red => Self::Blue,
+            Self::Blue => Self::Red,
+        }
+    }
+}
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Colors the nodes so that every edge connects a red node to a blue node, ignoring edge directions, if possible.
+    ///
+    /// The first node of every connected component in node iteration order is red.
+    ///
+    /// # Errors
+    ///
+    /// Returns the nodes along a cycle with an odd number of edges, which makes such a coloring impossible, in order.
+    /// A self-loop is a cycle of a single node.
+    pub fn is_bipartite(&self) -> Result<SecondaryMap<NodeKey<K>, Color>, Vec<NodeKey<K>>> {
+        let mut colors = SecondaryMap::with_capacity(self.node_len());
+        // the node every node was discovered from, leading back to the first node of its component
+        let mut parents = SecondaryMap::with_capacity(self.node_len());
+        let mut queue = VecDeque::new();
+        for (root, _) in self.iter_nodes() {
+            if colors.contains_key(root) {
+                continue;
+            }
+            colors.insert(root, Color::Red);
+            queue.push_back(root);
+            while let Some(nk) = queue.pop_front() {
+                let color = colors[nk];
+                for next in self.neighbors(nk) {
+                    match colors.get(next) {
+                        None => {
+                            colors.insert(next, color.other());
+                            parents.insert(next, nk);
+                            queue.push_back(next);
+                        }
+                        Some(&c) if c == color => return Err(odd_cycle(&parents, nk, next)),
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+        Ok(colors)
+    }
+}
+
+/// An edge and the node at its other end.
+type Mate<K> = (EdgeKey<K>, NodeKey<K>);
+
+/// A set of edges of a graph no two of which share a node, as computed by [`SlotGraph::maximum_matching`].
+#[derive(Clone, Debug)]
+pub struct Matching<K: Key> {
+    /// The matched edge and the node at its other end for every matched node, and `None` for every unmatched one.
+    mates: SecondaryMap<NodeKey<K>, Option<Mate<K>>>,
+    len: usize,
+}
+
+impl<K: Key> Matching<K> {
+    /// Returns the number of matched edges.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no edges are matched.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// An iterator over the matched edges, in arbitrary order.
+    pub fn edges(&self) -> impl Iterator<Item = EdgeKey<K>> + '_ {
+        // every matched edge is listed at both of its nodes, so it is only yielded from the lesser one
+        self.mates
+            .iter()
+            .filter_map(|(nk, &mate)| mate.filter(|&(_, other)| nk < other).map(|(ek, _)| ek))
+    }
+
+    /// Returns the node matched to the given node, or `None` if it is unmatched or was not matched over.
+    pub fn mate(&self, key: NodeKey<K>) -> Option<NodeKey<K>> {
+        self.mates.get(key)?.map(|(_, other)| other)
+    }
+
+    /// An iterator over the nodes matched over that are not matched, in arbitrary order.
+    pub fn unmatched(&self) -> impl Iterator<Item = NodeKey<K>> + '_ {
+        self.mates
+            .iter()
+            .filter(|(_, mate)| mate.is_none())
+            .map(|(nk, _)| nk)
+    }
+}
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Computes a maximum matching of a bipartite graph, with the bipartition found by [`SlotGraph::is_bipartite`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an odd cycle if the graph is not bipartite, like [`SlotGraph::is_bipartite`].
+    pub fn maximum_matching(&self) -> Result<Matching<K>, Vec<NodeKey<K>>> {
+        let colors = self.is_bipartite()?;
+        Ok(self.maximum_matching_with(&colors))
+    }
+
+    /// Computes a matching with as many edges as possible between red and blue nodes of the given bipartition, with
+    /// the Hopcroft-Karp algorithm, ignoring edge directions.
+    ///
+    /// Edges between nodes of the same color and nodes without a color are ignored.
+    pub fn maximum_matching_with(&self, colors: &SecondaryMap<NodeKey<K>, Color>) -> Matching<K> {
+        let is_blue = |nk| colors.get(nk) == Some(&Color::Blue);
+        let adjacent: SecondaryMap<NodeKey<K>, Vec<Mate<K>>> = colors
+            .iter()
+            .filter(|&(nk, &c)| c == Color::Red && self.get_node(nk).is_some())
+            .map(|(nk, _)| {
+                let blue = self
+                    .edges_of(nk)
+                    .map(|ek| {
+                        let (from, to) = self.get_edge_nodes(ek).expect("adjacent edges are valid");
+                        (ek, if from == nk { to } else { from })
+                    })
+                    .filter(|&(_, other)| is_blue(other))
+                    .collect();
+                (nk, blue)
+            })
+            .collect();
+        let mut mates: SecondaryMap<_, _> = colors
+            .keys()
+            .filter(|&nk| self.get_node(nk).is_some())
+            .map(|nk| (nk, None))
+            .collect();
+        let mut len = 0;
+
+        loop {
+            // breadth-first layers of red nodes, alternating between unmatched and matched edges from the free ones
+            let mut layer = SecondaryMap::with_capacity(adjacent.len());
+            let mut queue: VecDeque<_> =
+                adjacent.keys().filter(|&nk| mates[nk].is_none()).collect();
+            for &nk in &queue {
+                layer.insert(nk, 0);
+            }
+            let mut found = false;
+            while let Some(red) = queue.pop_front() {
+                for &(_, blue) in &adjacent[red] {
+                    match mates[blue] {
+                        None => found = true,
+                        Some((_, next)) => {
+                            if !layer.contains_key(next) {
+                                layer.insert(next, layer[red] + 1);
+                                queue.push_back(next);
+                            }
+                        }
+                    }
+                }
+            }
+            if !found {
+                return Matching { mates, len };
+            }
+
+            // augment along vertex-disjoint shortest paths found depth-first through the layers
+            for root in adjacent.keys() {
+                if mates[root].is_some() {
+                    continue;
+                }
+                // every entry is a red node on the path and the number of its edges searched so far
+                let mut path = vec![(root, 0)];
+                while let Some(&mut (red, ref mut searched)) = path.last_mut() {
+                    let Some(&(_, blue)) = adjacent[red].get(*searched) else {
+                        // no augmenting path continues through this node in this phase
+                        layer.remove(red);
+                        path.pop();
+                        continue;
+                    };
+                    *searched += 1;
+                    match mates[blue] {
+                        None => {
+                            for &(red, searched) in &path {
+                                let (ek, blue) = adjacent[red][searched - 1];
+                                mates[red] = Some((ek, blue));
+                                mates[blue] = Some((ek, red));
+                            }
+                            len += 1;
+                            break;
+                        }
+                        Some((_, next)) => {
+                            if layer
+                                .get(next)
+                                .is_some_and(|&l| Some(l) == layer.get(red).map(|l| l + 1))
+                            {
+                                path.push((next, 0));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the cycle closed by an edge between two nodes of the same color in the same breadth-first tree: the path
+/// from one node up to their closest common ancestor and back down to the other.
+fn odd_cycle<K: Key>(
+    parents: &SecondaryMap<NodeKey<K>, NodeKey<K>>,
+    a: NodeKey<K>,
+    b: NodeKey<K>,
+) -> Vec<NodeKey<K>> {
+    let ancestors = |mut nk| {
+        let mut path = vec![nk];
+        while let Some(&parent) = parents.get(nk) {
+            path.push(parent);
+            nk = parent;
+        }
+        path
+    };
+    let (mut up, mut down) = (ancestors(a), ancestors(b));
+    // both paths end at the root, so dropping their common tail leaves the paths to the closest common ancestor
+    while up.len() > 1 && down.len() > 1 && up[up.len() - 2] == down[down.len() - 2] {
+        up.pop();
+        down.pop();
+    }
+    down.pop();
+    down.reverse();
+    up.extend(down);
+    up
+}
@@ -0,0 +1,321 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::ops::Add;
+
+use slotmap::{Key, SecondaryMap};
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// The betweenness centrality of every node and edge of a graph, as computed by
+/// [`SlotGraph::betweenness_centrality`].
+#[derive(Clone, Debug)]
+pub struct Betweenness<K: Key> {
+    /// For every node, the number of shortest paths between other nodes passing through it, where the paths between a
+    /// pair of nodes count as fractions of one.
+    pub nodes: SecondaryMap<NodeKey<K>, f64>,
+    /// For every edge, the number of shortest paths between nodes using it, counted like for nodes.
+    pub edges: SecondaryMap<EdgeKey<K>, f64>,
+}
+
+/// The hub and authority scores of every node of a graph, as computed by [`SlotGraph::hits`].
+#[derive(Clone, Debug)]
+pub struct Hits<K: Key> {
+    /// For every node, how well it points to good authorities, summing to `1.0` over all nodes.
+    pub hubs: SecondaryMap<NodeKey<K>, f64>,
+    /// For every node, how well it is pointed to by good hubs, summing to `1.0` over all nodes.
+    pub authorities: SecondaryMap<NodeKey<K>, f64>,
+}
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Computes the in-degree centrality of every node: its number of incoming edges divided by the number of other
+    /// nodes.
+    ///
+    /// For undirected graphs, every edge points both ways, so this is the same as the out-degree centrality.
+    pub fn in_degree_centrality(&self) -> SecondaryMap<NodeKey<K>, f64> {
+        self.degree_fractions(|nk| self.incoming(nk).len())
+    }
+
+    /// Computes the out-degree centrality of every node: its number of outgoing edges divided by the number of other
+    /// nodes.
+    pub fn out_degree_centrality(&self) -> SecondaryMap<NodeKey<K>, f64> {
+        self.degree_fractions(|nk| self.outgoing(nk).len())
+    }
+
+    /// Computes the degree centrality of every node: its number of edge endpoints divided by the number of other
+    /// nodes, counting self-loops twice.
+    ///
+    /// For directed graphs, this is the sum of the in-degree and out-degree centralities.
+    pub fn degree_centrality(&self) -> SecondaryMap<NodeKey<K>, f64> {
+        self.degree_fractions(|nk| {
+            let outgoing = self.outgoing(nk);
+            if Ty::is_directed() {
+                outgoing.len() + self.incoming(nk).len()
+            } else {
+                // self-loops are listed once among the outgoing edges of undirected graphs
+                outgoing.len() + outgoing.iter().filter(|&&(_, other)| other == nk).count()
+            }
+        })
+    }
+
+    /// Divides the degree of every node by the number of other nodes, or by one for graphs with a single node.
+    fn degree_fractions(
+        &self,
+        degree: impl Fn(NodeKey<K>) -> usize,
+    ) -> SecondaryMap<NodeKey<K>, f64> {
+        let others = self.node_len().max(2) - 1;
+        self.iter_nodes()
+            .map(|(nk, _)| (nk, degree(nk) as f64 / others as f64))
+            .collect()
+    }
+
+    /// Computes the closeness centrality of every node from the breadth-first distances along outgoing edges.
+    ///
+    /// Unreachable nodes are excluded from the sum of distances, so for disconnected graphs this is the
+    /// component-normalized variant of Wasserman and Faust: for a node reaching `r - 1` other nodes at a total
+    /// distance of `d`, the closeness is `(r - 1) / d` scaled by the fraction `(r - 1) / (n - 1)` of the graph it
+    /// reaches. Nodes reaching no other node have a closeness of `0.0`.
+    pub fn closeness_centrality(&self) -> SecondaryMap<NodeKey<K>, f64> {
+        let n = self.node_len();
+        let mut closeness = SecondaryMap::with_capacity(n);
+        let mut distance = SecondaryMap::with_capacity(n);
+        let mut queue = VecDeque::new();
+        for (source, _) in self.iter_nodes() {
+            distance.clear();
+            distance.insert(source, 0usize);
+            queue.push_back(source);
+            let mut total = 0;
+            while let Some(nk) = queue.pop_front() {
+                let d = distance[nk] + 1;
+                for &(_, next) in self.outgoing(nk) {
+                    if !distance.contains_key(next) {
+                        distance.insert(next, d);
+                        total += d;
+                        queue.push_back(next);
+                    }
+                }
+            }
+
+            let reached = (distance.len() - 1) as f64;
+            let value = if total == 0 {
+                0.0
+            } else {
+                (reached / total as f64) * (reached / (n - 1) as f64)
+            };
+            closeness.insert(source, value);
+        }
+        closeness
+    }
+
+    /// Computes the PageRank of every node by power iteration, stopping once the ranks change by less than
+    /// `tolerance` in total or after `max_iterations` iterations.
+    ///
+    /// A random surfer follows a uniformly chosen outgoing edge with probability `damping`, usually `0.85`, and jumps
+    /// to a uniformly chosen node otherwise. Nodes without outgoing edges jump to a uniformly chosen node, so the ranks
+    /// always sum to `1.0`. Parallel edges are followed proportionally more often. Undirected edges are followed both
+    /// ways.
+    pub fn pagerank(
+        &self,
+        damping: f64,
+        tolerance: f64,
+        max_iterations: usize,
+    ) -> SecondaryMap<NodeKey<K>, f64> {
+        let (indices, keys) = self.index_nodes();
+        let n = keys.len();
+        let mut rank = vec![1.0 / n as f64; n];
+        let mut next = vec![0.0; n];
+        for _ in 0..max_iterations {
+            let dangling: f64 = keys
+                .iter()
+                .zip(&rank)
+                .filter(|&(&nk, _)| self.outgoing(nk).is_empty())
+                .map(|(_, r)| r)
+                .sum();
+            next.fill((1.0 - damping + damping * dangling) / n as f64);
+            for (&nk, &r) in keys.iter().zip(&rank) {
+                let out = self.outgoing(nk);
+                let share = damping * r / out.len() as f64;
+                for &(_, to) in out {
+                    next[indices[to]] += share;
+                }
+            }
+            let change: f64 = rank.iter().zip(&next).map(|(a, b)| (a - b).abs()).sum();
+            std::mem::swap(&mut rank, &mut next);
+            if change < tolerance {
+                break;
+            }
+        }
+        keys.into_iter().zip(rank).collect()
+    }
+
+    /// Computes the betweenness centrality of every node and edge with Brandes' algorithm, where every edge has a
+    /// length of one.
+    ///
+    /// The values are not normalized. For undirected graphs, the paths between a pair of nodes are counted once rather
+    /// than once in each direction.
+    pub fn betweenness_centrality(&self) -> Betweenness<K> {
+        self.betweenness_centrality_weighted(|_, _| 1usize)
+    }
+
+    /// Computes the betweenness centrality of every node and edge, like [`Self::betweenness_centrality`], where every
+    /// edge has the length returned by `edge_cost` and paths are shortest by the sum of their lengths.
+    ///
+    /// Paths of equal length are all counted, so lengths should be exact, like integers. Edges of length zero can
+    /// cause shortest paths to be missed.
+    pub fn betweenness_centrality_weighted<W, F>(&self, mut edge_cost: F) -> Betweenness<K>
+    where
+        W: Ord + Add<Output = W> + Copy + Default,
+        F: FnMut(EdgeKey<K>, &E) -> W,
+    {
+        let mut nodes: SecondaryMap<_, _> = self.iter_nodes().map(|(nk, _)| (nk, 0.0)).collect();
+        let mut edges: SecondaryMap<_, _> = self.iter_edges().map(|(ek, _)| (ek, 0.0)).collect();
+        let mut distance = SecondaryMap::with_capacity(nodes.len());
+        let mut paths = SecondaryMap::with_capacity(nodes.len());
+        let mut preds: SecondaryMap<_, Vec<_>> = SecondaryMap::with_capacity(nodes.len());
+        let mut dependency = SecondaryMap::with_capacity(nodes.len());
+        let mut order = Vec::with_capacity(nodes.len());
+        for (source, _) in self.iter_nodes() {
+            // Dijkstra's algorithm, counting the shortest paths to every node and the edges they arrive through
+            distance.clear();
+            paths.clear();
+            preds.clear();
+            order.clear();
+            distance.insert(source, W::default());
+            paths.insert(source, 1.0);
+            preds.insert(source, Vec::new());
+            let mut heap = BinaryHeap::from([Reverse((W::default(), source))]);
+            while let Some(Reverse((d, nk))) = heap.pop() {
+                if d > distance[nk] || dependency.contains_key(nk) {
+                    continue;
+                }
+                // nodes are marked as settled until the accumulation below clears them
+                dependency.insert(nk, 0.0);
+                order.push(nk);
+                for &(ek, next) in self.outgoing(nk) {
+                    if dependency.contains_key(next) {
+                        continue;
+                    }
+                    let nd =
+                        d + edge_cost(ek, self.get_edge(ek).expect("adjacent edges are valid"));
+                    match distance.get(next) {
+                        Some(&old) if nd > old => continue,
+                        Some(&old) if nd == old => {
+                            paths[next] += paths[nk];
+                            preds[next].push((ek, nk));
+                        }
+                        _ => {
+                            distance.insert(next, nd);
+                            paths.insert(next, paths[nk]);
+                            preds.insert(next, vec![(ek, nk)]);
+                            heap.push(Reverse((nd, next)));
+                        }
+                    }
+                }
+            }
+
+            // accumulate the dependencies of the source on every node, from the farthest nodes back
+            for &nk in order.iter().rev() {
+                let share = (1.0 + dependency[nk]) / paths[nk];
+                for &(ek, pred) in &preds[nk] {
+                    let c = paths[pred] * share;
+                    edges[ek] += c;
+                    dependency[pred] += c;
+                }
+                if nk != source {
+                    nodes[nk] += dependency[nk];
+                }
+            }
+            dependency.clear();
+        }
+        if !Ty::is_directed() {
+            nodes
+                .values_mut()
+                .chain(edges.values_mut())
+                .for_each(|v| *v /= 2.0);
+        }
+        Betweenness { nodes, edges }
+    }
+
+    /// Computes the eigenvector centrality of every node by power iteration, stopping once the centralities change by
+    /// less than `tolerance` in total or after `max_iterations` iterations.
+    ///
+    /// The centrality of a node is proportional to the sum of the centralities of the nodes with edges pointing to it,
+    /// counting parallel edges separately. The centralities are scaled to a Euclidean norm of `1.0`. Every iteration
+    /// also adds each node's own centrality, which keeps the iteration from oscillating on bipartite graphs without
+    /// changing the result.
+    pub fn eigenvector_centrality(
+        &self,
+        tolerance: f64,
+        max_iterations: usize,
+    ) -> SecondaryMap<NodeKey<K>, f64> {
+        let (indices, keys) = self.index_nodes();
+        let mut centrality = vec![1.0 / keys.len() as f64; keys.len()];
+        let mut next = vec![0.0; keys.len()];
+        for _ in 0..max_iterations {
+            next.copy_from_slice(&centrality);
+            for (&nk, &c) in keys.iter().zip(&centrality) {
+                for &(_, to) in self.outgoing(nk) {
+                    next[indices[to]] += c;
+                }
+            }
+            normalize(&mut next, |v| v.iter().map(|c| c * c).sum::<f64>().sqrt());
+            let change: f64 = centrality
+                .iter()
+                .zip(&next)
+                .map(|(a, b)| (a - b).abs())
+                .sum();
+            std::mem::swap(&mut centrality, &mut next);
+            if change < tolerance {
+                break;
+            }
+        }
+        keys.into_iter().zip(centrality).collect()
+    }
+
+    /// Computes the hub and authority scores of every node with Kleinberg's HITS algorithm, stopping once the scores
+    /// change by less than `tolerance` in total or after `max_iterations` iterations.
+    ///
+    /// The authority score of a node is proportional to the sum of the hub scores of the nodes with edges pointing to
+    /// it, and the hub score of a node to the sum of the authority scores of the nodes its edges point to, counting
+    /// parallel edges separately.
+    pub fn hits(&self, tolerance: f64, max_iterations: usize) -> Hits<K> {
+        let (indices, keys) = self.index_nodes();
+        let mut hubs = vec![1.0 / keys.len() as f64; keys.len()];
+        let mut authorities = vec![0.0; keys.len()];
+        let mut next = vec![0.0; keys.len()];
+        for _ in 0..max_iterations {
+            authorities.fill(0.0);
+            for (&nk, &h) in keys.iter().zip(&hubs) {
+                for &(_, to) in self.outgoing(nk) {
+                    authorities[indices[to]] += h;
+                }
+            }
+            normalize(&mut authorities, |v| v.iter().sum());
+            for (&nk, hub) in keys.iter().zip(&mut next) {
+                *hub = self
+                    .outgoing(nk)
+                    .iter()
+                    .map(|&(_, to)| authorities[indices[to]])
+                    .sum();
+            }
+            normalize(&mut next, |v| v.iter().sum());
+            let change: f64 = hubs.iter().zip(&next).map(|(a, b)| (a - b).abs()).sum();
+            std::mem::swap(&mut hubs, &mut next);
+            if change < tolerance {
+                break;
+            }
+        }
+        Hits {
+            hubs: keys.iter().copied().zip(hubs).collect(),
+            authorities: keys.into_iter().zip(authorities).collect(),
+        }
+    }
+}
+
+/// Divides every value by their norm, unless it is zero.
+fn normalize(values: &mut [f64], norm: fn(&[f64]) -> f64) {
+    let norm = norm(values);
+    if norm > 0.0 {
+        values.iter_mut().for_each(|v| *v /= norm);
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::HashSet;
+
+use slotmap::{Key, SecondaryMap};
+
+use crate::slotgraph::{NodeKey, SlotGraph};
+
+/// Structural facts about a graph, as computed by [`SlotGraph::classify`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GraphClass {
+    /// Whether any edge points from a node to itself.
+    pub self_loops: bool,
+    /// Whether any two edges point from the same node to the same node.
+    pub parallel_edges: bool,
+    /// Whether the graph contains no directed cycles, including self-loops.
+    pub acyclic: bool,
+}
+
+impl GraphClass {
+    /// Returns whether the graph is a simple directed acyclic graph: no self-loops, parallel edges or cycles.
+    pub fn is_simple_dag(&self) -> bool {
+        !self.self_loops && !self.parallel_edges && self.acyclic
+    }
+}
+
+/// A summary of the size and shape of a graph, as computed by [`SlotGraph::stats`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GraphStats {
+    /// The number of nodes.
+    pub node_count: usize,
+    /// The number of edges.
+    pub edge_count: usize,
+    /// The number of edges pointing from a node to itself.
+    pub self_loops: usize,
+    /// The largest number of edges pointing from a single node, or 0 for an empty graph.
+    pub max_out_degree: usize,
+    /// The largest number of edges pointing to a single node, or 0 for an empty graph.
+    pub max_in_degree: usize,
+    /// Whether the graph contains no directed cycles, including self-loops.
+    pub is_acyclic: bool,
+}
+
+impl<K: Key, N, E> SlotGraph<K, N, E> {
+    /// Computes a [`GraphStats`] summary of the graph in two passes: one over the adjacency lists of every node and a
+    /// search for a cycle.
+    pub fn stats(&self) -> GraphStats {
+        let mut self_loops = 0;
+        let mut max_out_degree = 0;
+        let mut max_in_degree = 0;
+        for nk in self.node_keys() {
+            let outgoing = self.outgoing(nk);
+            self_loops += outgoing.iter().filter(|&&(_, next)| next == nk).count();
+            max_out_degree = max_out_degree.max(outgoing.len());
+            max_in_degree = max_in_degree.max(self.incoming(nk).len());
+        }
+        GraphStats {
+            node_count: self.node_len(),
+            edge_count: self.edge_len(),
+            self_loops,
+            max_out_degree,
+            max_in_degree,
+            is_acyclic: self.is_dag(),
+        }
+    }
+
+    /// Returns whether any two edges point from the same node to the same node.
+    pub fn has_parallel_edges(&self) -> bool {
+        self.first_parallel_edge().is_some()
+    }
+
+    /// Returns the node keys of the first pair of nodes found to be connected by more than one edge in the same
+    /// direction.
+    pub fn first_parallel_edge(&self) -> Option<(NodeKey<K>, NodeKey<K>)> {
+        let mut pairs = HashSet::with_capacity(self.edge_len());
+        self.iter_edge_nodes()
+            .map(|(_, nodes)| nodes)
+            .find(|&nodes| !pairs.insert(nodes))
+    }
+
+    /// Determines whether the graph has self-loops, parallel edges and cycles in a single pass over the edges.
+    pub fn classify(&self) -> GraphClass {
+        let mut self_loops = false;
+        let mut parallel_edges = false;
+        let mut pairs = HashSet::with_capacity(self.edge_len());
+        let mut in_degree: SecondaryMap<_, usize> =
+            self.iter_nodes().map(|(k, _)| (k, 0)).collect();
+        for (_, (from, to)) in self.iter_edge_nodes() {
+            self_loops |= from == to;
+            parallel_edges |= !pairs.insert((from, to));
+            if let Some(d) = in_degree.get_mut(to) {
+                *d += 1;
+            }
+        }
+
+        // Kahn's algorithm: the graph is acyclic iff every node can be removed once its in-degree drops to zero
+        let mut stack: Vec<_> = in_degree
+            .iter()
+            .filter(|&(_, &d)| d == 0)
+            .map(|(k, _)| k)
+            .collect();
+        let mut removed = 0;
+        while let Some(nk) = stack.pop() {
+            removed += 1;
+            for &(_, next) in self.outgoing(nk) {
+                let d = &mut in_degree[next];
+                *d -= 1;
+                if *d == 0 {
+                    stack.push(next);
+                }
+            }
+        }
+
+        GraphClass {
+            self_loops,
+            parallel_edges,
+            acyclic: removed == self.node_len(),
+        }
+    }
+}
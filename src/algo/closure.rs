@@ -0,0 +1,149 @@
+use slotmap::{Key, SecondaryMap};
+
+use super::ordering::{toposort, Cycle};
+use super::scc::tarjan_scc;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// The reachability relation of a graph as a bit matrix over a dense numbering of its nodes, as computed by
+/// [`transitive_closure`].
+///
+/// Answers whether one node reaches another in constant time. The matrix takes `node_len()²` bits, so it suits graphs
+/// of up to tens of thousands of nodes.
+#[derive(Clone, Debug)]
+pub struct TransitiveClosure<K: Key> {
+    indices: SecondaryMap<NodeKey<K>, usize>,
+    keys: Vec<NodeKey<K>>,
+    words_per_row: usize,
+    bits: Vec<u64>,
+}
+
+impl<K: Key> TransitiveClosure<K> {
+    /// Returns whether there is a path of at least one edge from one node to another, or `false` if either node was
+    /// not in the graph.
+    ///
+    /// A node reaches itself only if it is on a cycle.
+    pub fn reaches(&self, from: NodeKey<K>, to: NodeKey<K>) -> bool {
+        let (Some(&from), Some(&to)) = (self.indices.get(from), self.indices.get(to)) else {
+            return false;
+        };
+        self.bits[from * self.words_per_row + to / 64] & (1 << (to % 64)) != 0
+    }
+
+    /// An iterator over the nodes reached by a path of at least one edge from the node, in the order of
+    /// [`SlotGraph::index_nodes`].
+    pub fn reachable_from(&self, from: NodeKey<K>) -> impl Iterator<Item = NodeKey<K>> + '_ {
+        let row = match self.indices.get(from) {
+            Some(&i) => &self.bits[i * self.words_per_row..(i + 1) * self.words_per_row],
+            None => &[],
+        };
+        self.keys
+            .iter()
+            .enumerate()
+            .filter(move |&(i, _)| row[i / 64] & (1 << (i % 64)) != 0)
+            .map(|(_, &nk)| nk)
+    }
+
+    /// Returns the dense index of the node in the matrix, which is its index in [`SlotGraph::index_nodes`].
+    pub fn index(&self, key: NodeKey<K>) -> Option<usize> {
+        self.indices.get(key).copied()
+    }
+}
+
+/// Computes which nodes of the graph reach which others, as a [`TransitiveClosure`] bit matrix.
+///
+/// Rows are combined along the strongly connected components in reverse topological order, so every row is built
+/// once from the rows of its successors.
+pub fn transitive_closure<K: Key, N, E>(graph: &SlotGraph<K, N, E>) -> TransitiveClosure<K> {
+    let (indices, keys) = graph.index_nodes();
+    let words_per_row = keys.len().div_ceil(64);
+    let mut bits = vec![0u64; keys.len() * words_per_row];
+    let mut row = vec![0u64; words_per_row];
+    // the component of every node by dense index, stamped as components are reached
+    let mut component_of = vec![usize::MAX; keys.len()];
+    // components come sinks first, so the rows of all successors outside a component are complete when it is reached
+    for (id, component) in tarjan_scc(graph).into_iter().enumerate() {
+        row.fill(0);
+        let cyclic = component.len() > 1;
+        for &nk in &component {
+            component_of[indices[nk]] = id;
+        }
+        for &nk in &component {
+            for &(_, next) in graph.outgoing(nk) {
+                let j = indices[next];
+                row[j / 64] |= 1 << (j % 64);
+                if component_of[j] != id {
+                    let successor = &bits[j * words_per_row..(j + 1) * words_per_row];
+                    row.iter_mut().zip(successor).for_each(|(w, s)| *w |= s);
+                }
+            }
+        }
+        // the members of a cycle reach each other and themselves, while a single node reaches itself only through a
+        // self-loop, which has set its own bit above
+        if cyclic {
+            for &nk in &component {
+                let j = indices[nk];
+                row[j / 64] |= 1 << (j % 64);
+            }
+        }
+        for &nk in &component {
+            let i = indices[nk];
+            bits[i * words_per_row..(i + 1) * words_per_row].copy_from_slice(&row);
+        }
+    }
+    TransitiveClosure {
+        indices,
+        keys,
+        words_per_row,
+        bits,
+    }
+}
+
+impl<K: Key, N, E> SlotGraph<K, N, E> {
+    /// Returns the edges of an acyclic graph that are implied by the others: edges to a node that is also reached by a
+    /// longer path, and all but the first of parallel edges.
+    ///
+    /// Removing them leaves the transitive reduction, the fewest edges with the same reachability.
+    ///
+    /// # Errors
+    ///
+    /// Returns a node on a cycle if the graph is not acyclic, since the reduction of a cyclic graph is not unique.
+    pub fn redundant_edges(&self) -> Result<Vec<EdgeKey<K>>, Cycle<NodeKey<K>>> {
+        toposort(self)?;
+        let closure = transitive_closure(self);
+        let words_per_row = closure.words_per_row;
+        let mut implied = vec![0u64; words_per_row];
+        let mut redundant = Vec::new();
+        for (nk, _) in self.iter_nodes() {
+            // everything reached through a successor is reached by a longer path
+            implied.fill(0);
+            for &(_, next) in self.outgoing(nk) {
+                let j = closure.indices[next];
+                let row = &closure.bits[j * words_per_row..(j + 1) * words_per_row];
+                implied.iter_mut().zip(row).for_each(|(w, r)| *w |= r);
+            }
+            let mut linked = SecondaryMap::new();
+            for &(ek, next) in self.outgoing(nk) {
+                let j = closure.indices[next];
+                if implied[j / 64] & (1 << (j % 64)) != 0 || linked.insert(next, ()).is_some() {
+                    redundant.push(ek);
+                }
+            }
+        }
+        Ok(redundant)
+    }
+
+    /// Removes the edges found by [`SlotGraph::redundant_edges`], reducing an acyclic graph to its transitive
+    /// reduction, and returns their keys and values.
+    ///
+    /// # Errors
+    ///
+    /// Returns a node on a cycle if the graph is not acyclic, leaving it unchanged.
+    #[allow(clippy::type_complexity)]
+    pub fn transitive_reduction(&mut self) -> Result<Vec<(EdgeKey<K>, E)>, Cycle<NodeKey<K>>> {
+        Ok(self
+            .redundant_edges()?
+            .into_iter()
+            .filter_map(|ek| Some((ek, self.remove_edge(ek)?)))
+            .collect())
+    }
+}
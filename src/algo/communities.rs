@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+
+use slotmap::{Key, SecondaryMap};
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// A partition of the nodes of a graph into communities, as found by [`SlotGraph::louvain_communities`].
+#[derive(Clone, Debug)]
+pub struct Communities<K: Key> {
+    /// The id of the community of every node, from 0 up to, but not including, the number of communities, numbered in
+    /// the order the communities are first seen in node iteration order.
+    pub ids: SecondaryMap<NodeKey<K>, usize>,
+    /// The number of communities.
+    pub count: usize,
+    /// The modularity of the partition, from `-0.5` to `1.0`: the fraction of the edge weight inside communities, minus
+    /// the fraction expected if edges were placed at random between nodes of the same degrees.
+    pub modularity: f64,
+}
+
+/// A weighted undirected graph of consecutively numbered nodes, which Louvain aggregates into ever smaller graphs.
+struct Weighted {
+    /// The neighbors of every node and the total weight of the edges to them, excluding self-loops.
+    adjacent: Vec<Vec<(usize, f64)>>,
+    /// The total weight of the self-loops of every node.
+    loops: Vec<f64>,
+}
+
+impl Weighted {
+    /// Returns the total weight of the edge endpoints at every node, counting self-loops twice.
+    fn degrees(&self) -> Vec<f64> {
+        self.adjacent
+            .iter()
+            .zip(&self.loops)
+            .map(|(adjacent, &loops)| adjacent.iter().map(|&(_, w)| w).sum::<f64>() + 2.0 * loops)
+            .collect()
+    }
+
+    /// Moves single nodes to the neighboring community that improves modularity the most, until no move does, and
+    /// returns the community of every node and whether any node moved.
+    fn local_moves(&self, total: f64) -> (Vec<usize>, bool) {
+        let degrees = self.degrees();
+        let mut community: Vec<usize> = (0..degrees.len()).collect();
+        let mut community_degree = degrees.clone();
+        let mut links: HashMap<usize, f64> = HashMap::new();
+        let mut moved = false;
+        loop {
+            let mut improved = false;
+            for (nk, adjacent) in self.adjacent.iter().enumerate() {
+                let current = community[nk];
+                community_degree[current] -= degrees[nk];
+                links.clear();
+                links.insert(current, 0.0);
+                for &(other, w) in adjacent {
+                    *links.entry(community[other]).or_default() += w;
+                }
+                // the gain of joining a community, up to terms that are the same for every community
+                let gain = |c: usize, w: f64| w - community_degree[c] * degrees[nk] / total;
+                // candidates in a fixed order, so ties are broken the same way every time
+                let mut candidates: Vec<_> = links.iter().map(|(&c, &w)| (c, w)).collect();
+                candidates.sort_unstable_by_key(|&(c, _)| c);
+                let mut best = (current, gain(current, links[&current]));
+                for (c, w) in candidates {
+                    let g = gain(c, w);
+                    if g > best.1 + 1e-12 {
+                        best = (c, g);
+                    }
+                }
+                community_degree[best.0] += degrees[nk];
+                if best.0 != current {
+                    community[nk] = best.0;
+                    improved = true;
+                }
+            }
+            if !improved {
+                return (community, moved);
+            }
+            moved = true;
+        }
+    }
+
+    /// Merges the nodes of every community into a single node, given communities numbered in `0..count`.
+    fn aggregate(&self, community: &[usize], count: usize) -> Self {
+        let mut links: Vec<HashMap<usize, f64>> = vec![HashMap::new(); count];
+        let mut loops = vec![0.0; count];
+        for (nk, adjacent) in self.adjacent.iter().enumerate() {
+            let c = community[nk];
+            loops[c] += self.loops[nk];
+            for &(other, w) in adjacent {
+                let d = community[other];
+                if c == d {
+                    // edges inside a community are seen from both ends
+                    loops[c] += w / 2.0;
+                } else {
+                    *links[c].entry(d).or_default() += w;
+                }
+            }
+        }
+        let adjacent = links
+            .into_iter()
+            .map(|links| {
+                let mut adjacent: Vec<_> = links.into_iter().collect();
+                adjacent.sort_unstable_by_key(|&(d, _)| d);
+                adjacent
+            })
+            .collect();
+        Self { adjacent, loops }
+    }
+}
+
+/// Renumbers the communities consecutively in order of their first node, and returns the number of communities.
+fn renumber(community: &mut [usize]) -> usize {
+    let mut ids = HashMap::new();
+    for c in community.iter_mut() {
+        let next_id = ids.len();
+        *c = *ids.entry(*c).or_insert(next_id);
+    }
+    ids.len()
+}
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Partitions the nodes into communities with the Louvain method, treating every edge as undirected with a weight
+    /// of one.
+    ///
+    /// See [`Self::louvain_communities_weighted`].
+    pub fn louvain_communities(&self) -> Communities<K> {
+        self.louvain_communities_weighted(|_, _| 1.0)
+    }
+
+    /// Partitions the nodes into communities with the Louvain method, treating every edge as undirected with the
+    /// weight returned by `edge_weight`, which should not be negative.
+    ///
+    /// The method greedily moves nodes between communities while that increases modularity, then merges every
+    /// community into a single node and repeats on the smaller graph, until nothing moves. The result is a local
+    /// maximum of modularity, which depends on node iteration order. Parallel edges add up, and nodes without edges
+    /// are communities of their own.
+    pub fn louvain_communities_weighted<F>(&self, mut edge_weight: F) -> Communities<K>
+    where
+        F: FnMut(EdgeKey<K>, &E) -> f64,
+    {
+        let (indices, keys) = self.index_nodes();
+        let mut graph = Weighted {
+            adjacent: vec![Vec::new(); keys.len()],
+            loops: vec![0.0; keys.len()],
+        };
+        let mut weights = Vec::with_capacity(self.edge_len());
+        for (ek, from, to, value) in self.iter_edges_full() {
+            let (from, to, w) = (indices[from], indices[to], edge_weight(ek, value));
+            weights.push((from, to, w));
+            if from == to {
+                graph.loops[from] += w;
+            } else {
+                graph.adjacent[from].push((to, w));
+                graph.adjacent[to].push((from, w));
+            }
+        }
+        let total: f64 = graph.degrees().iter().sum();
+
+        let mut community: Vec<usize> = (0..keys.len()).collect();
+        if total > 0.0 {
+            loop {
+                let (mut merged, moved) = graph.local_moves(total);
+                if !moved {
+                    break;
+                }
+                let count = renumber(&mut merged);
+                community.iter_mut().for_each(|c| *c = merged[*c]);
+                graph = graph.aggregate(&merged, count);
+            }
+        }
+        let count = renumber(&mut community);
+
+        // modularity from the fraction of weight inside communities and the degrees of the communities
+        let mut modularity = 0.0;
+        if total > 0.0 {
+            let mut community_degree = vec![0.0; count];
+            for (from, to, w) in weights {
+                community_degree[community[from]] += w;
+                community_degree[community[to]] += w;
+                if community[from] == community[to] {
+                    modularity += 2.0 * w / total;
+                }
+            }
+            modularity -= community_degree
+                .iter()
+                .map(|d| (d / total) * (d / total))
+                .sum::<f64>();
+        }
+        Communities {
+            ids: keys.into_iter().zip(community).collect(),
+            count,
+            modularity,
+        }
+    }
+}
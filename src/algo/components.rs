@@ -0,0 +1,45 @@
+use slotmap::{Key, SecondaryMap};
+
+use super::union_find::UnionFind;
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{NodeKey, SlotGraph};
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Returns the sizes of the weakly connected components, sorted in descending order.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        let uf = self.weak_union_find();
+        let mut sizes: Vec<usize> = uf.set_sizes().collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+    /// Labels every node with the id of its weakly connected component, treating edges as undirected, and returns the
+    /// labels and the number of components.
+    ///
+    /// Ids range from 0 up to, but not including, the number of components, numbered in the order the components are
+    /// first seen in node iteration order.
+    pub fn connected_components(&self) -> (SecondaryMap<NodeKey<K>, usize>, usize) {
+        let mut uf = self.weak_union_find();
+        let mut ids = SecondaryMap::with_capacity(self.node_len());
+        let mut labels = SecondaryMap::with_capacity(self.node_len());
+        for (nk, _) in self.iter_nodes() {
+            let root = uf.find(nk).expect("every node is in the forest");
+            let next_id = ids.len();
+            let id = *ids
+                .entry(root)
+                .expect("roots are valid keys")
+                .or_insert(next_id);
+            labels.insert(nk, id);
+        }
+        (labels, ids.len())
+    }
+
+    /// Builds a forest with a set for every weakly connected component.
+    fn weak_union_find(&self) -> UnionFind<NodeKey<K>> {
+        let mut uf = UnionFind::new(self.iter_nodes().map(|(k, _)| k));
+        for (_, (from, to)) in self.iter_edge_nodes() {
+            uf.union(from, to);
+        }
+        uf
+    }
+}
@@ -0,0 +1,106 @@
+use std::cmp::min;
+
+use slotmap::{Key, SecondaryMap};
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// A node on the depth-first search path of [`SlotGraph::cut_points_and_bridges`].
+struct Frame<K: Key> {
+    node: NodeKey<K>,
+    /// The edge the node was discovered through, or `None` for a root.
+    parent_edge: Option<EdgeKey<K>>,
+    /// The discovery time of the node.
+    time: usize,
+    /// The earliest discovery time reachable from the subtree of the node through a single non-tree edge.
+    low: usize,
+    incident: Vec<(EdgeKey<K>, NodeKey<K>)>,
+    searched: usize,
+    children: usize,
+}
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Returns the articulation points of the graph, ignoring edge directions: the nodes whose removal disconnects
+    /// their connected component.
+    ///
+    /// Nodes are returned in the order a depth-first search first finds them to be articulation points.
+    pub fn articulation_points(&self) -> Vec<NodeKey<K>> {
+        self.cut_points_and_bridges().0
+    }
+
+    /// Returns the bridges of the graph, ignoring edge directions: the edges whose removal disconnects their connected
+    /// component.
+    ///
+    /// Parallel edges and self-loops are never bridges. Edges are returned in the order a depth-first search finishes
+    /// them.
+    pub fn bridges(&self) -> Vec<EdgeKey<K>> {
+        self.cut_points_and_bridges().1
+    }
+
+    /// Finds the articulation points and bridges with a single iterative depth-first search, comparing the discovery
+    /// time of every node with the earliest one reachable from its subtree through a single non-tree edge.
+    fn cut_points_and_bridges(&self) -> (Vec<NodeKey<K>>, Vec<EdgeKey<K>>) {
+        let mut discovered = SecondaryMap::with_capacity(self.node_len());
+        let (mut points, mut bridges) = (Vec::new(), Vec::new());
+        let mut is_point = SecondaryMap::new();
+        let mut path: Vec<Frame<K>> = Vec::new();
+        let visit = |node, parent_edge, discovered: &mut SecondaryMap<_, _>| {
+            let time = discovered.len();
+            discovered.insert(node, time);
+            Frame {
+                node,
+                parent_edge,
+                time,
+                low: time,
+                incident: self.incident(node).collect(),
+                searched: 0,
+                children: 0,
+            }
+        };
+        for (root, _) in self.iter_nodes() {
+            if discovered.contains_key(root) {
+                continue;
+            }
+            path.push(visit(root, None, &mut discovered));
+            while let Some(frame) = path.last_mut() {
+                if let Some(&(ek, next)) = frame.incident.get(frame.searched) {
+                    frame.searched += 1;
+                    // the tree edge to the parent is skipped, but a parallel edge to it is not
+                    if Some(ek) == frame.parent_edge || next == frame.node {
+                        continue;
+                    }
+                    match discovered.get(next) {
+                        Some(&time) => frame.low = min(frame.low, time),
+                        None => {
+                            frame.children += 1;
+                            let child = visit(next, Some(ek), &mut discovered);
+                            path.push(child);
+                        }
+                    }
+                    continue;
+                }
+
+                let child = path.pop().expect("the frame is on the path");
+                let is_root = path.len() == 1;
+                let Some(parent) = path.last_mut() else {
+                    if child.children > 1 {
+                        points.push(child.node);
+                    }
+                    continue;
+                };
+                parent.low = min(parent.low, child.low);
+                if child.low > parent.time {
+                    bridges.push(child.parent_edge.expect("a child has a parent edge"));
+                }
+                // the root is handled by counting its children instead
+                if child.low >= parent.time
+                    && !is_root
+                    && is_point.insert(parent.node, ()).is_none()
+                {
+                    points.push(parent.node);
+                }
+            }
+        }
+        (points, bridges)
+    }
+}
@@ -0,0 +1,108 @@
+use std::ops::ControlFlow;
+
+use slotmap::{Key, SecondaryMap};
+
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// The state of a node during a depth-first search.
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Visit {
+    /// The node is on the current search path.
+    Active,
+    /// The node and everything reachable from it has been searched.
+    Done,
+}
+
+impl<K: Key, N, E> SlotGraph<K, N, E> {
+    /// Searches the graph depth-first, calling the closure for every back edge with the current search path, the edge
+    /// and the node it points back to, until the closure breaks.
+    ///
+    /// Every path entry is a node and the number of its outgoing edges searched so far, so the last one searched is
+    /// the edge to the next entry.
+    fn for_each_back_edge<F>(&self, mut f: F)
+    where
+        F: FnMut(&[(NodeKey<K>, usize)], EdgeKey<K>, NodeKey<K>) -> ControlFlow<()>,
+    {
+        let mut visit = SecondaryMap::with_capacity(self.node_len());
+        let mut stack = Vec::new();
+        for (root, _) in self.iter_nodes() {
+            if visit.contains_key(root) {
+                continue;
+            }
+            visit.insert(root, Visit::Active);
+            stack.push((root, 0));
+            while let Some((nk, i)) = stack.last_mut() {
+                let Some(&(ek, next)) = self.outgoing(*nk).get(*i) else {
+                    visit.insert(*nk, Visit::Done);
+                    stack.pop();
+                    continue;
+                };
+                *i += 1;
+                match visit.get(next) {
+                    None => {
+                        visit.insert(next, Visit::Active);
+                        stack.push((next, 0));
+                    }
+                    Some(Visit::Active) => {
+                        if f(&stack, ek, next).is_break() {
+                            return;
+                        }
+                    }
+                    Some(Visit::Done) => {}
+                }
+            }
+        }
+    }
+
+    /// Returns a set of edges whose removal makes the graph acyclic: the back edges found by a depth-first search.
+    ///
+    /// The set is not necessarily minimal. Every self-loop is in it, since a self-loop always points back to the node
+    /// being searched.
+    pub fn feedback_edge_set(&self) -> Vec<EdgeKey<K>> {
+        let mut back_edges = Vec::new();
+        self.for_each_back_edge(|_, ek, _| {
+            back_edges.push(ek);
+            ControlFlow::Continue(())
+        });
+        back_edges
+    }
+
+    /// Returns a cycle of the graph if it has one, as every node on the cycle with the edge leaving it along the
+    /// cycle, in order. The edge of the last node points to the first node.
+    pub fn find_cycle(&self) -> Option<Vec<(NodeKey<K>, EdgeKey<K>)>> {
+        let mut cycle = None;
+        self.for_each_back_edge(|path, _, start| {
+            let first = path
+                .iter()
+                .position(|&(nk, _)| nk == start)
+                .expect("back edges point to a node on the search path");
+            cycle = Some(
+                path[first..]
+                    .iter()
+                    .map(|&(nk, searched)| (nk, self.outgoing(nk)[searched - 1].0))
+                    .collect(),
+            );
+            ControlFlow::Break(())
+        });
+        cycle
+    }
+
+    /// Returns whether the graph contains a cycle, including a self-loop.
+    pub fn has_cycle(&self) -> bool {
+        self.find_cycle().is_some()
+    }
+
+    /// Returns whether the graph is a directed acyclic graph.
+    pub fn is_dag(&self) -> bool {
+        !self.has_cycle()
+    }
+
+    /// Removes the edges found by [`SlotGraph::feedback_edge_set`], making the graph acyclic, and returns their keys
+    /// and values.
+    pub fn break_cycles(&mut self) -> Vec<(EdgeKey<K>, E)> {
+        self.feedback_edge_set()
+            .into_iter()
+            .filter_map(|ek| Some((ek, self.remove_edge(ek)?)))
+            .collect()
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+
+use slotmap::{Key, SecondaryMap};
+
+use super::traversal::post_order;
+use crate::adjacency::Adjacency;
+use crate::slotgraph::{NodeKey, SlotGraph};
+
+/// Computes the immediate dominator of every node reachable from the entry node, with the iterative algorithm of
+/// Cooper, Harvey and Kennedy.
+///
+/// A node dominates another if every path from the entry to the other node passes through it, and the immediate
+/// dominator of a node is its closest strict dominator. The entry node is mapped to itself, and nodes not reachable
+/// from it are not in the map. Returns an empty map if the entry node is not in the graph.
+///
+/// Runs on a [`SlotGraph`] or, to follow edges backwards, its [`TransposeView`](crate::TransposeView), see
+/// [`post_dominators`].
+pub fn dominators<K: Key, G: Adjacency<K>>(
+    graph: &G,
+    entry: NodeKey<K>,
+) -> SecondaryMap<NodeKey<K>, NodeKey<K>> {
+    let post_order = post_order(graph, [entry]);
+    let number: SecondaryMap<_, _> = post_order
+        .iter()
+        .enumerate()
+        .map(|(i, &nk)| (nk, i))
+        .collect();
+    let mut idom = SecondaryMap::with_capacity(post_order.len());
+    if post_order.is_empty() {
+        return idom;
+    }
+    idom.insert(entry, entry);
+
+    // walks up from both nodes to their closest common dominator, using that dominators come later in post-order
+    let intersect =
+        |idom: &SecondaryMap<NodeKey<K>, NodeKey<K>>, mut a: NodeKey<K>, mut b: NodeKey<K>| {
+            while a != b {
+                while number[a] < number[b] {
+                    a = idom[a];
+                }
+                while number[b] < number[a] {
+                    b = idom[b];
+                }
+            }
+            a
+        };
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &nk in post_order.iter().rev().skip(1) {
+            let new_idom = graph
+                .incoming(nk)
+                .iter()
+                .map(|&(_, pred)| pred)
+                .filter(|&pred| idom.contains_key(pred))
+                .reduce(|a, b| intersect(&idom, a, b))
+                .expect("a node after the entry in reverse post-order has a processed predecessor");
+            if idom.insert(nk, new_idom) != Some(new_idom) {
+                changed = true;
+            }
+        }
+    }
+    idom
+}
+
+/// Computes the immediate post-dominator of every node that reaches the exit node: the closest node other than itself
+/// that every path from it to the exit passes through.
+///
+/// These are the [`dominators`] of the graph with its edges reversed, computed through its
+/// [`TransposeView`](crate::TransposeView) without copying it. The exit node is mapped to itself.
+pub fn post_dominators<K: Key, N, E>(
+    graph: &SlotGraph<K, N, E>,
+    exit: NodeKey<K>,
+) -> SecondaryMap<NodeKey<K>, NodeKey<K>> {
+    dominators(&graph.transpose_view(), exit)
+}
+
+/// Computes the dominance frontier of every node reachable from the entry node: the nodes where its dominance ends,
+/// which it does not strictly dominate but which have a predecessor it dominates.
+///
+/// These are the nodes where SSA construction places phi functions for definitions in the node. Every reachable node
+/// has a set, possibly empty, and predecessors not reachable from the entry are ignored.
+pub fn dominance_frontiers<K: Key, G: Adjacency<K>>(
+    graph: &G,
+    entry: NodeKey<K>,
+) -> SecondaryMap<NodeKey<K>, HashSet<NodeKey<K>>> {
+    let idom = dominators(graph, entry);
+    let mut frontiers: SecondaryMap<_, _> = idom.keys().map(|nk| (nk, HashSet::new())).collect();
+    for join in idom.keys() {
+        let preds: Vec<_> = graph
+            .incoming(join)
+            .iter()
+            .map(|&(_, pred)| pred)
+            .filter(|&pred| idom.contains_key(pred))
+            .collect();
+        // the entry is also entered from outside the graph, so a single edge back to it already makes it a join
+        if preds.len() < 2 && join != entry {
+            continue;
+        }
+        for pred in preds {
+            // every dominator of the predecessor up to the immediate dominator of the join has it in its frontier,
+            // which for the entry includes the entry itself
+            let mut runner = pred;
+            while join == entry || runner != idom[join] {
+                frontiers[runner].insert(join);
+                if runner == entry {
+                    break;
+                }
+                runner = idom[runner];
+            }
+        }
+    }
+    frontiers
+}
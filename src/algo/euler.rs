@@ -0,0 +1,86 @@
+use slotmap::{Key, SecondaryMap};
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// Returns the node an Eulerian path has to start at, any node with edges if it can start anywhere, or `None` if the
+/// degrees of the nodes rule out an Eulerian path.
+fn eulerian_start<K: Key, N, E, Ty: EdgeType>(
+    graph: &SlotGraph<K, N, E, Ty>,
+) -> Option<Option<NodeKey<K>>> {
+    let mut start = None;
+    let mut any = None;
+    let mut odd = 0;
+    for (nk, _) in graph.iter_nodes() {
+        let outgoing = graph.outgoing(nk);
+        if outgoing.is_empty() && graph.incoming(nk).is_empty() {
+            continue;
+        }
+        any.get_or_insert(nk);
+        if Ty::is_directed() {
+            match outgoing.len() as isize - graph.incoming(nk).len() as isize {
+                0 => {}
+                1 if start.is_none() => start = Some(nk),
+                -1 => odd += 1,
+                _ => return None,
+            }
+        } else {
+            // a self-loop adds 2 to the degree, so only the other edges decide its parity
+            let degree = outgoing.iter().filter(|&&(_, other)| other != nk).count();
+            if degree % 2 == 1 {
+                odd += 1;
+                start.get_or_insert(nk);
+            }
+        }
+    }
+    let balanced = if Ty::is_directed() {
+        odd == usize::from(start.is_some())
+    } else {
+        odd == 0 || odd == 2
+    };
+    balanced.then_some(start.or(any))
+}
+
+/// Returns an Eulerian path of the graph, traversing every edge exactly once, as the sequence of its edges, or `None`
+/// if there is none.
+///
+/// The path is a circuit, ending where it starts, whenever the degrees allow one. Otherwise it starts at the node with
+/// one more outgoing than incoming edge, or at a node of odd degree in an undirected graph. The path is built with
+/// Hierholzer's algorithm in time proportional to the number of edges. A graph without edges has an empty path.
+pub fn eulerian_path<K: Key, N, E, Ty: EdgeType>(
+    graph: &SlotGraph<K, N, E, Ty>,
+) -> Option<Vec<EdgeKey<K>>> {
+    let Some(start) = eulerian_start(graph)? else {
+        return Some(Vec::new());
+    };
+    let mut used = SecondaryMap::with_capacity(graph.edge_len());
+    // the number of outgoing edges of every node already looked at
+    let mut searched: SecondaryMap<NodeKey<K>, usize> = SecondaryMap::new();
+    let mut path = Vec::with_capacity(graph.edge_len());
+    // the trail being extended, with the edge every node was reached through
+    let mut trail = vec![(start, None)];
+    while let Some(&(nk, _)) = trail.last() {
+        let i = searched
+            .entry(nk)
+            .expect("trail nodes are valid")
+            .or_insert(0);
+        let outgoing = graph.outgoing(nk);
+        while *i < outgoing.len() && used.contains_key(outgoing[*i].0) {
+            *i += 1;
+        }
+        if let Some(&(ek, next)) = outgoing.get(*i) {
+            used.insert(ek, ());
+            trail.push((next, Some(ek)));
+        } else {
+            // a node without unused edges is finished, and the finished edges form the path in reverse
+            let (_, ek) = trail.pop().expect("the trail is not empty");
+            path.extend(ek);
+        }
+    }
+    // edges in another component were never reached
+    if path.len() != graph.edge_len() {
+        return None;
+    }
+    path.reverse();
+    Some(path)
+}
@@ -0,0 +1,225 @@
+use std::{
+    collections::VecDeque,
+    ops::{Add, Mul, Sub},
+};
+
+use slotmap::{Key, SecondaryMap};
+
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// A step along an augmenting path: the edge, the node it is traversed from, and whether it is traversed along its
+/// direction, adding flow, or against it, cancelling flow.
+type Step<K> = (EdgeKey<K>, NodeKey<K>, bool);
+
+/// Finds a shortest path from the source to the sink in the residual graph, returning the step used to reach every
+/// node on it, or `None` if the sink cannot be reached.
+fn augmenting_path<K, N, E, W>(
+    graph: &SlotGraph<K, N, E>,
+    source: NodeKey<K>,
+    sink: NodeKey<K>,
+    capacities: &SecondaryMap<EdgeKey<K>, W>,
+    flows: &SecondaryMap<EdgeKey<K>, W>,
+) -> Option<SecondaryMap<NodeKey<K>, Step<K>>>
+where
+    K: Key,
+    W: Ord + Copy + Default,
+{
+    let mut reached_by = SecondaryMap::new();
+    let mut queue = VecDeque::from([source]);
+    while let Some(nk) = queue.pop_front() {
+        if nk == sink {
+            return Some(reached_by);
+        }
+        let forward = graph
+            .outgoing(nk)
+            .iter()
+            .filter(|&&(ek, _)| flows[ek] < capacities[ek])
+            .map(|&(ek, next)| (ek, next, true));
+        let backward = graph
+            .incoming(nk)
+            .iter()
+            .filter(|&&(ek, _)| flows[ek] > W::default())
+            .map(|&(ek, prev)| (ek, prev, false));
+        for (ek, next, along) in forward.chain(backward) {
+            if next != source && !reached_by.contains_key(next) {
+                reached_by.insert(next, (ek, nk, along));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Computes a maximum flow from the source to the sink with the Edmonds-Karp algorithm, where every edge can carry up
+/// to the capacity returned by `capacity`, returning the total flow and the flow along every edge.
+///
+/// Capacities must not be negative. Returns no flow if the source and the sink are the same node or either is not in
+/// the graph.
+pub fn max_flow<K, N, E, W, F>(
+    graph: &SlotGraph<K, N, E>,
+    source: NodeKey<K>,
+    sink: NodeKey<K>,
+    mut capacity: F,
+) -> (W, SecondaryMap<EdgeKey<K>, W>)
+where
+    K: Key,
+    W: Ord + Add<Output = W> + Sub<Output = W> + Copy + Default,
+    F: FnMut(EdgeKey<K>, &E) -> W,
+{
+    let capacities: SecondaryMap<_, _> = graph
+        .iter_edges()
+        .map(|(ek, value)| (ek, capacity(ek, value)))
+        .collect();
+    let mut flows: SecondaryMap<_, _> = capacities.keys().map(|ek| (ek, W::default())).collect();
+    let mut total = W::default();
+    if source == sink || graph.get_node(source).is_none() || graph.get_node(sink).is_none() {
+        return (total, flows);
+    }
+
+    while let Some(reached_by) = augmenting_path(graph, source, sink, &capacities, &flows) {
+        let mut steps = Vec::new();
+        let mut nk = sink;
+        while nk != source {
+            let step = reached_by[nk];
+            steps.push(step);
+            nk = step.1;
+        }
+        let bottleneck = steps
+            .iter()
+            .map(|&(ek, _, along)| {
+                if along {
+                    capacities[ek] - flows[ek]
+                } else {
+                    flows[ek]
+                }
+            })
+            .min()
+            .expect("the source and sink differ");
+        for (ek, _, along) in steps {
+            flows[ek] = if along {
+                flows[ek] + bottleneck
+            } else {
+                flows[ek] - bottleneck
+            };
+        }
+        total = total + bottleneck;
+    }
+    (total, flows)
+}
+
+/// A flow of minimum cost, as computed by [`min_cost_flow`].
+#[derive(Clone, Debug)]
+pub struct MinCostFlow<K: Key, W> {
+    /// The total flow from the source to the sink.
+    pub flow: W,
+    /// The total cost of the flow: the sum over all edges of their flow times their cost.
+    pub cost: W,
+    /// The flow along every edge.
+    pub flows: SecondaryMap<EdgeKey<K>, W>,
+}
+
+/// Computes a maximum flow from the source to the sink of minimum total cost, where every edge can carry up to the
+/// capacity returned by `capacity` at the cost per unit of flow returned by `cost`.
+///
+/// Augments the flow along cheapest paths in the residual graph, found with the Bellman-Ford algorithm, so costs may be
+/// negative as long as no cycle has a negative total cost. Capacities must not be negative. Returns no flow if the
+/// source and the sink are the same node or either is not in the graph.
+pub fn min_cost_flow<K, N, E, W, FC, FW>(
+    graph: &SlotGraph<K, N, E>,
+    source: NodeKey<K>,
+    sink: NodeKey<K>,
+    mut capacity: FC,
+    mut cost: FW,
+) -> MinCostFlow<K, W>
+where
+    K: Key,
+    W: Ord + Add<Output = W> + Sub<Output = W> + Mul<Output = W> + Copy + Default,
+    FC: FnMut(EdgeKey<K>, &E) -> W,
+    FW: FnMut(EdgeKey<K>, &E) -> W,
+{
+    let zero = W::default();
+    let capacities: SecondaryMap<_, _> = graph
+        .iter_edges()
+        .map(|(ek, value)| (ek, capacity(ek, value)))
+        .collect();
+    let costs: SecondaryMap<_, _> = graph
+        .iter_edges()
+        .map(|(ek, value)| (ek, cost(ek, value)))
+        .collect();
+    let mut result = MinCostFlow {
+        flow: zero,
+        cost: zero,
+        flows: capacities.keys().map(|ek| (ek, zero)).collect(),
+    };
+    if source == sink || graph.get_node(source).is_none() || graph.get_node(sink).is_none() {
+        return result;
+    }
+
+    loop {
+        // Bellman-Ford over the residual graph, where cancelling flow refunds its cost
+        let mut distances = SecondaryMap::new();
+        let mut reached_by: SecondaryMap<NodeKey<K>, Step<K>> = SecondaryMap::new();
+        distances.insert(source, zero);
+        for _ in 0..graph.node_len() {
+            let mut relaxed = false;
+            for (nk, _) in graph.iter_nodes() {
+                let Some(&distance) = distances.get(nk) else {
+                    continue;
+                };
+                let forward = graph
+                    .outgoing(nk)
+                    .iter()
+                    .filter(|&&(ek, _)| result.flows[ek] < capacities[ek])
+                    .map(|&(ek, next)| (ek, next, true, costs[ek]));
+                let backward = graph
+                    .incoming(nk)
+                    .iter()
+                    .filter(|&&(ek, _)| result.flows[ek] > zero)
+                    .map(|&(ek, prev)| (ek, prev, false, zero - costs[ek]));
+                for (ek, next, along, step_cost) in forward.chain(backward) {
+                    let next_distance = distance + step_cost;
+                    if distances.get(next).is_none_or(|&d| next_distance < d) {
+                        distances.insert(next, next_distance);
+                        reached_by.insert(next, (ek, nk, along));
+                        relaxed = true;
+                    }
+                }
+            }
+            if !relaxed {
+                break;
+            }
+        }
+        let Some(&path_cost) = distances.get(sink) else {
+            return result;
+        };
+
+        let mut steps = Vec::new();
+        let mut nk = sink;
+        while nk != source {
+            let step = reached_by[nk];
+            steps.push(step);
+            nk = step.1;
+        }
+        let bottleneck = steps
+            .iter()
+            .map(|&(ek, _, along)| {
+                if along {
+                    capacities[ek] - result.flows[ek]
+                } else {
+                    result.flows[ek]
+                }
+            })
+            .min()
+            .expect("the source and sink differ");
+        for (ek, _, along) in steps {
+            let flow = &mut result.flows[ek];
+            *flow = if along {
+                *flow + bottleneck
+            } else {
+                *flow - bottleneck
+            };
+        }
+        result.flow = result.flow + bottleneck;
+        result.cost = result.cost + bottleneck * path_cost;
+    }
+}
@@ -0,0 +1,392 @@
+use std::collections::HashSet;
+
+use slotmap::{Key, SecondaryMap};
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// Whether every node and edge of the host has to be matched, or the pattern may match part of it.
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Mode {
+    Isomorphism,
+    Subgraph,
+}
+
+/// The host nodes a pattern node may be mapped to, and how many of them have been tried.
+struct Frame<K: Key> {
+    candidates: Vec<NodeKey<K>>,
+    tried: usize,
+}
+
+/// A VF2-style backtracking search for mappings of the nodes of a pattern graph to the nodes of a host graph that
+/// preserve values and edges, producing every mapping in turn.
+struct Vf2<'a, K1: Key, N1, E1, K2: Key, N2, E2, Ty: EdgeType, FN, FE> {
+    pattern: &'a SlotGraph<K1, N1, E1, Ty>,
+    host: &'a SlotGraph<K2, N2, E2, Ty>,
+    node_match: FN,
+    edge_match: FE,
+    mode: Mode,
+    /// The pattern nodes in the order they are mapped, each with an earlier neighbor its candidates are drawn from.
+    order: Vec<(NodeKey<K1>, Option<NodeKey<K1>>)>,
+    mapping: SecondaryMap<NodeKey<K1>, NodeKey<K2>>,
+    used: SecondaryMap<NodeKey<K2>, ()>,
+    stack: Vec<Frame<K2>>,
+    started: bool,
+}
+
+impl<'a, K1, N1, E1, K2, N2, E2, Ty, FN, FE> Vf2<'a, K1, N1, E1, K2, N2, E2, Ty, FN, FE>
+where
+    K1: Key,
+    K2: Key,
+    Ty: EdgeType,
+    FN: FnMut(&N1, &N2) -> bool,
+    FE: FnMut(&E1, &E2) -> bool,
+{
+    fn new(
+        pattern: &'a SlotGraph<K1, N1, E1, Ty>,
+        host: &'a SlotGraph<K2, N2, E2, Ty>,
+        node_match: FN,
+        edge_match: FE,
+        mode: Mode,
+    ) -> Self {
+        // breadth-first over every component, so every node but the first of a component has a mapped neighbor
+        let mut order = Vec::with_capacity(pattern.node_len());
+        let mut seen = SecondaryMap::with_capacity(pattern.node_len());
+        for (root, _) in pattern.iter_nodes() {
+            if seen.insert(root, ()).is_some() {
+                continue;
+            }
+            let first = order.len();
+            order.push((root, None));
+            let mut i = first;
+            while i < order.len() {
+                let nk = order[i].0;
+                for next in pattern.neighbors(nk) {
+                    if seen.insert(next, ()).is_none() {
+                        order.push((next, Some(nk)));
+                    }
+                }
+                i += 1;
+            }
+        }
+        let sizes_fit = match mode {
+            Mode::Isomorphism => {
+                pattern.node_len() == host.node_len() && pattern.edge_len() == host.edge_len()
+            }
+            Mode::Subgraph => {
+                pattern.node_len() <= host.node_len() && pattern.edge_len() <= host.edge_len()
+            }
+        };
+        Self {
+            pattern,
+            host,
+            node_match,
+            edge_match,
+            mode,
+            order,
+            mapping: SecondaryMap::new(),
+            used: SecondaryMap::new(),
+            stack: Vec::new(),
+            // a search that cannot succeed is finished from the start
+            started: !sizes_fit,
+        }
+    }
+
+    /// Returns the host nodes the pattern node at the given position could be mapped to.
+    fn candidates(&self, depth: usize) -> Vec<NodeKey<K2>> {
+        let candidates: Vec<_> = match self.order[depth].1 {
+            Some(anchor) => {
+                let mut seen = HashSet::new();
+                self.host
+                    .neighbors(self.mapping[anchor])
+                    .filter(|&m| seen.insert(m))
+                    .collect()
+            }
+            None => self.host.iter_nodes().map(|(m, _)| m).collect(),
+        };
+        candidates
+            .into_iter()
+            .filter(|&m| !self.used.contains_key(m))
+            .collect()
+    }
+
+    /// Returns whether the pattern node can be mapped to the host node given the nodes mapped so far.
+    fn feasible(&mut self, n: NodeKey<K1>, m: NodeKey<K2>) -> bool {
+        let (pattern, host) = (self.pattern, self.host);
+        let degrees_fit = |a: usize, b: usize| match self.mode {
+            Mode::Isomorphism => a == b,
+            Mode::Subgraph => a <= b,
+        };
+        if !degrees_fit(pattern.outgoing(n).len(), host.outgoing(m).len())
+            || !degrees_fit(pattern.incoming(n).len(), host.incoming(m).len())
+        {
+            return false;
+        }
+        let values = (
+            pattern.get_node(n).expect("pattern nodes are valid"),
+            host.get_node(m).expect("host nodes are valid"),
+        );
+        if !(self.node_match)(values.0, values.1) {
+            return false;
+        }
+
+        // the node is treated as mapped already, so self-loops are checked like edges to mapped nodes
+        let image = |nk: NodeKey<K1>| {
+            if nk == n {
+                Some(m)
+            } else {
+                self.mapping.get(nk).copied()
+            }
+        };
+        let mapped = |mk: NodeKey<K2>| mk == m || self.used.contains_key(mk);
+        let directions: &[bool] = if Ty::is_directed() {
+            &[true, false]
+        } else {
+            &[true]
+        };
+        for &outgoing in directions {
+            let (pattern_adjacent, host_adjacent) = if outgoing {
+                (pattern.outgoing(n), host.outgoing(m))
+            } else {
+                (pattern.incoming(n), host.incoming(m))
+            };
+            // in isomorphism mode, the host node may not have edges to mapped nodes the pattern node lacks
+            if self.mode == Mode::Isomorphism {
+                let pattern_count = pattern_adjacent
+                    .iter()
+                    .filter(|&&(_, nk)| image(nk).is_some())
+                    .count();
+                let host_count = host_adjacent.iter().filter(|&&(_, mk)| mapped(mk)).count();
+                if pattern_count != host_count {
+                    return false;
+                }
+            }
+            let mut checked = HashSet::new();
+            for &(_, other) in pattern_adjacent {
+                let Some(other_image) = image(other) else {
+                    continue;
+                };
+                if !checked.insert(other) {
+                    continue;
+                }
+                let pattern_edges: Vec<_> = pattern_adjacent
+                    .iter()
+                    .filter(|&&(_, nk)| nk == other)
+                    .map(|&(ek, _)| ek)
+                    .collect();
+                let host_edges: Vec<_> = host_adjacent
+                    .iter()
+                    .filter(|&&(_, mk)| mk == other_image)
+                    .map(|&(ek, _)| ek)
+                    .collect();
+                if !edges_fit(
+                    pattern,
+                    host,
+                    &mut self.edge_match,
+                    self.mode,
+                    &pattern_edges,
+                    &host_edges,
+                ) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Returns the next complete mapping, or `None` once every mapping has been produced.
+    fn next_mapping(&mut self) -> Option<&SecondaryMap<NodeKey<K1>, NodeKey<K2>>> {
+        if !self.started {
+            self.started = true;
+            if self.order.is_empty() {
+                return Some(&self.mapping);
+            }
+            let candidates = self.candidates(0);
+            self.stack.push(Frame {
+                candidates,
+                tried: 0,
+            });
+        }
+        while let Some(frame) = self.stack.last() {
+            let depth = self.stack.len() - 1;
+            let (n, _) = self.order[depth];
+            if let Some(previous) = self.mapping.remove(n) {
+                self.used.remove(previous);
+            }
+            let mut tried = frame.tried;
+            let mut found = None;
+            while let Some(&m) = self.stack[depth].candidates.get(tried) {
+                tried += 1;
+                if self.feasible(n, m) {
+                    found = Some(m);
+                    break;
+                }
+            }
+            self.stack[depth].tried = tried;
+            let Some(m) = found else {
+                self.stack.pop();
+                continue;
+            };
+            self.mapping.insert(n, m);
+            self.used.insert(m, ());
+            if depth + 1 == self.order.len() {
+                return Some(&self.mapping);
+            }
+            let candidates = self.candidates(depth + 1);
+            self.stack.push(Frame {
+                candidates,
+                tried: 0,
+            });
+        }
+        None
+    }
+}
+
+/// Returns whether every edge of the pattern can be matched to a distinct edge of the host, and in isomorphism mode
+/// whether there are as many of both.
+fn edges_fit<K1: Key, N1, E1, K2: Key, N2, E2, Ty: EdgeType>(
+    pattern: &SlotGraph<K1, N1, E1, Ty>,
+    host: &SlotGraph<K2, N2, E2, Ty>,
+    edge_match: &mut impl FnMut(&E1, &E2) -> bool,
+    mode: Mode,
+    pattern_edges: &[EdgeKey<K1>],
+    host_edges: &[EdgeKey<K2>],
+) -> bool {
+    if pattern_edges.len() > host_edges.len()
+        || (mode == Mode::Isomorphism && pattern_edges.len() != host_edges.len())
+    {
+        return false;
+    }
+    // parallel edges may match in different ways, so they are paired with augmenting paths
+    let compatible: Vec<Vec<usize>> = pattern_edges
+        .iter()
+        .map(|&pe| {
+            let value = pattern.get_edge(pe).expect("adjacent edges are valid");
+            let host_values = host_edges
+                .iter()
+                .map(|&he| host.get_edge(he).expect("adjacent edges are valid"));
+            host_values
+                .enumerate()
+                .filter(|&(_, host_value)| edge_match(value, host_value))
+                .map(|(h, _)| h)
+                .collect()
+        })
+        .collect();
+    let mut owner = vec![None; host_edges.len()];
+    (0..pattern_edges.len()).all(|p| {
+        let mut visited = vec![false; host_edges.len()];
+        augment(p, &compatible, &mut owner, &mut visited)
+    })
+}
+
+/// Tries to pair the pattern edge with a host edge, moving other pattern edges to different host edges as needed.
+fn augment(
+    p: usize,
+    compatible: &[Vec<usize>],
+    owner: &mut [Option<usize>],
+    visited: &mut [bool],
+) -> bool {
+    for &h in &compatible[p] {
+        if visited[h] {
+            continue;
+        }
+        visited[h] = true;
+        if owner[h].is_none_or(|q| augment(q, compatible, owner, visited)) {
+            owner[h] = Some(p);
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns whether the graphs are isomorphic: whether their nodes can be mapped one-to-one so that every pair of
+/// nodes has as many edges between them in each direction as the pair they are mapped to.
+///
+/// Values are ignored. Uses a VF2-style backtracking search, which is fast for most graphs but takes exponential time
+/// in the worst case.
+pub fn is_isomorphic<K1, N1, E1, K2, N2, E2, Ty>(
+    a: &SlotGraph<K1, N1, E1, Ty>,
+    b: &SlotGraph<K2, N2, E2, Ty>,
+) -> bool
+where
+    K1: Key,
+    K2: Key,
+    Ty: EdgeType,
+{
+    is_isomorphic_matching(a, b, |_, _| true, |_, _| true)
+}
+
+/// Returns whether the graphs are isomorphic, like [`is_isomorphic`], with a mapping under which `node_match` accepts
+/// every pair of node values and `edge_match` accepts every pair of edge values.
+pub fn is_isomorphic_matching<K1, N1, E1, K2, N2, E2, Ty, FN, FE>(
+    a: &SlotGraph<K1, N1, E1, Ty>,
+    b: &SlotGraph<K2, N2, E2, Ty>,
+    node_match: FN,
+    edge_match: FE,
+) -> bool
+where
+    K1: Key,
+    K2: Key,
+    Ty: EdgeType,
+    FN: FnMut(&N1, &N2) -> bool,
+    FE: FnMut(&E1, &E2) -> bool,
+{
+    Vf2::new(a, b, node_match, edge_match, Mode::Isomorphism)
+        .next_mapping()
+        .is_some()
+}
+
+/// Returns whether the graphs are equal up to their keys: whether they are isomorphic, like [`is_isomorphic`], with a
+/// mapping under which every node and edge has an equal value.
+///
+/// Unlike `==`, which compares graphs by key, this also accepts graphs built separately or with different key types.
+pub fn is_isomorphic_keyed<K1, K2, N, E, Ty>(
+    a: &SlotGraph<K1, N, E, Ty>,
+    b: &SlotGraph<K2, N, E, Ty>,
+) -> bool
+where
+    K1: Key,
+    K2: Key,
+    N: PartialEq,
+    E: PartialEq,
+    Ty: EdgeType,
+{
+    is_isomorphic_matching(a, b, N::eq, E::eq)
+}
+
+/// Returns every mapping of the nodes of the pattern to distinct nodes of the host under which each edge of the
+/// pattern corresponds to a distinct edge of the host between the images of its nodes, in the same direction.
+///
+/// The host may have more edges between mapped nodes than the pattern does, so the pattern matches any part of the
+/// host containing it. Values are ignored. Mappings are produced lazily, by the same search as [`is_isomorphic`];
+/// a pattern with symmetries matches the same part of the host once for each of them.
+pub fn subgraph_isomorphisms<'a, K1, N1, E1, K2, N2, E2, Ty>(
+    pattern: &'a SlotGraph<K1, N1, E1, Ty>,
+    host: &'a SlotGraph<K2, N2, E2, Ty>,
+) -> impl Iterator<Item = SecondaryMap<NodeKey<K1>, NodeKey<K2>>> + 'a
+where
+    K1: Key,
+    K2: Key,
+    Ty: EdgeType,
+{
+    subgraph_isomorphisms_matching(pattern, host, |_, _| true, |_, _| true)
+}
+
+/// Returns every mapping of the pattern into the host, like [`subgraph_isomorphisms`], under which `node_match`
+/// accepts every pair of node values and `edge_match` accepts every pair of edge values.
+pub fn subgraph_isomorphisms_matching<'a, K1, N1, E1, K2, N2, E2, Ty, FN, FE>(
+    pattern: &'a SlotGraph<K1, N1, E1, Ty>,
+    host: &'a SlotGraph<K2, N2, E2, Ty>,
+    node_match: FN,
+    edge_match: FE,
+) -> impl Iterator<Item = SecondaryMap<NodeKey<K1>, NodeKey<K2>>> + 'a
+where
+    K1: Key,
+    K2: Key,
+    Ty: EdgeType,
+    FN: FnMut(&N1, &N2) -> bool + 'a,
+    FE: FnMut(&E1, &E2) -> bool + 'a,
+{
+    let mut vf2 = Vf2::new(pattern, host, node_match, edge_match, Mode::Subgraph);
+    std::iter::from_fn(move || vf2.next_mapping().cloned())
+}
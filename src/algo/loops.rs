@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use slotmap::{Key, SecondaryMap};
+
+use super::dominators::dominators;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// A natural loop of a control-flow graph, as found by [`natural_loops`].
+#[derive(Clone, Debug)]
+pub struct NaturalLoop<K: Key> {
+    /// The node every path into the loop enters through, which dominates the whole loop.
+    pub header: NodeKey<K>,
+    /// The edges pointing back to the header from inside the loop.
+    pub back_edges: Vec<EdgeKey<K>>,
+    /// The nodes of the loop, including the header.
+    pub body: HashSet<NodeKey<K>>,
+    /// The edges pointing from a node of the loop to a node outside it, in arbitrary order.
+    pub exits: Vec<EdgeKey<K>>,
+}
+
+/// Finds the natural loops of the part of the graph reachable from the entry node, one per loop header.
+///
+/// A back edge is an edge to a node that dominates the node it points from. The loop of a header consists of the
+/// header and every node that reaches one of its back edges without passing through it, so loops with the same header
+/// are merged. Loops are returned in order of their headers in node iteration order; nested loops have separate
+/// entries, with the inner body a subset of the outer one.
+pub fn natural_loops<K: Key, N, E>(
+    graph: &SlotGraph<K, N, E>,
+    entry: NodeKey<K>,
+) -> Vec<NaturalLoop<K>> {
+    let idom = dominators(graph, entry);
+    let dominates = |a: NodeKey<K>, mut b: NodeKey<K>| loop {
+        if a == b {
+            return true;
+        }
+        if b == entry {
+            return false;
+        }
+        b = idom[b];
+    };
+
+    let mut back_edges: SecondaryMap<NodeKey<K>, Vec<EdgeKey<K>>> = SecondaryMap::new();
+    for (nk, _) in graph.iter_nodes().filter(|&(nk, _)| idom.contains_key(nk)) {
+        for &(ek, header) in graph.outgoing(nk) {
+            if dominates(header, nk) {
+                back_edges
+                    .entry(header)
+                    .expect("headers are valid")
+                    .or_default()
+                    .push(ek);
+            }
+        }
+    }
+
+    let mut loops = Vec::with_capacity(back_edges.len());
+    for (header, _) in graph.iter_nodes() {
+        let Some(edges) = back_edges.remove(header) else {
+            continue;
+        };
+        let mut body = HashSet::from([header]);
+        let mut stack: Vec<_> = edges
+            .iter()
+            .map(|&ek| graph.get_edge_nodes(ek).expect("back edges are valid").0)
+            .collect();
+        while let Some(nk) = stack.pop() {
+            if body.insert(nk) {
+                // predecessors not reachable from the entry cannot be in the loop, since the header dominates it
+                let preds = graph.incoming(nk).iter().map(|&(_, pred)| pred);
+                stack.extend(preds.filter(|&pred| idom.contains_key(pred)));
+            }
+        }
+        let exits = body
+            .iter()
+            .flat_map(|&nk| graph.outgoing(nk))
+            .filter(|&&(_, next)| !body.contains(&next))
+            .map(|&(ek, _)| ek)
+            .collect();
+        loops.push(NaturalLoop {
+            header,
+            back_edges: edges,
+            body,
+            exits,
+        });
+    }
+    loops
+}
@@ -0,0 +1,122 @@
+use std::collections::VecDeque;
+
+use slotmap::{Key, SecondaryMap};
+
+use crate::slotgraph::{NodeKey, SlotGraph};
+
+/// The error returned by [`toposort`] when the graph contains a cycle.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Cycle<N>(N);
+
+impl<N: Copy> Cycle<N> {
+    /// Returns a node on the cycle.
+    pub fn node_id(&self) -> N {
+        self.0
+    }
+}
+
+/// Returns the nodes of the graph in topological order, so every edge points from a node to a later node.
+///
+/// Nodes without a path between them are ordered by their position in the graph's iteration order. Returns a node on
+/// a cycle if the graph is not acyclic; a self-loop is a cycle.
+pub fn toposort<K: Key, N, E>(
+    graph: &SlotGraph<K, N, E>,
+) -> Result<Vec<NodeKey<K>>, Cycle<NodeKey<K>>> {
+    let mut in_degree = graph.in_degrees();
+    let mut ready: VecDeque<_> = in_degree
+        .iter()
+        .filter(|&(_, &d)| d == 0)
+        .map(|(k, _)| k)
+        .collect();
+    let mut order = Vec::with_capacity(graph.node_len());
+    while let Some(nk) = ready.pop_front() {
+        order.push(nk);
+        for &(_, next) in graph.outgoing(nk) {
+            let d = &mut in_degree[next];
+            *d -= 1;
+            if *d == 0 {
+                ready.push_back(next);
+            }
+        }
+    }
+    if order.len() == graph.node_len() {
+        return Ok(order);
+    }
+
+    // every unordered node has an unordered predecessor, so walking back through them must revisit a node, which is
+    // on a cycle
+    let (mut nk, _) = in_degree
+        .iter()
+        .find(|&(_, &d)| d > 0)
+        .expect("a node is unordered");
+    let mut walked = SecondaryMap::new();
+    while walked.insert(nk, ()).is_none() {
+        nk = graph
+            .incoming(nk)
+            .iter()
+            .map(|&(_, prev)| prev)
+            .find(|&prev| in_degree[prev] > 0)
+            .expect("an unordered node has an unordered predecessor");
+    }
+    Err(Cycle(nk))
+}
+
+impl<K: Key, N, E> SlotGraph<K, N, E> {
+    /// Groups the nodes into batches such that the predecessors of every node are all in earlier batches, so all
+    /// nodes in a batch can be resolved concurrently once the previous batches are done.
+    ///
+    /// Returns the nodes that could not be resolved if the graph contains a cycle: the nodes on cycles and every node
+    /// depending on them.
+    pub fn resolution_order(&self) -> Result<Vec<Vec<NodeKey<K>>>, Vec<NodeKey<K>>> {
+        let mut in_degree = self.in_degrees();
+        let mut batch: Vec<NodeKey<K>> = in_degree
+            .iter()
+            .filter(|&(_, &d)| d == 0)
+            .map(|(k, _)| k)
+            .collect();
+        let mut batches = Vec::new();
+        let mut resolved = 0;
+        while !batch.is_empty() {
+            let mut next_batch = Vec::new();
+            for &nk in &batch {
+                for &(_, next) in self.outgoing(nk) {
+                    let d = &mut in_degree[next];
+                    *d -= 1;
+                    if *d == 0 {
+                        next_batch.push(next);
+                    }
+                }
+            }
+            resolved += batch.len();
+            batches.push(std::mem::replace(&mut batch, next_batch));
+        }
+
+        if resolved == self.node_len() {
+            Ok(batches)
+        } else {
+            Err(in_degree
+                .into_iter()
+                .filter(|&(_, d)| d > 0)
+                .map(|(k, _)| k)
+                .collect())
+        }
+    }
+
+    /// Assigns every node the length of the longest chain of predecessors ending at it, so nodes without incoming
+    /// edges have depth 0.
+    ///
+    /// Returns the nodes that could not be assigned a depth if the graph contains a cycle, like
+    /// [`SlotGraph::resolution_order`].
+    pub fn dependency_depth(&self) -> Result<SecondaryMap<NodeKey<K>, usize>, Vec<NodeKey<K>>> {
+        // a node lands in the batch after the one holding its last resolved predecessor, so the batch index is the
+        // length of the longest chain of predecessors
+        let batches = self.resolution_order()?;
+        let mut depth = SecondaryMap::with_capacity(self.node_len());
+        for (i, batch) in batches.into_iter().enumerate() {
+            for nk in batch {
+                depth.insert(nk, i);
+            }
+        }
+        Ok(depth)
+    }
+}
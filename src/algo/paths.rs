@@ -0,0 +1,63 @@
+use std::collections::VecDeque;
+
+use slotmap::{Key, SecondaryMap};
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Returns the node keys along a path with the fewest edges from one node to another, including both ends.
+    ///
+    /// Returns `None` if either node key is stale or if there is no such path.
+    pub fn shortest_path(&self, from: NodeKey<K>, to: NodeKey<K>) -> Option<Vec<NodeKey<K>>> {
+        let steps = self.shortest_path_steps(from, to)?;
+        let mut path = Vec::with_capacity(steps.len() + 1);
+        path.push(from);
+        path.extend(steps.iter().map(|&(_, nk)| nk));
+        Some(path)
+    }
+
+    /// Returns the edge keys along a path with the fewest edges from one node to another.
+    ///
+    /// When there are parallel edges between two nodes on the path, any one of them is chosen.
+    /// Returns `None` if either node key is stale or if there is no such path.
+    pub fn shortest_path_edges(&self, from: NodeKey<K>, to: NodeKey<K>) -> Option<Vec<EdgeKey<K>>> {
+        let steps = self.shortest_path_steps(from, to)?;
+        Some(steps.into_iter().map(|(ek, _)| ek).collect())
+    }
+
+    /// Returns every edge along a path with the fewest edges together with the node it leads to.
+    fn shortest_path_steps(
+        &self,
+        from: NodeKey<K>,
+        to: NodeKey<K>,
+    ) -> Option<Vec<(EdgeKey<K>, NodeKey<K>)>> {
+        if self.get_node(from).is_none() || self.get_node(to).is_none() {
+            return None;
+        }
+        // the edge used to first reach each node and the node it was followed from
+        let mut reached_by = SecondaryMap::new();
+        reached_by.insert(from, None);
+        let mut queue = VecDeque::from([from]);
+        while let Some(nk) = queue.pop_front() {
+            if nk == to {
+                break;
+            }
+            for &(ek, next) in self.outgoing(nk) {
+                if !reached_by.contains_key(next) {
+                    reached_by.insert(next, Some((ek, nk)));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut path = Vec::new();
+        let mut nk = to;
+        while let Some((ek, prev)) = *reached_by.get(nk)? {
+            path.push((ek, nk));
+            nk = prev;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
@@ -0,0 +1,61 @@
+use rand::Rng;
+use slotmap::Key;
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Walks from the start node along outgoing edges for at most `steps` steps, returning the visited nodes
+    /// including the start node.
+    ///
+    /// At every step an outgoing edge is chosen with a probability proportional to its weight. Edges with a
+    /// non-positive or non-finite weight are never chosen. The walk stops early at a node without any such edge.
+    /// Returns an empty path if the start node key is stale.
+    pub fn random_walk<F, R>(
+        &self,
+        start: NodeKey<K>,
+        steps: usize,
+        weight: F,
+        rng: &mut R,
+    ) -> Vec<NodeKey<K>>
+    where
+        F: Fn(EdgeKey<K>, &E) -> f64,
+        R: Rng + ?Sized,
+    {
+        if self.get_node(start).is_none() {
+            return Vec::new();
+        }
+        let mut path = vec![start];
+        let mut weights = Vec::new();
+        let mut current = start;
+        for _ in 0..steps {
+            weights.clear();
+            weights.extend(self.outgoing(current).iter().map(|&(ek, _)| {
+                let w = weight(ek, &self.edge_values()[ek]);
+                if w.is_finite() && w > 0.0 {
+                    w
+                } else {
+                    0.0
+                }
+            }));
+            let total: f64 = weights.iter().sum();
+            if total <= 0.0 {
+                break;
+            }
+
+            let mut target = rng.gen::<f64>() * total;
+            // fall back to the last edge with a positive weight in case of rounding errors
+            let mut chosen = weights.iter().rposition(|&w| w > 0.0).unwrap_or(0);
+            for (i, &w) in weights.iter().enumerate() {
+                if w > 0.0 && target < w {
+                    chosen = i;
+                    break;
+                }
+                target -= w;
+            }
+            current = self.outgoing(current)[chosen].1;
+            path.push(current);
+        }
+        path
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+
+use slotmap::Key;
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// Collects every node reachable from `start` through one or more steps along the adjacency lists returned by `adj`.
+fn reachable<'a, K: Key + 'a, F>(adj: F, start: NodeKey<K>) -> HashSet<NodeKey<K>>
+where
+    F: Fn(NodeKey<K>) -> &'a [(EdgeKey<K>, NodeKey<K>)],
+{
+    let mut reached = HashSet::new();
+    let mut stack = vec![start];
+    while let Some(nk) = stack.pop() {
+        for &(_, next) in adj(nk) {
+            if reached.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+    reached
+}
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Returns every node reachable from the given node by following outgoing edges.
+    ///
+    /// The node itself is only included if it lies on a cycle.
+    pub fn descendants(&self, source: NodeKey<K>) -> HashSet<NodeKey<K>> {
+        reachable(|nk| self.outgoing(nk), source)
+    }
+
+    /// Returns every node from which the given node is reachable by following outgoing edges.
+    ///
+    /// The node itself is only included if it lies on a cycle.
+    pub fn can_reach(&self, target: NodeKey<K>) -> HashSet<NodeKey<K>> {
+        reachable(|nk| self.incoming(nk), target)
+    }
+
+    /// Returns whether there is a path from one node to another by following outgoing edges.
+    ///
+    /// Every existing node has a path to itself.
+    pub fn has_path(&self, from: NodeKey<K>, to: NodeKey<K>) -> bool {
+        if self.get_node(from).is_none() || self.get_node(to).is_none() {
+            return false;
+        }
+        if from == to {
+            return true;
+        }
+        let mut reached = HashSet::from([from]);
+        let mut stack = vec![from];
+        while let Some(nk) = stack.pop() {
+            for &(_, next) in self.outgoing(nk) {
+                if next == to {
+                    return true;
+                }
+                if reached.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+        false
+    }
+
+    /// Returns whether both nodes are in the same strongly connected component, i.e. are reachable from each other.
+    pub fn in_same_scc(&self, a: NodeKey<K>, b: NodeKey<K>) -> bool {
+        self.has_path(a, b) && self.has_path(b, a)
+    }
+
+    /// Removes every node that is not reachable from any of the roots, returning the removed nodes and the edges
+    /// between them as a new graph with the same keys.
+    ///
+    /// Edges between a reachable and an unreachable node are dropped.
+    pub fn split_off_unreachable(&mut self, roots: &[NodeKey<K>]) -> Self {
+        let mut reached: HashSet<NodeKey<K>> = roots
+            .iter()
+            .copied()
+            .filter(|&k| self.get_node(k).is_some())
+            .collect();
+        let mut stack: Vec<NodeKey<K>> = reached.iter().copied().collect();
+        while let Some(nk) = stack.pop() {
+            for &(_, next) in self.outgoing(nk) {
+                if reached.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+        self.split_off_nodes(|k| !reached.contains(&k))
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use slotmap::{DefaultKey, Key, SecondaryMap};
+
+use super::traversal::post_order;
+use crate::adjacency::Adjacency;
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// The search state of a node visited by Tarjan's algorithm.
+struct Visit {
+    index: usize,
+    low_link: usize,
+    on_stack: bool,
+}
+
+/// Returns the strongly connected components of the graph, in reverse topological order: no edge points from a
+/// component to a later one.
+///
+/// Uses Tarjan's algorithm without recursion, so deep graphs do not overflow the stack.
+pub fn tarjan_scc<K: Key, N, E, Ty: EdgeType>(
+    graph: &SlotGraph<K, N, E, Ty>,
+) -> Vec<Vec<NodeKey<K>>> {
+    let mut visits: SecondaryMap<NodeKey<K>, Visit> = SecondaryMap::with_capacity(graph.node_len());
+    let mut components = Vec::new();
+    let mut stack = Vec::new();
+    // every entry is a node on the search path and the number of its outgoing edges searched so far
+    let mut path = Vec::new();
+    for (root, _) in graph.iter_nodes() {
+        if visits.contains_key(root) {
+            continue;
+        }
+        path.push((root, 0));
+        while let Some((nk, i)) = path.last_mut() {
+            let nk = *nk;
+            if *i == 0 && !visits.contains_key(nk) {
+                let index = visits.len();
+                visits.insert(
+                    nk,
+                    Visit {
+                        index,
+                        low_link: index,
+                        on_stack: true,
+                    },
+                );
+                stack.push(nk);
+            }
+            if let Some(&(_, next)) = graph.outgoing(nk).get(*i) {
+                *i += 1;
+                match visits.get(next) {
+                    None => path.push((next, 0)),
+                    Some(v) if v.on_stack => {
+                        let index = v.index;
+                        let low_link = &mut visits[nk].low_link;
+                        *low_link = (*low_link).min(index);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            path.pop();
+            let Visit {
+                index, low_link, ..
+            } = visits[nk];
+            if let Some(&(parent, _)) = path.last() {
+                let parent_low_link = &mut visits[parent].low_link;
+                *parent_low_link = (*parent_low_link).min(low_link);
+            }
+            if low_link == index {
+                let mut component = Vec::new();
+                loop {
+                    let member = stack.pop().expect("the node is on the stack");
+                    visits[member].on_stack = false;
+                    component.push(member);
+                    if member == nk {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+        }
+    }
+    components
+}
+
+/// Returns the strongly connected components of the graph, in topological order: no edge points from a component to
+/// an earlier one.
+///
+/// Uses Kosaraju's algorithm, which searches the graph once forwards and once backwards. Runs on a [`SlotGraph`] or
+/// its [`TransposeView`](crate::TransposeView), which has the same components in a topological order of the reversed
+/// edges.
+pub fn kosaraju_scc<K: Key, G: Adjacency<K>>(graph: &G) -> Vec<Vec<NodeKey<K>>> {
+    let finished = post_order(graph, graph.node_keys());
+    let mut assigned = SecondaryMap::with_capacity(finished.len());
+    let mut components = Vec::new();
+    // the node finished last is in a source component, which a backward search cannot leave
+    for &root in finished.iter().rev() {
+        if assigned.insert(root, ()).is_some() {
+            continue;
+        }
+        let mut component = vec![root];
+        let mut searched = 0;
+        while let Some(&nk) = component.get(searched) {
+            searched += 1;
+            for &(_, prev) in graph.incoming(nk) {
+                if assigned.insert(prev, ()).is_none() {
+                    component.push(prev);
+                }
+            }
+        }
+        components.push(component);
+    }
+    components
+}
+
+/// Assigns every node the index of its strongly connected component in the result of [`tarjan_scc`].
+pub fn tarjan_scc_ids<K: Key, N, E, Ty: EdgeType>(
+    graph: &SlotGraph<K, N, E, Ty>,
+) -> SecondaryMap<NodeKey<K>, usize> {
+    let mut ids = SecondaryMap::with_capacity(graph.node_len());
+    for (id, component) in tarjan_scc(graph).into_iter().enumerate() {
+        for nk in component {
+            ids.insert(nk, id);
+        }
+    }
+    ids
+}
+
+/// Builds the condensation of the graph: an acyclic graph with a node for every strongly connected component, holding
+/// its members, and a single edge between two components for all edges between their members, holding their keys.
+///
+/// Components are inserted in topological order, and edges within a component are left out.
+pub fn condensation<K: Key, N, E>(
+    graph: &SlotGraph<K, N, E>,
+) -> SlotGraph<DefaultKey, Vec<NodeKey<K>>, Vec<EdgeKey<K>>> {
+    let mut components = tarjan_scc(graph);
+    components.reverse();
+    let mut condensed = SlotGraph::new();
+    let mut component_of = SecondaryMap::with_capacity(graph.node_len());
+    for members in components {
+        let ck = condensed.insert_node(members);
+        for &nk in condensed
+            .get_node(ck)
+            .expect("the component was just inserted")
+        {
+            component_of.insert(nk, ck);
+        }
+    }
+
+    let mut between = HashMap::new();
+    for (ek, from, to, _) in graph.iter_edges_full() {
+        let (from, to) = (component_of[from], component_of[to]);
+        if from == to {
+            continue;
+        }
+        let ck = *between
+            .entry((from, to))
+            .or_insert_with(|| condensed.insert_edge(from, to, Vec::new()));
+        condensed
+            .get_edge_mut(ck)
+            .expect("the edge was inserted")
+            .push(ek);
+    }
+    condensed
+}
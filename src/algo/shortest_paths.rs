@@ -0,0 +1,243 @@
+use std::{cmp::Reverse, collections::BinaryHeap, ops::Add};
+
+use slotmap::{Key, SecondaryMap};
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// The shortest distances from a start node, as computed by [`dijkstra`] or [`bellman_ford`], and the edges they are
+/// reached by.
+#[derive(Clone, Debug)]
+pub struct ShortestPaths<K: Key, W> {
+    /// The length of the shortest path to every reached node.
+    pub distances: SecondaryMap<NodeKey<K>, W>,
+    /// The last edge of the shortest path to every reached node except the start, and the node it is followed from.
+    pub predecessors: SecondaryMap<NodeKey<K>, (EdgeKey<K>, NodeKey<K>)>,
+}
+
+impl<K: Key, W> ShortestPaths<K, W> {
+    /// Returns the node keys along the shortest path to the target, starting at the start node and including the
+    /// target, or `None` if it was not reached.
+    pub fn path_to(&self, target: NodeKey<K>) -> Option<Vec<NodeKey<K>>> {
+        let steps = self.steps_to(target)?;
+        let mut path: Vec<_> = steps.iter().map(|&(_, prev)| prev).collect();
+        path.push(target);
+        Some(path)
+    }
+
+    /// Returns the edge keys along the shortest path to the target, or `None` if it was not reached.
+    pub fn path_edges_to(&self, target: NodeKey<K>) -> Option<Vec<EdgeKey<K>>> {
+        let steps = self.steps_to(target)?;
+        Some(steps.into_iter().map(|(ek, _)| ek).collect())
+    }
+
+    /// Returns every edge along the shortest path to the target together with the node it is followed from.
+    fn steps_to(&self, target: NodeKey<K>) -> Option<Vec<(EdgeKey<K>, NodeKey<K>)>> {
+        if !self.distances.contains_key(target) {
+            return None;
+        }
+        let mut steps = Vec::new();
+        let mut nk = target;
+        while let Some(&(ek, prev)) = self.predecessors.get(nk) {
+            steps.push((ek, prev));
+            nk = prev;
+        }
+        steps.reverse();
+        Some(steps)
+    }
+}
+
+/// Computes the shortest paths from the start node with Dijkstra's algorithm, where every edge has the cost returned
+/// by `edge_cost` and a path costs the sum of its edges, starting from `W::default()`.
+///
+/// If a goal is given, the search stops once the shortest path to it is known, so only nodes closer than the goal are
+/// guaranteed to have their shortest distance. Edge costs must not be negative. Returns no paths if the start node is
+/// not in the graph.
+pub fn dijkstra<K, N, E, Ty, W, F>(
+    graph: &SlotGraph<K, N, E, Ty>,
+    start: NodeKey<K>,
+    goal: Option<NodeKey<K>>,
+    mut edge_cost: F,
+) -> ShortestPaths<K, W>
+where
+    K: Key,
+    Ty: EdgeType,
+    W: Ord + Add<Output = W> + Copy + Default,
+    F: FnMut(EdgeKey<K>, &E) -> W,
+{
+    let mut paths = ShortestPaths {
+        distances: SecondaryMap::new(),
+        predecessors: SecondaryMap::new(),
+    };
+    if graph.get_node(start).is_none() {
+        return paths;
+    }
+    let mut done = SecondaryMap::new();
+    paths.distances.insert(start, W::default());
+    let mut heap = BinaryHeap::from([Reverse((W::default(), start))]);
+    while let Some(Reverse((distance, nk))) = heap.pop() {
+        if done.insert(nk, ()).is_some() {
+            continue;
+        }
+        if Some(nk) == goal {
+            break;
+        }
+        for &(ek, next) in graph.outgoing(nk) {
+            if done.contains_key(next) {
+                continue;
+            }
+            let value = graph.get_edge(ek).expect("adjacent edges are valid");
+            let next_distance = distance + edge_cost(ek, value);
+            if paths.distances.get(next).is_none_or(|&d| next_distance < d) {
+                paths.distances.insert(next, next_distance);
+                paths.predecessors.insert(next, (ek, nk));
+                heap.push(Reverse((next_distance, next)));
+            }
+        }
+    }
+    paths
+}
+
+/// Finds a cheapest path from the start node to a node satisfying `is_goal` with the A* algorithm, returning its cost
+/// and the node keys along it, including both ends.
+///
+/// Every edge has the cost returned by `edge_cost`, and `heuristic` estimates the cost of the cheapest path from a
+/// node to a goal. The path found is the cheapest if the estimate never exceeds the actual cost. Edge costs must not be
+/// negative. Returns `None` if the start node is not in the graph or no goal is reachable.
+pub fn astar<K, N, E, Ty, W, G, F, H>(
+    graph: &SlotGraph<K, N, E, Ty>,
+    start: NodeKey<K>,
+    mut is_goal: G,
+    mut edge_cost: F,
+    mut heuristic: H,
+) -> Option<(W, Vec<NodeKey<K>>)>
+where
+    K: Key,
+    Ty: EdgeType,
+    W: Ord + Add<Output = W> + Copy + Default,
+    G: FnMut(NodeKey<K>) -> bool,
+    F: FnMut(EdgeKey<K>, &E) -> W,
+    H: FnMut(NodeKey<K>) -> W,
+{
+    graph.get_node(start)?;
+    let mut costs = SecondaryMap::new();
+    let mut predecessors = SecondaryMap::new();
+    costs.insert(start, W::default());
+    let mut heap = BinaryHeap::from([Reverse((heuristic(start), W::default(), start))]);
+    while let Some(Reverse((_, cost, nk))) = heap.pop() {
+        // a node is queued again whenever a cheaper path to it is found, so older entries are skipped
+        if costs[nk] < cost {
+            continue;
+        }
+        if is_goal(nk) {
+            let mut path = vec![nk];
+            let mut prev = nk;
+            while let Some(&p) = predecessors.get(prev) {
+                path.push(p);
+                prev = p;
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+        for &(ek, next) in graph.outgoing(nk) {
+            let value = graph.get_edge(ek).expect("adjacent edges are valid");
+            let next_cost = cost + edge_cost(ek, value);
+            if costs.get(next).is_none_or(|&c| next_cost < c) {
+                costs.insert(next, next_cost);
+                predecessors.insert(next, nk);
+                heap.push(Reverse((next_cost + heuristic(next), next_cost, next)));
+            }
+        }
+    }
+    None
+}
+
+/// The error returned by [`bellman_ford`] when a cycle of negative total cost is reachable from the start node, so
+/// some nodes have no shortest path.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct NegativeCycle<K: Key>(Vec<NodeKey<K>>);
+
+impl<K: Key> NegativeCycle<K> {
+    /// Returns the nodes on the cycle in order; the last node has an edge to the first.
+    pub fn nodes(&self) -> &[NodeKey<K>] {
+        &self.0
+    }
+}
+
+/// Computes the shortest paths from the start node with the Bellman-Ford algorithm, where every edge has the cost
+/// returned by `edge_cost`, which may be negative, and a path costs the sum of its edges, starting from `W::default()`.
+///
+/// Takes time proportional to the number of nodes times the number of edges. Returns no paths if the start node is not
+/// in the graph.
+///
+/// # Errors
+///
+/// Returns a [`NegativeCycle`] if a cycle with negative total cost is reachable from the start node. In an undirected
+/// graph, every edge with a negative cost is such a cycle.
+pub fn bellman_ford<K, N, E, Ty, W, F>(
+    graph: &SlotGraph<K, N, E, Ty>,
+    start: NodeKey<K>,
+    mut edge_cost: F,
+) -> Result<ShortestPaths<K, W>, NegativeCycle<K>>
+where
+    K: Key,
+    Ty: EdgeType,
+    W: Ord + Add<Output = W> + Copy + Default,
+    F: FnMut(EdgeKey<K>, &E) -> W,
+{
+    let mut paths = ShortestPaths {
+        distances: SecondaryMap::new(),
+        predecessors: SecondaryMap::new(),
+    };
+    if graph.get_node(start).is_none() {
+        return Ok(paths);
+    }
+    paths.distances.insert(start, W::default());
+    let costs: SecondaryMap<EdgeKey<K>, W> = graph
+        .iter_edges()
+        .map(|(ek, value)| (ek, edge_cost(ek, value)))
+        .collect();
+
+    // relaxes every edge leaving a reached node, returning the last node whose distance decreased
+    let relax = |paths: &mut ShortestPaths<K, W>| {
+        let mut relaxed = None;
+        for (nk, _) in graph.iter_nodes() {
+            let Some(&distance) = paths.distances.get(nk) else {
+                continue;
+            };
+            for &(ek, next) in graph.outgoing(nk) {
+                let next_distance = distance + costs[ek];
+                if paths.distances.get(next).is_none_or(|&d| next_distance < d) {
+                    paths.distances.insert(next, next_distance);
+                    paths.predecessors.insert(next, (ek, nk));
+                    relaxed = Some(next);
+                }
+            }
+        }
+        relaxed
+    };
+    // a shortest path has fewer edges than there are nodes, so distances still decreasing after that many rounds
+    // means a negative cycle
+    for _ in 1..graph.node_len() {
+        if relax(&mut paths).is_none() {
+            return Ok(paths);
+        }
+    }
+    let Some(mut nk) = relax(&mut paths) else {
+        return Ok(paths);
+    };
+
+    // the predecessors of a node whose distance kept decreasing lead into a negative cycle within as many steps as
+    // there are nodes
+    for _ in 0..graph.node_len() {
+        nk = paths.predecessors[nk].1;
+    }
+    let mut cycle = vec![nk];
+    let mut prev = paths.predecessors[nk].1;
+    while prev != nk {
+        cycle.push(prev);
+        prev = paths.predecessors[prev].1;
+    }
+    cycle.reverse();
+    Err(NegativeCycle(cycle))
+}
@@ -0,0 +1,31 @@
+use slotmap::Key;
+
+use crate::slotgraph::SlotGraph;
+
+impl<K: Key, N, E> SlotGraph<K, N, E> {
+    /// Collapses every chain `a -> m -> b` through a node `m` with exactly one incoming and one outgoing edge into a
+    /// single edge `a -> b`, removing `m`.
+    ///
+    /// The value of the new edge is computed by `merge` from the value of the incoming edge, the value of the removed
+    /// node and the value of the outgoing edge, in that order, so a chain of any length folds into a single edge.
+    /// Nodes with a self-loop are never removed.
+    pub fn simplify_chains<F: FnMut(E, N, E) -> E>(&mut self, mut merge: F) {
+        let candidates: Vec<_> = self.iter_nodes().map(|(k, _)| k).collect();
+        for m in candidates {
+            let (&[(in_edge, a)], &[(out_edge, b)]) = (self.incoming(m), self.outgoing(m)) else {
+                continue;
+            };
+            if a == m || b == m {
+                continue;
+            }
+            let (Some(in_value), Some(out_value), Some(node_value)) = (
+                self.remove_edge(in_edge),
+                self.remove_edge(out_edge),
+                self.remove_node(m),
+            ) else {
+                continue;
+            };
+            self.insert_edge(a, b, merge(in_value, node_value, out_value));
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+};
+
+use slotmap::{Key, SecondaryMap};
+
+use super::union_find::UnionFind;
+use crate::slotgraph::{EdgeKey, NodeKey, UnSlotGraph};
+
+/// Returns whether a weight can be ordered, that is, whether it is comparable with itself, which rules out NaN.
+fn comparable<W: PartialOrd>(weight: &W) -> bool {
+    weight.partial_cmp(weight).is_some()
+}
+
+/// Returns the edges of a minimum spanning forest of the graph, in order of increasing weight: a spanning tree of
+/// every connected component whose total weight returned by `weight` is as small as possible.
+///
+/// Uses Kruskal's algorithm. Edges of equal weight are considered in edge iteration order, and self-loops are never
+/// selected. Edges whose weight is not comparable with itself, such as a NaN float, are skipped.
+pub fn min_spanning_tree<K, N, E, W, F>(
+    graph: &UnSlotGraph<K, N, E>,
+    mut weight: F,
+) -> Vec<EdgeKey<K>>
+where
+    K: Key,
+    W: PartialOrd,
+    F: FnMut(EdgeKey<K>, &E) -> W,
+{
+    let mut edges: Vec<_> = graph
+        .iter_edges_full()
+        .map(|(ek, from, to, value)| (weight(ek, value), ek, from, to))
+        .filter(|(w, ..)| comparable(w))
+        .collect();
+    edges.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    let mut forest = UnionFind::new(graph.iter_nodes().map(|(k, _)| k));
+    edges
+        .into_iter()
+        .filter(|&(_, _, from, to)| forest.union(from, to))
+        .map(|(_, ek, _, _)| ek)
+        .collect()
+}
+
+/// A candidate edge of [`min_spanning_tree_prim`], ordered by weight and then by the order it was found in.
+struct Candidate<K: Key, W> {
+    weight: W,
+    order: usize,
+    edge: EdgeKey<K>,
+    to: NodeKey<K>,
+}
+
+impl<K: Key, W: PartialOrd> PartialEq for Candidate<K, W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<K: Key, W: PartialOrd> Eq for Candidate<K, W> {}
+
+impl<K: Key, W: PartialOrd> PartialOrd for Candidate<K, W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Key, W: PartialOrd> Ord for Candidate<K, W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // only comparable weights become candidates, so this is a total order
+        self.weight
+            .partial_cmp(&other.weight)
+            .unwrap_or(Ordering::Equal)
+            .then(self.order.cmp(&other.order))
+    }
+}
+
+/// Returns the edges of a minimum spanning forest of the graph like [`min_spanning_tree`], using Prim's algorithm.
+///
+/// Every tree is grown from its first node in node iteration order, and its edges are returned in the order they join
+/// it, trees in the order of their first nodes. Edges of equal weight are taken in the order they are found, and
+/// `weight` is called at most once per edge, only for edges leaving a tree. Self-loops are never selected, and edges
+/// whose weight is not comparable with itself, such as a NaN float, are skipped.
+pub fn min_spanning_tree_prim<K, N, E, W, F>(
+    graph: &UnSlotGraph<K, N, E>,
+    mut weight: F,
+) -> Vec<EdgeKey<K>>
+where
+    K: Key,
+    W: PartialOrd,
+    F: FnMut(EdgeKey<K>, &E) -> W,
+{
+    let mut in_tree = SecondaryMap::with_capacity(graph.node_len());
+    let mut heap = BinaryHeap::new();
+    let mut order = 0;
+    let mut tree = Vec::new();
+    for root in graph.node_keys() {
+        if in_tree.insert(root, ()).is_some() {
+            continue;
+        }
+        let mut nk = root;
+        loop {
+            // every edge leaves the tree when its first node joins, so it becomes a candidate at most once
+            for &(ek, next) in graph.outgoing(nk) {
+                if in_tree.contains_key(next) {
+                    continue;
+                }
+                let w = weight(ek, graph.get_edge(ek).expect("adjacent edges are valid"));
+                if comparable(&w) {
+                    heap.push(Reverse(Candidate {
+                        weight: w,
+                        order,
+                        edge: ek,
+                        to: next,
+                    }));
+                    order += 1;
+                }
+            }
+            let Some(next) = std::iter::from_fn(|| heap.pop())
+                .map(|Reverse(candidate)| candidate)
+                .find(|candidate| !in_tree.contains_key(candidate.to))
+            else {
+                break;
+            };
+            in_tree.insert(next.to, ());
+            tree.push(next.edge);
+            nk = next.to;
+        }
+    }
+    tree
+}
@@ -0,0 +1,258 @@
+use std::collections::VecDeque;
+
+use slotmap::{Key, SecondaryMap};
+
+use crate::adjacency::Adjacency;
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Performs a breadth-first search from the start node, calling the closure with the key and both node keys of
+    /// every outgoing edge of each visited node in the order the search discovers them.
+    ///
+    /// Both tree edges and edges to already discovered nodes are reported, each exactly once. In an undirected graph
+    /// an edge is reported from whichever of its nodes is visited first, with that node first.
+    pub fn for_each_edge_bfs<F: FnMut(EdgeKey<K>, NodeKey<K>, NodeKey<K>)>(
+        &self,
+        start: NodeKey<K>,
+        mut f: F,
+    ) {
+        if self.get_node(start).is_none() {
+            return;
+        }
+        let mut discovered = SecondaryMap::new();
+        discovered.insert(start, ());
+        let mut reported = SecondaryMap::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(nk) = queue.pop_front() {
+            for &(ek, next) in self.outgoing(nk) {
+                if !Ty::is_directed() && reported.insert(ek, ()).is_some() {
+                    continue;
+                }
+                f(ek, nk, next);
+                if discovered.insert(next, ()).is_none() {
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+}
+
+/// A lazy breadth-first traversal of a [`SlotGraph`], yielding every node reachable from its seed nodes once, in order
+/// of discovery.
+///
+/// Nodes are reached through their outgoing edges, which in an undirected graph are all their edges. The seeds are
+/// yielded first, in the order given.
+pub struct Bfs<'a, K: Key, N, E, Ty: EdgeType> {
+    graph: &'a SlotGraph<K, N, E, Ty>,
+    discovered: SecondaryMap<NodeKey<K>, ()>,
+    queue: VecDeque<NodeKey<K>>,
+}
+
+impl<'a, K: Key, N, E, Ty: EdgeType> Bfs<'a, K, N, E, Ty> {
+    /// Creates a traversal starting from a single node.
+    pub fn new(graph: &'a SlotGraph<K, N, E, Ty>, start: NodeKey<K>) -> Self {
+        Self::from_seeds(graph, [start])
+    }
+
+    /// Creates a traversal starting from all given nodes at once. Seeds that are not in the graph are ignored.
+    pub fn from_seeds<I: IntoIterator<Item = NodeKey<K>>>(
+        graph: &'a SlotGraph<K, N, E, Ty>,
+        seeds: I,
+    ) -> Self {
+        let mut discovered = SecondaryMap::new();
+        let queue = seeds
+            .into_iter()
+            .filter(|&nk| graph.get_node(nk).is_some() && discovered.insert(nk, ()).is_none())
+            .collect();
+        Self {
+            graph,
+            discovered,
+            queue,
+        }
+    }
+
+    /// Returns whether the node has been discovered, that is, yielded or queued to be yielded.
+    pub fn is_discovered(&self, key: NodeKey<K>) -> bool {
+        self.discovered.contains_key(key)
+    }
+}
+
+impl<K: Key, N, E, Ty: EdgeType> Iterator for Bfs<'_, K, N, E, Ty> {
+    type Item = NodeKey<K>;
+
+    fn next(&mut self) -> Option<NodeKey<K>> {
+        let nk = self.queue.pop_front()?;
+        for &(_, next) in self.graph.outgoing(nk) {
+            if self.discovered.insert(next, ()).is_none() {
+                self.queue.push_back(next);
+            }
+        }
+        Some(nk)
+    }
+}
+
+/// A lazy depth-first traversal of a [`SlotGraph`], yielding every node reachable from its seed nodes once, in
+/// pre-order.
+///
+/// The outgoing edges of a node are followed in order, and the seeds are searched from in the order given.
+pub struct Dfs<'a, K: Key, N, E, Ty: EdgeType> {
+    graph: &'a SlotGraph<K, N, E, Ty>,
+    visited: SecondaryMap<NodeKey<K>, ()>,
+    stack: Vec<NodeKey<K>>,
+}
+
+impl<'a, K: Key, N, E, Ty: EdgeType> Dfs<'a, K, N, E, Ty> {
+    /// Creates a traversal starting from a single node.
+    pub fn new(graph: &'a SlotGraph<K, N, E, Ty>, start: NodeKey<K>) -> Self {
+        Self::from_seeds(graph, [start])
+    }
+
+    /// Creates a traversal starting from each given node in turn. Seeds that are not in the graph are ignored.
+    pub fn from_seeds<I: IntoIterator<Item = NodeKey<K>>>(
+        graph: &'a SlotGraph<K, N, E, Ty>,
+        seeds: I,
+    ) -> Self {
+        let mut stack: Vec<_> = seeds
+            .into_iter()
+            .filter(|&nk| graph.get_node(nk).is_some())
+            .collect();
+        stack.reverse();
+        Self {
+            graph,
+            visited: SecondaryMap::new(),
+            stack,
+        }
+    }
+
+    /// Returns whether the node has been yielded.
+    pub fn is_visited(&self, key: NodeKey<K>) -> bool {
+        self.visited.contains_key(key)
+    }
+}
+
+impl<K: Key, N, E, Ty: EdgeType> Iterator for Dfs<'_, K, N, E, Ty> {
+    type Item = NodeKey<K>;
+
+    fn next(&mut self) -> Option<NodeKey<K>> {
+        while let Some(nk) = self.stack.pop() {
+            if self.visited.insert(nk, ()).is_some() {
+                continue;
+            }
+            let unvisited = self
+                .graph
+                .outgoing(nk)
+                .iter()
+                .rev()
+                .map(|&(_, next)| next)
+                .filter(|&next| !self.visited.contains_key(next));
+            self.stack.extend(unvisited);
+            return Some(nk);
+        }
+        None
+    }
+}
+
+/// A lazy depth-first traversal of a [`SlotGraph`], yielding every node reachable from its seed nodes once, in
+/// post-order: a node is yielded after all nodes first discovered through it.
+///
+/// Reversing the order gives a reverse post-order, which is a topological order of an acyclic graph.
+pub struct DfsPostOrder<'a, K: Key, N, E, Ty: EdgeType> {
+    graph: &'a SlotGraph<K, N, E, Ty>,
+    discovered: SecondaryMap<NodeKey<K>, ()>,
+    seeds: std::vec::IntoIter<NodeKey<K>>,
+    // every node on the path being searched, with the number of its outgoing edges already followed
+    stack: Vec<(NodeKey<K>, usize)>,
+}
+
+impl<'a, K: Key, N, E, Ty: EdgeType> DfsPostOrder<'a, K, N, E, Ty> {
+    /// Creates a traversal starting from a single node.
+    pub fn new(graph: &'a SlotGraph<K, N, E, Ty>, start: NodeKey<K>) -> Self {
+        Self::from_seeds(graph, [start])
+    }
+
+    /// Creates a traversal starting from each given node in turn. Seeds that are not in the graph are ignored.
+    pub fn from_seeds<I: IntoIterator<Item = NodeKey<K>>>(
+        graph: &'a SlotGraph<K, N, E, Ty>,
+        seeds: I,
+    ) -> Self {
+        let seeds: Vec<_> = seeds
+            .into_iter()
+            .filter(|&nk| graph.get_node(nk).is_some())
+            .collect();
+        Self {
+            graph,
+            discovered: SecondaryMap::new(),
+            seeds: seeds.into_iter(),
+            stack: Vec::new(),
+        }
+    }
+
+    /// Returns whether the node has been discovered, that is, yielded or on the path being searched.
+    pub fn is_discovered(&self, key: NodeKey<K>) -> bool {
+        self.discovered.contains_key(key)
+    }
+}
+
+impl<K: Key, N, E, Ty: EdgeType> Iterator for DfsPostOrder<'_, K, N, E, Ty> {
+    type Item = NodeKey<K>;
+
+    fn next(&mut self) -> Option<NodeKey<K>> {
+        loop {
+            let Some((nk, followed)) = self.stack.last_mut() else {
+                let seed = self.seeds.find(|&nk| !self.discovered.contains_key(nk))?;
+                self.discovered.insert(seed, ());
+                self.stack.push((seed, 0));
+                continue;
+            };
+            let nk = *nk;
+            match self.graph.outgoing(nk).get(*followed) {
+                Some(&(_, next)) => {
+                    *followed += 1;
+                    if self.discovered.insert(next, ()).is_none() {
+                        self.stack.push((next, 0));
+                    }
+                }
+                None => {
+                    self.stack.pop();
+                    return Some(nk);
+                }
+            }
+        }
+    }
+}
+
+/// Collects the depth-first post-order of [`DfsPostOrder`] for any [`Adjacency`], such as a [`TransposeView`].
+///
+/// [`TransposeView`]: crate::TransposeView
+pub(crate) fn post_order<K: Key, G: Adjacency<K>>(
+    graph: &G,
+    seeds: impl IntoIterator<Item = NodeKey<K>>,
+) -> Vec<NodeKey<K>> {
+    let mut order = Vec::new();
+    let mut discovered = SecondaryMap::new();
+    // every node on the path being searched, with the number of its outgoing edges already followed
+    let mut stack = Vec::new();
+    for seed in seeds {
+        if !graph.contains_node(seed) || discovered.insert(seed, ()).is_some() {
+            continue;
+        }
+        stack.push((seed, 0));
+        while let Some((nk, followed)) = stack.last_mut() {
+            let nk = *nk;
+            match graph.outgoing(nk).get(*followed) {
+                Some(&(_, next)) => {
+                    *followed += 1;
+                    if discovered.insert(next, ()).is_none() {
+                        stack.push((next, 0));
+                    }
+                }
+                None => {
+                    stack.pop();
+                    order.push(nk);
+                }
+            }
+        }
+    }
+    order
+}
@@ -0,0 +1,56 @@
+use slotmap::{Key, SecondaryMap};
+
+/// A disjoint-set forest over slotmap keys, using union by size and path splitting.
+pub(crate) struct UnionFind<K: Key> {
+    parent: SecondaryMap<K, K>,
+    size: SecondaryMap<K, usize>,
+}
+
+impl<K: Key> UnionFind<K> {
+    /// Constructs a forest where every given key is in its own set.
+    pub(crate) fn new(keys: impl IntoIterator<Item = K>) -> Self {
+        let mut parent = SecondaryMap::new();
+        let mut size = SecondaryMap::new();
+        for k in keys {
+            parent.insert(k, k);
+            size.insert(k, 1);
+        }
+        Self { parent, size }
+    }
+
+    /// Returns the representative of the set containing the key, or `None` if the key is not in the forest.
+    pub(crate) fn find(&mut self, mut k: K) -> Option<K> {
+        let mut p = *self.parent.get(k)?;
+        while p != k {
+            let grandparent = self.parent[p];
+            self.parent[k] = grandparent;
+            k = p;
+            p = grandparent;
+        }
+        Some(k)
+    }
+
+    /// Merges the sets containing both keys, returning `true` if they were in different sets.
+    pub(crate) fn union(&mut self, a: K, b: K) -> bool {
+        let (Some(mut a), Some(mut b)) = (self.find(a), self.find(b)) else {
+            return false;
+        };
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        true
+    }
+
+    /// An iterator over the sizes of all sets in arbitrary order.
+    pub(crate) fn set_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.parent
+            .iter()
+            .filter(|&(k, &p)| k == p)
+            .map(|(k, _)| self.size[k])
+    }
+}
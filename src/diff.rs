@@ -0,0 +1,75 @@
+//! Differences between two graphs sharing a key space.
+
+use slotmap::Key;
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// The node and edge keys that differ between two graphs, as computed by [`SlotGraph::diff`].
+#[derive(Clone, Default, Eq, PartialEq, Debug)]
+pub struct GraphDiff<K: Key> {
+    /// Node keys only present in the other graph.
+    pub added_nodes: Vec<NodeKey<K>>,
+    /// Node keys only present in this graph.
+    pub removed_nodes: Vec<NodeKey<K>>,
+    /// Node keys present in both graphs with different values.
+    pub changed_nodes: Vec<NodeKey<K>>,
+    /// Edge keys only present in the other graph.
+    pub added_edges: Vec<EdgeKey<K>>,
+    /// Edge keys only present in this graph.
+    pub removed_edges: Vec<EdgeKey<K>>,
+    /// Edge keys present in both graphs with different values or different node keys.
+    pub changed_edges: Vec<EdgeKey<K>>,
+}
+
+impl<K: Key> GraphDiff<K> {
+    /// Returns whether both graphs contain exactly the same nodes and edges.
+    pub fn is_empty(&self) -> bool {
+        self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.changed_nodes.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+            && self.changed_edges.is_empty()
+    }
+}
+
+impl<K: Key, N: PartialEq, E: PartialEq, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Compares this graph to another graph, reporting the nodes and edges that were added, removed or changed in
+    /// `other`.
+    ///
+    /// Both graphs must share a key space, for example because `other` is a modified clone of `self`. Keys from
+    /// unrelated graphs can collide, in which case the diff is meaningless.
+    pub fn diff(&self, other: &Self) -> GraphDiff<K> {
+        let mut diff = GraphDiff::default();
+        for (k, value) in self.iter_nodes() {
+            match other.get_node(k) {
+                None => diff.removed_nodes.push(k),
+                Some(other_value) if other_value != value => diff.changed_nodes.push(k),
+                Some(_) => {}
+            }
+        }
+        diff.added_nodes = other
+            .iter_nodes()
+            .map(|(k, _)| k)
+            .filter(|&k| self.get_node(k).is_none())
+            .collect();
+
+        for (k, from, to, value) in self.iter_edges_full() {
+            match (other.get_edge_nodes(k), other.get_edge(k)) {
+                (Some(nodes), Some(other_value)) => {
+                    if nodes != (from, to) || other_value != value {
+                        diff.changed_edges.push(k);
+                    }
+                }
+                _ => diff.removed_edges.push(k),
+            }
+        }
+        diff.added_edges = other
+            .iter_edges()
+            .map(|(k, _)| k)
+            .filter(|&k| self.get_edge(k).is_none())
+            .collect();
+        diff
+    }
+}
@@ -0,0 +1,502 @@
+//! Export and import of graphs in the Graphviz DOT language.
+
+use std::{collections::HashMap, fmt, io, iter::Peekable, str::Chars};
+
+use slotmap::{DefaultKey, Key};
+
+use crate::edge_type::{EdgeType, Undirected};
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// A closure formatting a node as a string.
+type NodeFormat<'a, K, N> = Box<dyn Fn(NodeKey<K>, &N) -> String + 'a>;
+
+/// A closure formatting an edge as a string.
+type EdgeFormat<'a, K, E> = Box<dyn Fn(EdgeKey<K>, &E) -> String + 'a>;
+
+/// A wrapper displaying a [`SlotGraph`] in the DOT language, as a `digraph` or a `graph` depending on its
+/// [`EdgeType`].
+///
+/// Nodes are identified by their index in [`SlotGraph::index_nodes`]. Labels are quoted and escaped, while the
+/// optional attribute closures return further attributes such as `color=red` that are inserted verbatim.
+pub struct Dot<'a, K: Key, N, E, Ty: EdgeType> {
+    graph: &'a SlotGraph<K, N, E, Ty>,
+    node_label: NodeFormat<'a, K, N>,
+    edge_label: EdgeFormat<'a, K, E>,
+    node_attributes: Option<NodeFormat<'a, K, N>>,
+    edge_attributes: Option<EdgeFormat<'a, K, E>>,
+}
+
+impl<'a, K: Key, N, E, Ty: EdgeType> Dot<'a, K, N, E, Ty> {
+    /// Wraps the graph, labelling its nodes and edges with the given closures.
+    pub fn new<FN, FE>(graph: &'a SlotGraph<K, N, E, Ty>, node_label: FN, edge_label: FE) -> Self
+    where
+        FN: Fn(NodeKey<K>, &N) -> String + 'a,
+        FE: Fn(EdgeKey<K>, &E) -> String + 'a,
+    {
+        Self {
+            graph,
+            node_label: Box::new(node_label),
+            edge_label: Box::new(edge_label),
+            node_attributes: None,
+            edge_attributes: None,
+        }
+    }
+
+    /// Adds the attributes returned by the closure to every node.
+    pub fn with_node_attributes<F: Fn(NodeKey<K>, &N) -> String + 'a>(mut self, f: F) -> Self {
+        self.node_attributes = Some(Box::new(f));
+        self
+    }
+
+    /// Adds the attributes returned by the closure to every edge.
+    pub fn with_edge_attributes<F: Fn(EdgeKey<K>, &E) -> String + 'a>(mut self, f: F) -> Self {
+        self.edge_attributes = Some(Box::new(f));
+        self
+    }
+}
+
+/// Writes a quoted DOT string, escaping quotes, backslashes and line breaks.
+fn write_quoted(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    f.write_str("\"")
+}
+
+/// Writes the label and any further attributes of a node or edge, followed by the end of the statement.
+fn write_attributes(
+    f: &mut fmt::Formatter<'_>,
+    label: &str,
+    attributes: Option<String>,
+) -> fmt::Result {
+    f.write_str(" [label=")?;
+    write_quoted(f, label)?;
+    match attributes {
+        Some(attributes) if !attributes.is_empty() => writeln!(f, ", {attributes}]"),
+        _ => writeln!(f, "]"),
+    }
+}
+
+impl<K: Key, N, E, Ty: EdgeType> fmt::Display for Dot<'_, K, N, E, Ty> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (keyword, edge_op) = if Ty::is_directed() {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        writeln!(f, "{keyword} {{")?;
+        let (indices, keys) = self.graph.index_nodes();
+        for (i, &k) in keys.iter().enumerate() {
+            let value = &self.graph.node_values()[k];
+            write!(f, "    {i}")?;
+            let attributes = self.node_attributes.as_ref().map(|a| a(k, value));
+            write_attributes(f, &(self.node_label)(k, value), attributes)?;
+        }
+        for (k, from, to, value) in self.graph.iter_edges_full() {
+            write!(f, "    {} {edge_op} {}", indices[from], indices[to])?;
+            let attributes = self.edge_attributes.as_ref().map(|a| a(k, value));
+            write_attributes(f, &(self.edge_label)(k, value), attributes)?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Renders the graph in the DOT language, labelling its nodes and edges with the given closures.
+    ///
+    /// Use [`Dot`] to add further attributes.
+    pub fn to_dot<FN, FE>(&self, node_label: FN, edge_label: FE) -> String
+    where
+        FN: Fn(NodeKey<K>, &N) -> String,
+        FE: Fn(EdgeKey<K>, &E) -> String,
+    {
+        Dot::new(self, node_label, edge_label).to_string()
+    }
+}
+
+/// A lexical token of the DOT language.
+#[derive(Clone, PartialEq, Debug)]
+enum Token {
+    /// An identifier, numeral, quoted string or HTML string, with whether it was quoted so it cannot be a keyword.
+    Id(String, bool),
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Equals,
+    Semicolon,
+    Comma,
+    /// An edge operator, `->` if directed and `--` otherwise.
+    Edge(bool),
+}
+
+fn invalid_data(line: usize, msg: impl fmt::Display) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("line {line}: {msg}"))
+}
+
+/// Splits DOT source into tokens, each with the line it starts on, skipping whitespace and comments.
+struct Lexer<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+}
+
+impl Lexer<'_> {
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    fn skip_line(&mut self) {
+        while self.chars.next_if(|&c| c != '\n').is_some() {}
+    }
+
+    fn skip_block_comment(&mut self) -> io::Result<()> {
+        let line = self.line;
+        let mut star = false;
+        loop {
+            match self.bump() {
+                Some('/') if star => return Ok(()),
+                Some(c) => star = c == '*',
+                None => return Err(invalid_data(line, "unterminated comment")),
+            }
+        }
+    }
+
+    fn quoted(&mut self) -> io::Result<String> {
+        let line = self.line;
+        let mut s = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(s),
+                Some('\\') => match self.bump() {
+                    Some('"') => s.push('"'),
+                    Some('\\') => s.push('\\'),
+                    Some('n') => s.push('\n'),
+                    // an escaped line break continues the string on the next line
+                    Some('\n') => {}
+                    Some(c) => {
+                        s.push('\\');
+                        s.push(c);
+                    }
+                    None => break,
+                },
+                Some(c) => s.push(c),
+                None => break,
+            }
+        }
+        Err(invalid_data(line, "unterminated string"))
+    }
+
+    fn html(&mut self) -> io::Result<String> {
+        let line = self.line;
+        let mut s = String::new();
+        let mut depth = 1;
+        while let Some(c) = self.bump() {
+            match c {
+                '<' => depth += 1,
+                '>' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                return Ok(s);
+            }
+            s.push(c);
+        }
+        Err(invalid_data(line, "unterminated HTML string"))
+    }
+
+    fn tokenize(mut self) -> io::Result<Vec<(Token, usize)>> {
+        let mut tokens = Vec::new();
+        // whether only whitespace precedes on the current line
+        let mut line_start = true;
+        while let Some(c) = self.bump() {
+            let line = self.line;
+            if c == '\n' {
+                line_start = true;
+                continue;
+            }
+            let token = match c {
+                c if c.is_whitespace() => continue,
+                // a line starting with `#` is output from the C preprocessor
+                '#' if line_start => {
+                    self.skip_line();
+                    continue;
+                }
+                '/' if self.chars.next_if_eq(&'/').is_some() => {
+                    self.skip_line();
+                    continue;
+                }
+                '/' if self.chars.next_if_eq(&'*').is_some() => {
+                    self.skip_block_comment()?;
+                    continue;
+                }
+                '{' => Token::LBrace,
+                '}' => Token::RBrace,
+                '[' => Token::LBracket,
+                ']' => Token::RBracket,
+                '=' => Token::Equals,
+                ';' => Token::Semicolon,
+                ',' => Token::Comma,
+                '-' if self.chars.next_if_eq(&'>').is_some() => Token::Edge(true),
+                '-' if self.chars.next_if_eq(&'-').is_some() => Token::Edge(false),
+                '"' => Token::Id(self.quoted()?, true),
+                '<' => Token::Id(self.html()?, true),
+                c if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' => {
+                    let mut id = c.to_string();
+                    while let Some(c) = self
+                        .chars
+                        .next_if(|&c| c.is_alphanumeric() || c == '_' || c == '.')
+                    {
+                        id.push(c);
+                    }
+                    Token::Id(id, false)
+                }
+                c => return Err(invalid_data(line, format!("unexpected character {c:?}"))),
+            };
+            line_start = false;
+            tokens.push((token, line));
+        }
+        Ok(tokens)
+    }
+}
+
+/// A graph read from DOT source, labelled with strings.
+type DotGraph<Ty> = SlotGraph<DefaultKey, String, String, Ty>;
+
+/// The map from DOT node identifiers to the keys of their nodes.
+type DotIds = HashMap<String, NodeKey<DefaultKey>>;
+
+/// Builds a graph from DOT tokens.
+struct Parser<Ty: EdgeType> {
+    tokens: std::vec::IntoIter<(Token, usize)>,
+    peeked: Option<(Token, usize)>,
+    line: usize,
+    strict: bool,
+    graph: DotGraph<Ty>,
+    ids: DotIds,
+}
+
+impl<Ty: EdgeType> Parser<Ty> {
+    fn new(input: &str) -> io::Result<Self> {
+        let lexer = Lexer {
+            chars: input.chars().peekable(),
+            line: 1,
+        };
+        Ok(Self {
+            tokens: lexer.tokenize()?.into_iter(),
+            peeked: None,
+            line: 1,
+            strict: false,
+            graph: SlotGraph::default(),
+            ids: HashMap::new(),
+        })
+    }
+
+    fn peek(&mut self) -> Option<&Token> {
+        if self.peeked.is_none() {
+            self.peeked = self.tokens.next();
+        }
+        self.peeked.as_ref().map(|(token, _)| token)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let (token, line) = self.peeked.take().or_else(|| self.tokens.next())?;
+        self.line = line;
+        Some(token)
+    }
+
+    fn error(&self, msg: impl fmt::Display) -> io::Error {
+        invalid_data(self.line, msg)
+    }
+
+    fn expect(&mut self, expected: Token) -> io::Result<()> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            found => Err(self.error(format!("expected {expected:?}, found {found:?}"))),
+        }
+    }
+
+    fn id(&mut self) -> io::Result<String> {
+        match self.next() {
+            Some(Token::Id(id, _)) => Ok(id),
+            found => Err(self.error(format!("expected an identifier, found {found:?}"))),
+        }
+    }
+
+    /// Returns whether the next token is the unquoted keyword, consuming it if so.
+    fn keyword(&mut self, keyword: &str) -> bool {
+        let is_keyword =
+            matches!(self.peek(), Some(Token::Id(id, false)) if id.eq_ignore_ascii_case(keyword));
+        if is_keyword {
+            self.next();
+        }
+        is_keyword
+    }
+
+    fn node(&mut self, id: String) -> NodeKey<DefaultKey> {
+        let graph = &mut self.graph;
+        *self
+            .ids
+            .entry(id)
+            .or_insert_with_key(|id| graph.insert_node(id.clone()))
+    }
+
+    /// Parses any number of bracketed attribute lists, returning the value of the last `label` attribute.
+    fn attributes(&mut self) -> io::Result<Option<String>> {
+        let mut label = None;
+        while self.peek() == Some(&Token::LBracket) {
+            self.next();
+            loop {
+                match self.peek() {
+                    Some(Token::RBracket) => {
+                        self.next();
+                        break;
+                    }
+                    Some(Token::Semicolon | Token::Comma) => {
+                        self.next();
+                    }
+                    _ => {
+                        let name = self.id()?;
+                        self.expect(Token::Equals)?;
+                        let value = self.id()?;
+                        if name == "label" {
+                            label = Some(value);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(label)
+    }
+
+    fn statement(&mut self) -> io::Result<()> {
+        if self.keyword("subgraph") || self.peek() == Some(&Token::LBrace) {
+            return Err(self.error("subgraphs are not supported"));
+        }
+        if self.keyword("graph") || self.keyword("node") || self.keyword("edge") {
+            self.attributes()?;
+            return Ok(());
+        }
+        let id = self.id()?;
+        if self.peek() == Some(&Token::Equals) {
+            self.next();
+            self.id()?;
+            return Ok(());
+        }
+
+        let mut chain = vec![id];
+        while let Some(&Token::Edge(directed)) = self.peek() {
+            self.next();
+            if directed != Ty::is_directed() {
+                return Err(self.error("edge operator does not match the graph type"));
+            }
+            chain.push(self.id()?);
+        }
+        let label = self.attributes()?;
+        if chain.len() == 1 {
+            let key = self.node(chain.pop().unwrap_or_default());
+            if let (Some(label), Some(value)) = (label, self.graph.get_node_mut(key)) {
+                *value = label;
+            }
+        } else {
+            let keys: Vec<_> = chain.into_iter().map(|id| self.node(id)).collect();
+            for pair in keys.windows(2) {
+                // a strict graph merges repeated edges, taking the label of the last one that has any
+                let existing = if self.strict {
+                    self.graph.find_edge(pair[0], pair[1])
+                } else {
+                    None
+                };
+                match existing {
+                    Some(ek) => {
+                        if let (Some(label), Some(value)) = (&label, self.graph.get_edge_mut(ek)) {
+                            value.clone_from(label);
+                        }
+                    }
+                    None => {
+                        let value = label.clone().unwrap_or_default();
+                        self.graph.insert_edge(pair[0], pair[1], value);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn parse(mut self) -> io::Result<(DotGraph<Ty>, DotIds)> {
+        self.strict = self.keyword("strict");
+        let directed = if self.keyword("digraph") {
+            true
+        } else if self.keyword("graph") {
+            false
+        } else {
+            return Err(self.error("expected `graph` or `digraph`"));
+        };
+        if directed != Ty::is_directed() {
+            return Err(self.error(if directed {
+                "expected an undirected `graph`, found a `digraph`"
+            } else {
+                "expected a `digraph`, found an undirected `graph`"
+            }));
+        }
+        if let Some(Token::Id(..)) = self.peek() {
+            self.next();
+        }
+        self.expect(Token::LBrace)?;
+        loop {
+            match self.peek() {
+                Some(Token::RBrace) => break,
+                Some(Token::Semicolon) => {
+                    self.next();
+                }
+                Some(_) => self.statement()?,
+                None => return Err(self.error("unexpected end of input")),
+            }
+        }
+        self.next();
+        if let Some(found) = self.next() {
+            return Err(self.error(format!("unexpected {found:?} after the graph")));
+        }
+        Ok((self.graph, self.ids))
+    }
+}
+
+impl SlotGraph<DefaultKey, String, String> {
+    /// Reads a directed graph from DOT source, returning the graph and the map from DOT node identifiers to node keys.
+    ///
+    /// Every node gets the value of its `label` attribute, or its identifier if it has none, while every edge gets
+    /// the value of its `label` attribute or an empty string. Other attributes are ignored. Nodes are created the first
+    /// time their identifier is encountered. In a `strict` graph, repeated edges between the same nodes are merged into
+    /// the first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::ErrorKind::InvalidData`] error if the source is not a valid DOT `digraph`, or if it uses
+    /// subgraphs or ports, which are not supported. Use [`SlotGraph::from_dot_undirected`] to read a `graph`.
+    pub fn from_dot(input: &str) -> io::Result<(Self, HashMap<String, NodeKey<DefaultKey>>)> {
+        Parser::new(input)?.parse()
+    }
+}
+
+impl SlotGraph<DefaultKey, String, String, Undirected> {
+    /// Reads an undirected graph from DOT source, like [`SlotGraph::from_dot`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::ErrorKind::InvalidData`] error if the source is not a valid undirected DOT `graph`, or if it
+    /// uses subgraphs or ports, which are not supported.
+    pub fn from_dot_undirected(
+        input: &str,
+    ) -> io::Result<(Self, HashMap<String, NodeKey<DefaultKey>>)> {
+        Parser::new(input)?.parse()
+    }
+}
@@ -0,0 +1,85 @@
+//! An index of the edges between every pair of nodes, kept up to date by observing a graph.
+
+use std::collections::HashMap;
+
+use slotmap::Key;
+use smallvec::SmallVec;
+
+use crate::{
+    observed::{ObservedSlotGraph, SlotGraphObserver},
+    slotgraph::{EdgeKey, NodeKey, SlotGraph},
+};
+
+/// The keys of the edges between an ordered pair of nodes, stored inline for the common case of a single edge.
+type EdgeKeys<K> = SmallVec<[EdgeKey<K>; 1]>;
+
+/// A [`SlotGraphObserver`] that maps every ordered pair of node keys to the keys of the edges between them.
+#[derive(Clone, Debug)]
+pub struct EdgeIndex<K: Key> {
+    edges: HashMap<(NodeKey<K>, NodeKey<K>), EdgeKeys<K>>,
+}
+
+impl<K: Key> Default for EdgeIndex<K> {
+    fn default() -> Self {
+        Self {
+            edges: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Key> EdgeIndex<K> {
+    /// Returns the keys of the edges pointing from one node to another, in insertion order.
+    pub fn edges_between(&self, from: NodeKey<K>, to: NodeKey<K>) -> &[EdgeKey<K>] {
+        self.edges
+            .get(&(from, to))
+            .map_or(&[], |edges| edges.as_slice())
+    }
+}
+
+impl<K: Key, N, E> SlotGraphObserver<K, N, E> for EdgeIndex<K> {
+    fn on_insert_edge(&mut self, graph: &SlotGraph<K, N, E>, key: EdgeKey<K>) {
+        if let Some(nodes) = graph.get_edge_nodes(key) {
+            self.edges.entry(nodes).or_default().push(key);
+        }
+    }
+
+    fn on_remove_edge(
+        &mut self,
+        _graph: &SlotGraph<K, N, E>,
+        key: EdgeKey<K>,
+        nodes: (NodeKey<K>, NodeKey<K>),
+        _value: &E,
+    ) {
+        if let Some(edges) = self.edges.get_mut(&nodes) {
+            edges.retain(|&mut k| k != key);
+            if edges.is_empty() {
+                self.edges.remove(&nodes);
+            }
+        }
+    }
+}
+
+/// An [`ObservedSlotGraph`] maintaining an [`EdgeIndex`], making edge existence checks between two nodes O(1).
+pub type EdgeIndexedSlotGraph<K, N, E> = ObservedSlotGraph<K, N, E, EdgeIndex<K>>;
+
+impl<K: Key, N, E> EdgeIndexedSlotGraph<K, N, E> {
+    /// Returns whether there is an edge pointing from one node to another.
+    pub fn contains_edge_between(&self, from: NodeKey<K>, to: NodeKey<K>) -> bool {
+        !self.edges_between(from, to).is_empty()
+    }
+
+    /// Returns whether there is an edge between two nodes in either direction.
+    pub fn is_neighbor(&self, a: NodeKey<K>, b: NodeKey<K>) -> bool {
+        self.contains_edge_between(a, b) || self.contains_edge_between(b, a)
+    }
+
+    /// Returns the keys of the edges pointing from one node to another, in insertion order.
+    pub fn edges_between(&self, from: NodeKey<K>, to: NodeKey<K>) -> &[EdgeKey<K>] {
+        self.observer().edges_between(from, to)
+    }
+
+    /// Returns the key of the first edge inserted among those pointing from one node to another, in constant time.
+    pub fn find_edge(&self, from: NodeKey<K>, to: NodeKey<K>) -> Option<EdgeKey<K>> {
+        self.edges_between(from, to).first().copied()
+    }
+}
@@ -0,0 +1,57 @@
+//! Reading graphs from plain-text edge lists.
+
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, BufReader, Read},
+};
+
+use slotmap::DefaultKey;
+
+use crate::slotgraph::{NodeKey, SlotGraph};
+
+impl SlotGraph<DefaultKey, (), ()> {
+    /// Reads a graph from an edge list with one whitespace-separated `src dst` pair of integer node ids per line,
+    /// returning the graph and the map from node ids to node keys.
+    ///
+    /// Nodes are created the first time their id is encountered. Blank lines and lines starting with `#` are skipped.
+    /// The input is read line by line, so it never has to be held in memory as a whole.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails, or an [`io::ErrorKind::InvalidData`] error if a line does not consist of
+    /// exactly two integer ids.
+    pub fn from_edge_reader<R: Read>(
+        reader: R,
+    ) -> io::Result<(Self, HashMap<u64, NodeKey<DefaultKey>>)> {
+        let mut reader = BufReader::new(reader);
+        let mut sg = Self::new();
+        let mut ids = HashMap::new();
+        let mut line = String::new();
+        let mut line_number = 0;
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+            line_number += 1;
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = trimmed.split_whitespace().map(str::parse::<u64>);
+            let (Some(Ok(src)), Some(Ok(dst)), None) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {line_number}: expected two integer node ids, found {trimmed:?}"),
+                ));
+            };
+            let from = *ids.entry(src).or_insert_with(|| sg.insert_node(()));
+            let to = *ids.entry(dst).or_insert_with(|| sg.insert_node(()));
+            sg.insert_edge(from, to, ());
+        }
+        Ok((sg, ids))
+    }
+}
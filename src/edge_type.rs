@@ -0,0 +1,27 @@
+//! Marker types selecting whether the edges of a [`SlotGraph`](crate::SlotGraph) are directed.
+
+/// The directedness of the edges of a graph, implemented by [`Directed`] and [`Undirected`].
+pub trait EdgeType {
+    /// Returns whether edges can only be traversed from the node they point from to the node they point to.
+    fn is_directed() -> bool;
+}
+
+/// Marks a graph whose edges point from one node to another.
+#[derive(Copy, Clone, Debug)]
+pub enum Directed {}
+
+impl EdgeType for Directed {
+    fn is_directed() -> bool {
+        true
+    }
+}
+
+/// Marks a graph whose edges connect their nodes in both directions.
+#[derive(Copy, Clone, Debug)]
+pub enum Undirected {}
+
+impl EdgeType for Undirected {
+    fn is_directed() -> bool {
+        false
+    }
+}
@@ -0,0 +1,138 @@
+//! An entry API for the values of nodes, in the style of [`HashMap::entry`](std::collections::HashMap::entry).
+
+use slotmap::Key;
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{NodeKey, RemovedNode, SlotGraph};
+
+/// The node of a key in a graph, which is either present or stale, created with [`SlotGraph::node_entry`].
+pub enum NodeEntry<'a, K: Key, N, E, Ty: EdgeType> {
+    /// The node is in the graph.
+    Occupied(OccupiedNodeEntry<'a, K, N, E, Ty>),
+    /// The key is stale, because the node has been removed or belongs to another graph.
+    Vacant(VacantNodeEntry<'a, K, N, E, Ty>),
+}
+
+/// A node that is in the graph, as part of a [`NodeEntry`].
+pub struct OccupiedNodeEntry<'a, K: Key, N, E, Ty: EdgeType> {
+    graph: &'a mut SlotGraph<K, N, E, Ty>,
+    key: NodeKey<K>,
+}
+
+/// A stale node key, as part of a [`NodeEntry`].
+pub struct VacantNodeEntry<'a, K: Key, N, E, Ty: EdgeType> {
+    graph: &'a mut SlotGraph<K, N, E, Ty>,
+    key: NodeKey<K>,
+}
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Returns the entry of the node key, for inspecting, modifying or removing the node, or inserting a node in its
+    /// place if the key is stale.
+    pub fn node_entry(&mut self, key: NodeKey<K>) -> NodeEntry<'_, K, N, E, Ty> {
+        if self.get_node(key).is_some() {
+            NodeEntry::Occupied(OccupiedNodeEntry { graph: self, key })
+        } else {
+            NodeEntry::Vacant(VacantNodeEntry { graph: self, key })
+        }
+    }
+}
+
+impl<'a, K: Key, N, E, Ty: EdgeType> NodeEntry<'a, K, N, E, Ty> {
+    /// Returns the key the entry was created with.
+    pub fn key(&self) -> NodeKey<K> {
+        match self {
+            Self::Occupied(entry) => entry.key,
+            Self::Vacant(entry) => entry.key,
+        }
+    }
+
+    /// Calls `f` with the value of the node if it is in the graph, and returns the entry.
+    pub fn and_modify<F: FnOnce(&mut N)>(mut self, f: F) -> Self {
+        if let Self::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+
+    /// Returns the key and value of the node, inserting a new node with the value if the key is stale.
+    ///
+    /// Unlike a [`HashMap`](std::collections::HashMap), a new node gets a fresh key rather than the key the entry was
+    /// created with, since stale keys are never reused, so the returned key is the only way to reach it again.
+    pub fn or_insert(self, value: N) -> (NodeKey<K>, &'a mut N) {
+        self.or_insert_with(|| value)
+    }
+
+    /// Returns the key and value of the node, inserting a new node with the value returned by `f` if the key is stale.
+    ///
+    /// See [`Self::or_insert`].
+    pub fn or_insert_with<F: FnOnce() -> N>(self, f: F) -> (NodeKey<K>, &'a mut N) {
+        match self {
+            Self::Occupied(entry) => (entry.key, entry.into_mut()),
+            Self::Vacant(entry) => entry.insert(f()),
+        }
+    }
+}
+
+impl<'a, K: Key, N, E, Ty: EdgeType> OccupiedNodeEntry<'a, K, N, E, Ty> {
+    /// Returns the key of the node.
+    pub fn key(&self) -> NodeKey<K> {
+        self.key
+    }
+
+    /// Returns a reference to the value of the node.
+    pub fn get(&self) -> &N {
+        self.graph
+            .get_node(self.key)
+            .expect("occupied entries are valid")
+    }
+
+    /// Returns a mutable reference to the value of the node.
+    pub fn get_mut(&mut self) -> &mut N {
+        self.graph
+            .get_node_mut(self.key)
+            .expect("occupied entries are valid")
+    }
+
+    /// Returns a mutable reference to the value of the node that lives as long as the borrow of the graph.
+    pub fn into_mut(self) -> &'a mut N {
+        self.graph
+            .get_node_mut(self.key)
+            .expect("occupied entries are valid")
+    }
+
+    /// Replaces the value of the node, returning the old value.
+    pub fn insert(&mut self, value: N) -> N {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the node together with every edge pointing from or to it, returning its value.
+    pub fn remove(self) -> N {
+        self.graph
+            .remove_node(self.key)
+            .expect("occupied entries are valid")
+    }
+
+    /// Removes the node together with every edge pointing from or to it, returning its value and the removed edges.
+    pub fn remove_detailed(self) -> RemovedNode<K, N, E> {
+        self.graph
+            .remove_node_detailed(self.key)
+            .expect("occupied entries are valid")
+    }
+}
+
+impl<'a, K: Key, N, E, Ty: EdgeType> VacantNodeEntry<'a, K, N, E, Ty> {
+    /// Returns the stale key the entry was created with.
+    pub fn key(&self) -> NodeKey<K> {
+        self.key
+    }
+
+    /// Inserts a new node with the value, returning its fresh key and a mutable reference to its value.
+    pub fn insert(self, value: N) -> (NodeKey<K>, &'a mut N) {
+        let key = self.graph.insert_node(value);
+        let value = self
+            .graph
+            .get_node_mut(key)
+            .expect("inserted nodes are valid");
+        (key, value)
+    }
+}
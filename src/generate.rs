@@ -0,0 +1,188 @@
+//! Generators of graphs of common shapes and, with the `rand` feature, of random graphs.
+//!
+//! Every generator returns the graph along with its node keys, in the order the nodes are numbered in its
+//! description.
+
+#[cfg(feature = "rand")]
+use std::collections::HashSet;
+
+#[cfg(feature = "rand")]
+use rand::Rng;
+use slotmap::DefaultKey;
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{NodeKey, SlotGraph};
+
+/// A generated graph without values, and its node keys in order.
+pub type Generated<Ty> = (SlotGraph<DefaultKey, (), (), Ty>, Vec<NodeKey<DefaultKey>>);
+
+/// Builds a graph with `n` nodes and the given edges between their numbers.
+fn from_pairs<Ty: EdgeType>(
+    n: usize,
+    pairs: impl IntoIterator<Item = (usize, usize)>,
+) -> Generated<Ty> {
+    let mut sg = SlotGraph::default();
+    let nodes: Vec<_> = (0..n).map(|_| sg.insert_node(())).collect();
+    for (from, to) in pairs {
+        sg.insert_edge(nodes[from], nodes[to], ());
+    }
+    (sg, nodes)
+}
+
+/// Generates a complete graph with `n` nodes, with an edge between every pair of distinct nodes.
+///
+/// Directed graphs get an edge in each direction.
+pub fn complete<Ty: EdgeType>(n: usize) -> Generated<Ty> {
+    let pairs = (0..n).flat_map(|from| {
+        let first = if Ty::is_directed() { 0 } else { from + 1 };
+        (first..n)
+            .filter(move |&to| to != from)
+            .map(move |to| (from, to))
+    });
+    from_pairs(n, pairs)
+}
+
+/// Generates a path with `n` nodes, with an edge from every node to the next.
+pub fn path<Ty: EdgeType>(n: usize) -> Generated<Ty> {
+    from_pairs(n, (1..n).map(|to| (to - 1, to)))
+}
+
+/// Generates a cycle with `n` nodes, with an edge from every node to the next and from the last node to the first.
+///
+/// A cycle with one node is a self-loop, and a cycle with two nodes has an edge in each direction between them.
+pub fn cycle<Ty: EdgeType>(n: usize) -> Generated<Ty> {
+    from_pairs(n, (0..n).map(|from| (from, (from + 1) % n)))
+}
+
+/// Generates a star with `n` nodes, with an edge from the first node to each of the others.
+pub fn star<Ty: EdgeType>(n: usize) -> Generated<Ty> {
+    from_pairs(n, (1..n).map(|to| (0, to)))
+}
+
+/// Generates a grid `width` nodes wide and `height` nodes high, with an edge from every node to the next node in its
+/// row and to the node below it.
+///
+/// Nodes are numbered row by row, so the node in column `x` of row `y` is at index `y * width + x`.
+pub fn grid<Ty: EdgeType>(width: usize, height: usize) -> Generated<Ty> {
+    let right =
+        (0..height).flat_map(|y| (1..width).map(move |x| (y * width + x - 1, y * width + x)));
+    let down = (width..width * height).map(|i| (i - width, i));
+    from_pairs(width * height, right.chain(down))
+}
+
+/// Generates an Erdős–Rényi graph G(n, p) with `n` nodes, in which every possible edge exists independently with
+/// probability `p`.
+///
+/// Possible edges are ordered pairs of distinct nodes for directed graphs and unordered pairs for undirected graphs,
+/// so there are no self-loops or parallel edges. Takes time quadratic in `n`.
+///
+/// # Panics
+///
+/// Panics if `p` is not between `0.0` and `1.0`.
+#[cfg(feature = "rand")]
+pub fn erdos_renyi<Ty: EdgeType, R: Rng + ?Sized>(n: usize, p: f64, rng: &mut R) -> Generated<Ty> {
+    let mut pairs = Vec::new();
+    for from in 0..n {
+        let first = if Ty::is_directed() { 0 } else { from + 1 };
+        for to in first..n {
+            if to != from && rng.gen_bool(p) {
+                pairs.push((from, to));
+            }
+        }
+    }
+    from_pairs(n, pairs)
+}
+
+/// Generates a Barabási–Albert graph with `n` nodes by preferential attachment.
+///
+/// The graph starts as a star with `m + 1` nodes, with edges from the first node to the others. Every further node
+/// gets edges to `m` distinct earlier nodes, each chosen with a probability proportional to its number of edges. For
+/// directed graphs, the edges point from the new node to the earlier ones.
+///
+/// # Panics
+///
+/// Panics if `m` is zero or not less than `n`.
+#[cfg(feature = "rand")]
+pub fn barabasi_albert<Ty: EdgeType, R: Rng + ?Sized>(
+    n: usize,
+    m: usize,
+    rng: &mut R,
+) -> Generated<Ty> {
+    assert!(m >= 1 && m < n, "barabasi_albert needs 1 <= m < n");
+    let mut pairs: Vec<_> = (1..=m).map(|to| (0, to)).collect();
+    // every node appears once per edge endpoint, so a uniform choice is proportional to degree
+    let mut endpoints: Vec<_> = pairs.iter().flat_map(|&(from, to)| [from, to]).collect();
+    let mut targets = HashSet::with_capacity(m);
+    for from in m + 1..n {
+        targets.clear();
+        while targets.len() < m {
+            targets.insert(endpoints[rng.gen_range(0..endpoints.len())]);
+        }
+        for &to in &targets {
+            pairs.push((from, to));
+            endpoints.extend([from, to]);
+        }
+    }
+    from_pairs(n, pairs)
+}
+
+/// Generates a Watts–Strogatz small-world graph with `n` nodes.
+///
+/// The graph starts as a ring in which every node has edges to its `k / 2` nearest successors around the ring. Then
+/// every edge is rewired with probability `beta` to point to a uniformly chosen node instead, avoiding self-loops and
+/// parallel edges; an edge that has nowhere to go is kept.
+///
+/// # Panics
+///
+/// Panics if `k` is not less than `n`, or if `beta` is not between `0.0` and `1.0`.
+#[cfg(feature = "rand")]
+pub fn watts_strogatz<Ty: EdgeType, R: Rng + ?Sized>(
+    n: usize,
+    k: usize,
+    beta: f64,
+    rng: &mut R,
+) -> Generated<Ty> {
+    assert!(k < n, "watts_strogatz needs k < n");
+    let key = |from: usize, to: usize| {
+        if Ty::is_directed() {
+            (from, to)
+        } else {
+            (from.min(to), from.max(to))
+        }
+    };
+    let mut pairs: Vec<_> = (1..=k / 2)
+        .flat_map(|offset| (0..n).map(move |from| (from, (from + offset) % n)))
+        .collect();
+    let mut existing: HashSet<_> = pairs.iter().map(|&(from, to)| key(from, to)).collect();
+    // the number of nodes every node is connected to, in the direction edges are rewired from
+    let mut connected = vec![0; n];
+    for &(from, to) in &pairs {
+        connected[from] += 1;
+        if !Ty::is_directed() {
+            connected[to] += 1;
+        }
+    }
+    for pair in &mut pairs {
+        if !rng.gen_bool(beta) {
+            continue;
+        }
+        let from = pair.0;
+        if connected[from] + 1 >= n {
+            continue;
+        }
+        let to = loop {
+            let to = rng.gen_range(0..n);
+            if to != from && !existing.contains(&key(from, to)) {
+                break to;
+            }
+        };
+        existing.remove(&key(from, pair.1));
+        existing.insert(key(from, to));
+        if !Ty::is_directed() {
+            connected[pair.1] -= 1;
+            connected[to] += 1;
+        }
+        pair.1 = to;
+    }
+    from_pairs(n, pairs)
+}
@@ -0,0 +1,336 @@
+//! Export and import of graphs in the GraphML format, as read and written by tools such as Gephi and yEd.
+
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
+
+use quick_xml::{
+    escape::{escape, resolve_predefined_entity},
+    events::{BytesStart, Event},
+    Reader, XmlVersion,
+};
+use slotmap::{DefaultKey, Key};
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+fn invalid_data(msg: impl std::fmt::Display) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.to_string())
+}
+
+/// Assigns every attribute name the id of the GraphML key declaring it, in order of first appearance.
+fn declare_keys<'a>(
+    attributes: impl Iterator<Item = &'a Vec<(String, String)>>,
+    ids: &mut HashMap<String, String>,
+    order: &mut Vec<String>,
+    next_id: &mut usize,
+) {
+    for (name, _) in attributes.flatten() {
+        if !ids.contains_key(name) {
+            ids.insert(name.clone(), format!("d{next_id}"));
+            order.push(name.clone());
+            *next_id += 1;
+        }
+    }
+}
+
+fn write_data<W: Write>(
+    w: &mut W,
+    attributes: &[(String, String)],
+    ids: &HashMap<String, String>,
+) -> io::Result<()> {
+    for (name, value) in attributes {
+        writeln!(
+            w,
+            "      <data key=\"{}\">{}</data>",
+            ids[name],
+            escape(value.as_str())
+        )?;
+    }
+    Ok(())
+}
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Writes the graph as a GraphML document, storing the attributes returned by the closures as `<data>` elements
+    /// with string-typed keys.
+    ///
+    /// Nodes are identified by `n` followed by their index in [`SlotGraph::index_nodes`].
+    pub fn to_graphml<W, FN, FE>(
+        &self,
+        mut writer: W,
+        node_attributes: FN,
+        edge_attributes: FE,
+    ) -> io::Result<()>
+    where
+        W: Write,
+        FN: Fn(NodeKey<K>, &N) -> Vec<(String, String)>,
+        FE: Fn(EdgeKey<K>, &E) -> Vec<(String, String)>,
+    {
+        let (indices, keys) = self.index_nodes();
+        let node_data: Vec<_> = keys
+            .iter()
+            .map(|&k| node_attributes(k, &self.node_values()[k]))
+            .collect();
+        let edge_data: Vec<_> = self
+            .iter_edges()
+            .map(|(k, value)| edge_attributes(k, value))
+            .collect();
+
+        // node and edge attributes are declared separately, since the same name may be used for both
+        let mut next_id = 0;
+        let (mut node_ids, mut node_names) = (HashMap::new(), Vec::new());
+        declare_keys(
+            node_data.iter(),
+            &mut node_ids,
+            &mut node_names,
+            &mut next_id,
+        );
+        let (mut edge_ids, mut edge_names) = (HashMap::new(), Vec::new());
+        declare_keys(
+            edge_data.iter(),
+            &mut edge_ids,
+            &mut edge_names,
+            &mut next_id,
+        );
+
+        let w = &mut writer;
+        writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            w,
+            r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+        )?;
+        for (domain, ids, names) in [
+            ("node", &node_ids, &node_names),
+            ("edge", &edge_ids, &edge_names),
+        ] {
+            for name in names {
+                writeln!(
+                    w,
+                    r#"  <key id="{}" for="{domain}" attr.name="{}" attr.type="string"/>"#,
+                    ids[name],
+                    escape(name.as_str())
+                )?;
+            }
+        }
+        let edgedefault = if Ty::is_directed() {
+            "directed"
+        } else {
+            "undirected"
+        };
+        writeln!(w, r#"  <graph id="G" edgedefault="{edgedefault}">"#)?;
+        for (i, data) in node_data.iter().enumerate() {
+            if data.is_empty() {
+                writeln!(w, r#"    <node id="n{i}"/>"#)?;
+            } else {
+                writeln!(w, r#"    <node id="n{i}">"#)?;
+                write_data(w, data, &node_ids)?;
+                writeln!(w, "    </node>")?;
+            }
+        }
+        for (i, ((k, _), data)) in self.iter_edges().zip(&edge_data).enumerate() {
+            let (from, to) = self.get_edge_nodes(k).expect("edge keys are valid");
+            let (from, to) = (indices[from], indices[to]);
+            if data.is_empty() {
+                writeln!(
+                    w,
+                    r#"    <edge id="e{i}" source="n{from}" target="n{to}"/>"#
+                )?;
+            } else {
+                writeln!(w, r#"    <edge id="e{i}" source="n{from}" target="n{to}">"#)?;
+                write_data(w, data, &edge_ids)?;
+                writeln!(w, "    </edge>")?;
+            }
+        }
+        writeln!(w, "  </graph>")?;
+        writeln!(w, "</graphml>")
+    }
+}
+
+/// The attributes of a node or edge, by name.
+type Attributes = HashMap<String, String>;
+
+/// A GraphML key declaration.
+struct KeyDecl {
+    name: String,
+    domain: String,
+    default: Option<String>,
+}
+
+/// An open element relevant to reading the graph.
+enum Open {
+    Key(String),
+    Default,
+    Node(String, Attributes),
+    Edge(String, String, Attributes),
+    Data(String),
+    Other,
+}
+
+/// Returns the unescaped value of an attribute of the element, if present.
+fn attribute(e: &BytesStart<'_>, name: &str) -> io::Result<Option<String>> {
+    for attr in e.attributes() {
+        let attr = attr.map_err(invalid_data)?;
+        if attr.key.local_name().as_ref() == name {
+            let value = attr
+                .normalized_value(XmlVersion::Implicit1_0)
+                .map_err(invalid_data)?;
+            return Ok(Some(value.into_owned()));
+        }
+    }
+    Ok(None)
+}
+
+fn required_attribute(e: &BytesStart<'_>, name: &str) -> io::Result<String> {
+    attribute(e, name)?.ok_or_else(|| {
+        let element = e.local_name();
+        invalid_data(format!(
+            "<{}> is missing the {name} attribute",
+            element.as_ref()
+        ))
+    })
+}
+
+/// The keys, nodes and edges collected from a GraphML document, before any node keys are assigned.
+#[derive(Default)]
+struct Document {
+    keys: HashMap<String, KeyDecl>,
+    nodes: Vec<(String, Attributes)>,
+    edges: Vec<(String, String, Attributes)>,
+    open: Vec<Open>,
+    text: String,
+}
+
+impl Document {
+    fn open(&mut self, e: &BytesStart<'_>) -> io::Result<()> {
+        let element = match e.local_name().as_ref() {
+            "key" => {
+                let id = required_attribute(e, "id")?;
+                let decl = KeyDecl {
+                    name: attribute(e, "attr.name")?.unwrap_or_else(|| id.clone()),
+                    domain: attribute(e, "for")?.unwrap_or_else(|| "all".to_string()),
+                    default: None,
+                };
+                self.keys.insert(id.clone(), decl);
+                Open::Key(id)
+            }
+            "default" => Open::Default,
+            "node" => Open::Node(required_attribute(e, "id")?, HashMap::new()),
+            "edge" => Open::Edge(
+                required_attribute(e, "source")?,
+                required_attribute(e, "target")?,
+                HashMap::new(),
+            ),
+            "data" => Open::Data(required_attribute(e, "key")?),
+            _ => Open::Other,
+        };
+        self.text.clear();
+        self.open.push(element);
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        let text = std::mem::take(&mut self.text);
+        match (self.open.pop(), self.open.last_mut()) {
+            (Some(Open::Node(id, attributes)), _) => self.nodes.push((id, attributes)),
+            (Some(Open::Edge(source, target, attributes)), _) => {
+                self.edges.push((source, target, attributes))
+            }
+            (
+                Some(Open::Data(key)),
+                Some(Open::Node(_, attributes) | Open::Edge(_, _, attributes)),
+            ) => {
+                let name = self.keys.get(&key).map_or(key, |k| k.name.clone());
+                attributes.insert(name, text);
+            }
+            (Some(Open::Default), Some(Open::Key(id))) => {
+                if let Some(key) = self.keys.get_mut(id) {
+                    key.default = Some(text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Adds the default value of every key declared for the domain that the attributes do not set.
+    fn apply_defaults(&self, attributes: &mut Attributes, domain: &str) {
+        for key in self.keys.values() {
+            if key.domain != domain && key.domain != "all" {
+                continue;
+            }
+            if let Some(default) = &key.default {
+                attributes
+                    .entry(key.name.clone())
+                    .or_insert_with(|| default.clone());
+            }
+        }
+    }
+}
+
+impl<N, E> SlotGraph<DefaultKey, N, E> {
+    /// Reads a graph from a GraphML document, returning the graph and the map from GraphML node ids to node keys.
+    ///
+    /// The value of every node is created by `node_value` from its id and its attributes, and the value of every edge
+    /// by `edge_value` from its attributes. Attributes are given by the `attr.name` of their key, or by the key id if
+    /// it has no name, and include the defaults declared by the keys. Nested graphs are flattened into a single
+    /// graph, and undirected edges point from their source to their target.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::ErrorKind::InvalidData`] error if the document is not well-formed XML, if a node id is
+    /// declared twice, or if an edge refers to a node that is not declared.
+    pub fn from_graphml<FN, FE>(
+        input: &str,
+        mut node_value: FN,
+        mut edge_value: FE,
+    ) -> io::Result<(Self, HashMap<String, NodeKey<DefaultKey>>)>
+    where
+        FN: FnMut(&str, &Attributes) -> N,
+        FE: FnMut(&Attributes) -> E,
+    {
+        let mut reader = Reader::from_str(input);
+        let mut doc = Document::default();
+        loop {
+            match reader.read_event().map_err(invalid_data)? {
+                Event::Start(e) => doc.open(&e)?,
+                Event::Empty(e) => {
+                    doc.open(&e)?;
+                    doc.close();
+                }
+                Event::End(_) => doc.close(),
+                Event::Text(t) => doc.text.push_str(&t.xml10_content()),
+                Event::CData(t) => doc.text.push_str(&t.xml10_content()),
+                Event::GeneralRef(r) => match r.resolve_char_ref().map_err(invalid_data)? {
+                    Some(c) => doc.text.push(c),
+                    None => doc.text.push_str(
+                        resolve_predefined_entity(&r)
+                            .ok_or_else(|| invalid_data(format!("unknown entity &{};", &*r)))?,
+                    ),
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        let mut sg = Self::new();
+        let mut ids = HashMap::with_capacity(doc.nodes.len());
+        for (id, mut attributes) in std::mem::take(&mut doc.nodes) {
+            if ids.contains_key(&id) {
+                return Err(invalid_data(format!("node {id:?} is declared twice")));
+            }
+            doc.apply_defaults(&mut attributes, "node");
+            let key = sg.insert_node(node_value(&id, &attributes));
+            ids.insert(id, key);
+        }
+        for (source, target, mut attributes) in std::mem::take(&mut doc.edges) {
+            let (Some(&from), Some(&to)) = (ids.get(&source), ids.get(&target)) else {
+                return Err(invalid_data(format!(
+                    "edge from {source:?} to {target:?} refers to an undeclared node"
+                )));
+            };
+            doc.apply_defaults(&mut attributes, "edge");
+            sg.insert_edge(from, to, edge_value(&attributes));
+        }
+        Ok((sg, ids))
+    }
+}
@@ -0,0 +1,129 @@
+//! Export and import of graphs as JSON documents preserving their node and edge keys.
+//!
+//! The document is an object with the following fields:
+//!
+//! - `directed`: whether the edges are directed.
+//! - `nodes`: an array with an object per node, holding its `key` and its `value`.
+//! - `edges`: an array with an object per edge, holding its `key`, the keys of the nodes it points `from` and `to`,
+//!   and its `value`.
+//!
+//! Keys are objects with the slot index `idx` and the slot `version`, as serialized by [`slotmap`], so they are
+//! exact in JavaScript. Values are serialized with [`serde`].
+//!
+//! ```json
+//! {
+//!   "directed": true,
+//!   "nodes": [
+//!     { "key": { "idx": 1, "version": 1 }, "value": "a" },
+//!     { "key": { "idx": 2, "version": 1 }, "value": "b" }
+//!   ],
+//!   "edges": [
+//!     {
+//!       "key": { "idx": 1, "version": 1 },
+//!       "from": { "idx": 1, "version": 1 },
+//!       "to": { "idx": 2, "version": 1 },
+//!       "value": 1.5
+//!     }
+//!   ]
+//! }
+//! ```
+
+use serde::{de::Error, Deserialize, Serialize};
+use slotmap::{Key, KeyData};
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+#[derive(Serialize, Deserialize)]
+struct JsonNode<N> {
+    key: KeyData,
+    value: N,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonEdge<E> {
+    key: KeyData,
+    from: KeyData,
+    to: KeyData,
+    value: E,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JsonGraph<N, E> {
+    directed: bool,
+    nodes: Vec<JsonNode<N>>,
+    edges: Vec<JsonEdge<E>>,
+}
+
+impl<K: Key, N: Serialize, E: Serialize, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Serializes the graph to a JSON document, see the [module documentation](self) for its schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serializing a node or edge value fails.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        let doc = JsonGraph {
+            directed: Ty::is_directed(),
+            nodes: self
+                .iter_nodes()
+                .map(|(k, value)| JsonNode {
+                    key: k.data(),
+                    value,
+                })
+                .collect(),
+            edges: self
+                .iter_edges_full()
+                .map(|(k, from, to, value)| JsonEdge {
+                    key: k.data(),
+                    from: from.data(),
+                    to: to.data(),
+                    value,
+                })
+                .collect(),
+        };
+        serde_json::to_string(&doc)
+    }
+}
+
+impl<K, N, E, Ty> SlotGraph<K, N, E, Ty>
+where
+    K: Key,
+    N: for<'de> Deserialize<'de>,
+    E: for<'de> Deserialize<'de>,
+    Ty: EdgeType,
+{
+    /// Deserializes a graph from a JSON document, see the [module documentation](self) for its schema.
+    ///
+    /// Every node and edge keeps the key it is listed with, so keys stored elsewhere remain valid, and the edges of a
+    /// node are adjacent in the order they are listed in. Building the graph takes time and memory proportional to the
+    /// highest slot index in the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document does not match the schema, if its directedness differs from `Ty`, if two keys
+    /// share a slot, if an edge refers to a node that is not listed, or if a slot index exceeds twice the number of
+    /// nodes or edges by more than 1024, which bounds the memory an untrusted document can claim. Compact a graph with
+    /// many removed nodes using [`SlotGraph::remap_keys`] before writing it to stay within that bound.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        let doc: JsonGraph<N, E> = serde_json::from_str(json)?;
+        if doc.directed != Ty::is_directed() {
+            return Err(serde_json::Error::custom(
+                "the graph has a different edge type",
+            ));
+        }
+        let nodes = doc
+            .nodes
+            .into_iter()
+            .map(|n| (NodeKey::from(n.key), n.value))
+            .collect();
+        let edges = doc
+            .edges
+            .into_iter()
+            .map(|e| {
+                let (from, to) = (NodeKey::from(e.from), NodeKey::from(e.to));
+                (EdgeKey::from(e.key), from, to, e.value)
+            })
+            .collect();
+        Self::from_keyed_parts(nodes, edges).map_err(serde_json::Error::custom)
+    }
+}
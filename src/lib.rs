@@ -1,3 +1,29 @@
+pub mod adjacency;
+pub mod algo;
+pub mod diff;
+pub mod dot;
+pub mod edge_index;
+pub mod edge_list;
+pub mod edge_type;
+pub mod entry;
+pub mod generate;
+#[cfg(feature = "graphml")]
+pub mod graphml;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod observed;
+pub mod product;
 pub mod slotgraph;
+pub mod transpose;
+pub mod undirected;
+#[cfg(feature = "petgraph")]
+pub mod visit;
 
-pub use slotgraph::SlotGraph;
+pub use adjacency::Adjacency;
+pub use diff::GraphDiff;
+pub use dot::Dot;
+pub use edge_index::EdgeIndexedSlotGraph;
+pub use edge_type::{Directed, EdgeType, Undirected};
+pub use observed::{ObservedSlotGraph, SlotGraphObserver};
+pub use slotgraph::{AdjGraph, SlotGraph, UnSlotGraph};
+pub use transpose::TransposeView;
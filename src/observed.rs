@@ -0,0 +1,265 @@
+//! A [`SlotGraph`] wrapper that notifies an observer of every structural mutation.
+
+use std::ops::Deref;
+
+use slotmap::{DefaultKey, Key};
+
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// Callbacks invoked by an [`ObservedSlotGraph`] after each mutation of the wrapped graph.
+///
+/// Every callback receives the graph in its state after the mutation, so secondary indexes can be kept in sync with
+/// it. All callbacks do nothing by default.
+pub trait SlotGraphObserver<K: Key, N, E> {
+    /// Called after a node was inserted.
+    fn on_insert_node(&mut self, _graph: &SlotGraph<K, N, E>, _key: NodeKey<K>) {}
+
+    /// Called after a node was removed, with the value it held.
+    fn on_remove_node(&mut self, _graph: &SlotGraph<K, N, E>, _key: NodeKey<K>, _value: &N) {}
+
+    /// Called after an edge was inserted.
+    fn on_insert_edge(&mut self, _graph: &SlotGraph<K, N, E>, _key: EdgeKey<K>) {}
+
+    /// Called after an edge was removed, with the node keys it pointed from and to and the value it held.
+    fn on_remove_edge(
+        &mut self,
+        _graph: &SlotGraph<K, N, E>,
+        _key: EdgeKey<K>,
+        _nodes: (NodeKey<K>, NodeKey<K>),
+        _value: &E,
+    ) {
+    }
+}
+
+/// A [`SlotGraph`] that invokes a [`SlotGraphObserver`] on every insertion and removal.
+///
+/// Read access goes through [`Deref`] to the wrapped graph. Mutation is only possible through the wrapper so the
+/// observer cannot miss any change: values can be changed freely, while nodes and edges are inserted and removed one at
+/// a time with a notification each. Mutators that rebuild the whole graph, such as [`SlotGraph::reverse`],
+/// [`SlotGraph::absorb`], [`SlotGraph::drain_nodes`] and [`SlotGraph::node_entry`], are left out; use
+/// [`ObservedSlotGraph::into_parts`] for those.
+pub struct ObservedSlotGraph<K: Key, N, E, O: SlotGraphObserver<K, N, E>> {
+    graph: SlotGraph<K, N, E>,
+    observer: O,
+}
+
+impl<N, E, O: SlotGraphObserver<DefaultKey, N, E> + Default> Default
+    for ObservedSlotGraph<DefaultKey, N, E, O>
+{
+    fn default() -> Self {
+        Self::new(O::default())
+    }
+}
+
+impl<N, E, O: SlotGraphObserver<DefaultKey, N, E>> ObservedSlotGraph<DefaultKey, N, E, O> {
+    /// Constructs a new, empty [`ObservedSlotGraph`] with the given observer.
+    pub fn new(observer: O) -> Self {
+        Self::with_key(observer)
+    }
+}
+
+impl<K: Key, N, E, O: SlotGraphObserver<K, N, E>> ObservedSlotGraph<K, N, E, O> {
+    /// Constructs a new, empty [`ObservedSlotGraph`] with a custom [`SlotMap`](slotmap::SlotMap) key and the given
+    /// observer.
+    pub fn with_key(observer: O) -> Self {
+        Self {
+            graph: SlotGraph::with_key(),
+            observer,
+        }
+    }
+
+    /// Returns a reference to the wrapped graph.
+    pub fn graph(&self) -> &SlotGraph<K, N, E> {
+        &self.graph
+    }
+
+    /// Returns a reference to the observer.
+    pub fn observer(&self) -> &O {
+        &self.observer
+    }
+
+    /// Returns a mutable reference to the observer.
+    pub fn observer_mut(&mut self) -> &mut O {
+        &mut self.observer
+    }
+
+    /// Unwraps the graph and the observer.
+    pub fn into_parts(self) -> (SlotGraph<K, N, E>, O) {
+        (self.graph, self.observer)
+    }
+
+    /// Insert a new node with the value into the slot graph, notifying the observer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of nodes in the graph equals 2³² - 2.
+    pub fn insert_node(&mut self, value: N) -> NodeKey<K> {
+        let key = self.graph.insert_node(value);
+        self.observer.on_insert_node(&self.graph, key);
+        key
+    }
+
+    /// Removes a node key from the slot graph, returning the value at the given key if it was not previously removed.
+    ///
+    /// Every edge pointing from or to the node is removed as well. The observer is notified of each removed edge and
+    /// then of the removed node, and only if the node was removed.
+    pub fn remove_node(&mut self, key: NodeKey<K>) -> Option<N> {
+        let removed = self.graph.remove_node_detailed(key)?;
+        for (ek, from, to, value) in &removed.edges {
+            self.observer
+                .on_remove_edge(&self.graph, *ek, (*from, *to), value);
+        }
+        self.observer
+            .on_remove_node(&self.graph, key, &removed.value);
+        Some(removed.value)
+    }
+
+    /// Insert a new edge with the given value into the slot graph, notifying the observer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of edges in the graph equals 2³² - 2.
+    pub fn insert_edge(&mut self, from: NodeKey<K>, to: NodeKey<K>, value: E) -> EdgeKey<K> {
+        let key = self.graph.insert_edge(from, to, value);
+        self.observer.on_insert_edge(&self.graph, key);
+        key
+    }
+
+    /// Removes an edge key from the slot graph, returning the value at the given key if it was not previously removed.
+    ///
+    /// The observer is only notified if the edge was removed.
+    pub fn remove_edge(&mut self, key: EdgeKey<K>) -> Option<E> {
+        let nodes = self.graph.get_edge_nodes(key)?;
+        let value = self.graph.remove_edge(key)?;
+        self.observer
+            .on_remove_edge(&self.graph, key, nodes, &value);
+        Some(value)
+    }
+
+    /// Insert a new node with the value into the slot graph, or return the value if the graph already holds
+    /// [`SlotGraph::MAX_NODES`] nodes. The observer is only notified if the node was inserted.
+    pub fn try_insert_node(&mut self, value: N) -> Result<NodeKey<K>, N> {
+        let key = self.graph.try_insert_node(value)?;
+        self.observer.on_insert_node(&self.graph, key);
+        Ok(key)
+    }
+
+    /// Insert a new edge with the given value into the slot graph, or return the value if either node key is stale or
+    /// the graph already holds [`SlotGraph::MAX_EDGES`] edges. The observer is only notified if the edge was inserted.
+    pub fn try_insert_edge(
+        &mut self,
+        from: NodeKey<K>,
+        to: NodeKey<K>,
+        value: E,
+    ) -> Result<EdgeKey<K>, E> {
+        let key = self.graph.try_insert_edge(from, to, value)?;
+        self.observer.on_insert_edge(&self.graph, key);
+        Ok(key)
+    }
+
+    /// Replaces the value of the first edge inserted among those pointing from one node to another, or inserts a new
+    /// edge if there is none, see [`SlotGraph::update_edge`]. The observer is only notified of a new edge.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node key is stale or if a new edge is needed and the number of edges in the graph equals
+    /// [`SlotGraph::MAX_EDGES`].
+    pub fn update_edge(
+        &mut self,
+        from: NodeKey<K>,
+        to: NodeKey<K>,
+        value: E,
+    ) -> (EdgeKey<K>, Option<E>) {
+        match self.graph.find_edge(from, to) {
+            Some(key) => {
+                let old = self
+                    .graph
+                    .get_edge_mut(key)
+                    .map(|v| std::mem::replace(v, value));
+                (key, old)
+            }
+            None => (self.insert_edge(from, to, value), None),
+        }
+    }
+
+    /// Removes every node for which the predicate returns `false` together with its edges, notifying the observer of
+    /// every removal as [`Self::remove_node`] does.
+    ///
+    /// The predicate is called once with the key and a mutable reference to the value of every node, in iteration
+    /// order.
+    pub fn retain_nodes<F: FnMut(NodeKey<K>, &mut N) -> bool>(&mut self, mut keep: F) {
+        let removed: Vec<_> = self
+            .graph
+            .iter_nodes_mut()
+            .filter_map(|(k, v)| (!keep(k, v)).then_some(k))
+            .collect();
+        for key in removed {
+            self.remove_node(key);
+        }
+    }
+
+    /// Removes every edge for which the predicate returns `false`, notifying the observer of every removal.
+    ///
+    /// The predicate is called once with the key and a mutable reference to the value of every edge, in iteration
+    /// order.
+    pub fn retain_edges<F: FnMut(EdgeKey<K>, &mut E) -> bool>(&mut self, mut keep: F) {
+        let removed: Vec<_> = self
+            .graph
+            .iter_edges_mut()
+            .filter_map(|(k, v)| (!keep(k, v)).then_some(k))
+            .collect();
+        for key in removed {
+            self.remove_edge(key);
+        }
+    }
+
+    /// Removes every node and edge, notifying the observer of every removal.
+    pub fn clear(&mut self) {
+        self.retain_nodes(|_, _| false);
+    }
+
+    /// Removes every edge while keeping the nodes, notifying the observer of every removal.
+    pub fn clear_edges(&mut self) {
+        self.retain_edges(|_, _| false);
+    }
+
+    /// Returns a mutable reference to the value corresponding to the node key.
+    pub fn get_node_mut(&mut self, key: NodeKey<K>) -> Option<&mut N> {
+        self.graph.get_node_mut(key)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the edge key.
+    pub fn get_edge_mut(&mut self, key: EdgeKey<K>) -> Option<&mut E> {
+        self.graph.get_edge_mut(key)
+    }
+
+    /// An iterator visiting all the node key-value pairs in arbitrary order, returning mutable references to the node
+    /// values.
+    pub fn iter_nodes_mut(&mut self) -> impl Iterator<Item = (NodeKey<K>, &mut N)> {
+        self.graph.iter_nodes_mut()
+    }
+
+    /// An iterator visiting all the edge key-value pairs in arbitrary order, returning mutable references to the edge
+    /// values.
+    pub fn iter_edges_mut(&mut self) -> impl Iterator<Item = (EdgeKey<K>, &mut E)> {
+        self.graph.iter_edges_mut()
+    }
+
+    /// Reserves room for at least `additional` more nodes.
+    pub fn reserve_nodes(&mut self, additional: usize) {
+        self.graph.reserve_nodes(additional);
+    }
+
+    /// Reserves room for at least `additional` more edges.
+    pub fn reserve_edges(&mut self, additional: usize) {
+        self.graph.reserve_edges(additional);
+    }
+}
+
+impl<K: Key, N, E, O: SlotGraphObserver<K, N, E>> Deref for ObservedSlotGraph<K, N, E, O> {
+    type Target = SlotGraph<K, N, E>;
+
+    fn deref(&self) -> &SlotGraph<K, N, E> {
+        &self.graph
+    }
+}
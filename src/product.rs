@@ -0,0 +1,97 @@
+//! Products of graphs, whose nodes are the pairs of nodes of two graphs.
+
+use std::collections::HashMap;
+
+use slotmap::{DefaultKey, Key};
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{NodeKey, SlotGraph};
+
+/// The map from pairs of nodes of the factors to the nodes of a product.
+pub type ProductNodes<K1, K2> = HashMap<(NodeKey<K1>, NodeKey<K2>), NodeKey<DefaultKey>>;
+
+/// Inserts a node for every pair of nodes of the factors, with the value returned by `node_value`.
+fn product_nodes<K1: Key, N1, E1, K2: Key, N2, E2, N, E, Ty: EdgeType>(
+    a: &SlotGraph<K1, N1, E1, Ty>,
+    b: &SlotGraph<K2, N2, E2, Ty>,
+    product: &mut SlotGraph<DefaultKey, N, E, Ty>,
+    mut node_value: impl FnMut(&N1, &N2) -> N,
+) -> ProductNodes<K1, K2> {
+    let mut nodes = HashMap::with_capacity(a.node_len() * b.node_len());
+    for (ak, av) in a.iter_nodes() {
+        for (bk, bv) in b.iter_nodes() {
+            nodes.insert((ak, bk), product.insert_node(node_value(av, bv)));
+        }
+    }
+    nodes
+}
+
+/// Returns the Cartesian product of two graphs and the map from pairs of nodes of the graphs to its nodes.
+///
+/// The product has a node for every pair of a node of `a` and a node of `b`, with the value returned by `node_value`.
+/// For every edge of `a` and every node of `b`, it has an edge between the pairs of the endpoints with that node, and
+/// likewise for every edge of `b` and every node of `a`, with the value of the edge it stems from. Nodes are inserted
+/// row by row, pairing each node of `a` with every node of `b` in iteration order.
+pub fn cartesian_product<K1, N1, K2, N2, N, E, Ty, F>(
+    a: &SlotGraph<K1, N1, E, Ty>,
+    b: &SlotGraph<K2, N2, E, Ty>,
+    node_value: F,
+) -> (SlotGraph<DefaultKey, N, E, Ty>, ProductNodes<K1, K2>)
+where
+    K1: Key,
+    K2: Key,
+    E: Clone,
+    Ty: EdgeType,
+    F: FnMut(&N1, &N2) -> N,
+{
+    let mut product = SlotGraph::default();
+    let nodes = product_nodes(a, b, &mut product, node_value);
+    for (_, from, to, value) in a.iter_edges_full() {
+        for (bk, _) in b.iter_nodes() {
+            product.insert_edge(nodes[&(from, bk)], nodes[&(to, bk)], value.clone());
+        }
+    }
+    for (_, from, to, value) in b.iter_edges_full() {
+        for (ak, _) in a.iter_nodes() {
+            product.insert_edge(nodes[&(ak, from)], nodes[&(ak, to)], value.clone());
+        }
+    }
+    (product, nodes)
+}
+
+/// Returns the tensor product of two graphs and the map from pairs of nodes of the graphs to its nodes.
+///
+/// The product has a node for every pair of a node of `a` and a node of `b`, with the value returned by `node_value`.
+/// For every edge of `a` and every edge of `b`, it has an edge from the pair of their sources to the pair of their
+/// targets, with the value returned by `edge_value`. For undirected graphs, a pair of edges that are not self-loops
+/// also connects the pairs with the endpoints of one edge swapped, so it gives two edges. Nodes are inserted like for
+/// [`cartesian_product`].
+pub fn tensor_product<K1, N1, E1, K2, N2, E2, N, E, Ty, FN, FE>(
+    a: &SlotGraph<K1, N1, E1, Ty>,
+    b: &SlotGraph<K2, N2, E2, Ty>,
+    node_value: FN,
+    mut edge_value: FE,
+) -> (SlotGraph<DefaultKey, N, E, Ty>, ProductNodes<K1, K2>)
+where
+    K1: Key,
+    K2: Key,
+    Ty: EdgeType,
+    FN: FnMut(&N1, &N2) -> N,
+    FE: FnMut(&E1, &E2) -> E,
+{
+    let mut product = SlotGraph::default();
+    let nodes = product_nodes(a, b, &mut product, node_value);
+    for (_, a_from, a_to, a_value) in a.iter_edges_full() {
+        for (_, b_from, b_to, b_value) in b.iter_edges_full() {
+            let from = nodes[&(a_from, b_from)];
+            let to = nodes[&(a_to, b_to)];
+            product.insert_edge(from, to, edge_value(a_value, b_value));
+            if !Ty::is_directed() && a_from != a_to && b_from != b_to {
+                let from = nodes[&(a_from, b_to)];
+                let to = nodes[&(a_to, b_from)];
+                product.insert_edge(from, to, edge_value(a_value, b_value));
+            }
+        }
+    }
+    (product, nodes)
+}
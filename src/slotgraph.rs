@@ -1,4 +1,9 @@
-use slotmap::{DefaultKey, Key, KeyData, SlotMap};
+use std::fmt;
+use std::marker::PhantomData;
+
+use slotmap::{DefaultKey, Key, KeyData, SecondaryMap, SlotMap};
+
+use crate::edge_type::{Directed, EdgeType, Undirected};
 
 #[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct NodeKey<K: Key>(K);
@@ -15,8 +20,26 @@ unsafe impl<K: Key> Key for NodeKey<K> {
     }
 }
 
-struct NodeValue<N> {
-    value: N,
+impl<K: Key> NodeKey<K> {
+    /// Wraps an underlying slot map key as a node key.
+    pub fn from_inner(key: K) -> Self {
+        Self(key)
+    }
+
+    /// Returns the underlying slot map key of this node key.
+    pub fn into_inner(self) -> K {
+        self.0
+    }
+
+    /// Converts this node key to a `u64` suitable for FFI or serialization, see [`KeyData::as_ffi`].
+    pub fn as_ffi(self) -> u64 {
+        self.0.data().as_ffi()
+    }
+
+    /// Converts a `u64` created with [`NodeKey::as_ffi`] back into a node key, see [`KeyData::from_ffi`].
+    pub fn from_ffi(value: u64) -> Self {
+        Self::from(KeyData::from_ffi(value))
+    }
 }
 
 #[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -34,21 +57,209 @@ unsafe impl<K: Key> Key for EdgeKey<K> {
     }
 }
 
-struct EdgeValue<K: Key, E> {
+impl<K: Key> EdgeKey<K> {
+    /// Wraps an underlying slot map key as an edge key.
+    pub fn from_inner(key: K) -> Self {
+        Self(key)
+    }
+
+    /// Returns the underlying slot map key of this edge key.
+    pub fn into_inner(self) -> K {
+        self.0
+    }
+
+    /// Converts this edge key to a `u64` suitable for FFI or serialization, see [`KeyData::as_ffi`].
+    pub fn as_ffi(self) -> u64 {
+        self.0.data().as_ffi()
+    }
+
+    /// Converts a `u64` created with [`EdgeKey::as_ffi`] back into an edge key, see [`KeyData::from_ffi`].
+    pub fn from_ffi(value: u64) -> Self {
+        Self::from(KeyData::from_ffi(value))
+    }
+}
+
+/// The node keys an edge points from and to.
+#[derive(Copy, Clone)]
+struct EdgeNodes<K: Key> {
     from: NodeKey<K>,
     to: NodeKey<K>,
-    value: E,
+}
+
+/// A node removed by [`SlotGraph::remove_node_detailed`], together with every edge removed along with it.
+#[derive(Clone, Debug)]
+pub struct RemovedNode<K: Key, N, E> {
+    /// The value of the removed node.
+    pub value: N,
+    /// The key, the node keys it pointed from and to, and the value of every removed incident edge.
+    #[allow(clippy::type_complexity)]
+    pub edges: Vec<(EdgeKey<K>, NodeKey<K>, NodeKey<K>, E)>,
+}
+
+/// Extracts the slot version stored in the upper half of the key's FFI representation.
+fn key_version<K: Key>(key: K) -> u32 {
+    (key.data().as_ffi() >> 32) as u32
+}
+
+/// The edges pointing from and to a node, each with the node key at its other end, in insertion order.
+///
+/// A self-loop is listed in both. In an undirected graph every other edge is listed in both as well, since it can be
+/// traversed in either direction.
+#[derive(Clone)]
+struct Adjacency<K: Key> {
+    outgoing: Vec<(EdgeKey<K>, NodeKey<K>)>,
+    incoming: Vec<(EdgeKey<K>, NodeKey<K>)>,
+}
+
+impl<K: Key> Adjacency<K> {
+    fn new() -> Self {
+        Self {
+            outgoing: Vec::new(),
+            incoming: Vec::new(),
+        }
+    }
+}
+
+/// Removes the entry of the edge from a list of adjacent edges, preserving the order of the others.
+fn remove_adjacent<K: Key>(list: &mut Vec<(EdgeKey<K>, NodeKey<K>)>, key: EdgeKey<K>) {
+    if let Some(i) = list.iter().position(|&(ek, _)| ek == key) {
+        list.remove(i);
+    }
+}
+
+/// Points the entry of the edge in a list of adjacent edges at a different node.
+fn retarget_adjacent<K: Key>(
+    list: &mut [(EdgeKey<K>, NodeKey<K>)],
+    key: EdgeKey<K>,
+    node: NodeKey<K>,
+) {
+    if let Some(entry) = list.iter_mut().find(|(ek, _)| *ek == key) {
+        entry.1 = node;
+    }
+}
+
+/// Adds the edge to the adjacency of both of its nodes.
+fn link<K: Key, Ty: EdgeType>(
+    nodes: &mut SlotMap<NodeKey<K>, Adjacency<K>>,
+    key: EdgeKey<K>,
+    e: EdgeNodes<K>,
+) {
+    nodes[e.from].outgoing.push((key, e.to));
+    nodes[e.to].incoming.push((key, e.from));
+    if !Ty::is_directed() && e.from != e.to {
+        nodes[e.to].outgoing.push((key, e.from));
+        nodes[e.from].incoming.push((key, e.to));
+    }
+}
+
+/// Removes the edge from the adjacency of both of its nodes.
+fn unlink<K: Key, Ty: EdgeType>(
+    nodes: &mut SlotMap<NodeKey<K>, Adjacency<K>>,
+    key: EdgeKey<K>,
+    e: EdgeNodes<K>,
+) {
+    if let Some(adj) = nodes.get_mut(e.from) {
+        remove_adjacent(&mut adj.outgoing, key);
+        if !Ty::is_directed() {
+            remove_adjacent(&mut adj.incoming, key);
+        }
+    }
+    if let Some(adj) = nodes.get_mut(e.to) {
+        remove_adjacent(&mut adj.incoming, key);
+        if !Ty::is_directed() {
+            remove_adjacent(&mut adj.outgoing, key);
+        }
+    }
 }
 
 /// A graph data structure based on the [`SlotMap`] data structure.
-pub struct SlotGraph<K: Key, N, E> {
-    nodes: SlotMap<NodeKey<K>, NodeValue<N>>,
-    edges: SlotMap<EdgeKey<K>, EdgeValue<K, E>>,
+///
+/// The slot maps own the node and edge keys and the graph structure, while the values are stored separately in
+/// [`SecondaryMap`]s so they can be replaced without changing any keys. Every node keeps the lists of its outgoing
+/// and incoming edges, so adjacency queries take time proportional to the degree of the node.
+///
+/// The [`EdgeType`] parameter selects whether edges are [`Directed`] or [`Undirected`]. An undirected edge still
+/// stores the node keys it was inserted with in that order, but is traversable from both of them: it is yielded by
+/// both [`SlotGraph::out_edges`] and [`SlotGraph::in_edges`] of either node.
+pub struct SlotGraph<K: Key, N, E, Ty: EdgeType = Directed> {
+    nodes: SlotMap<NodeKey<K>, Adjacency<K>>,
+    edges: SlotMap<EdgeKey<K>, EdgeNodes<K>>,
+    node_values: SecondaryMap<NodeKey<K>, N>,
+    edge_values: SecondaryMap<EdgeKey<K>, E>,
+    ty: PhantomData<Ty>,
 }
 
-impl<N, E> Default for SlotGraph<DefaultKey, N, E> {
+/// An undirected [`SlotGraph`].
+pub type UnSlotGraph<K, N, E> = SlotGraph<K, N, E, Undirected>;
+
+// Clone is implemented manually to avoid requiring `Ty: Clone`.
+impl<K: Key, N: Clone, E: Clone, Ty: EdgeType> Clone for SlotGraph<K, N, E, Ty> {
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes.clone(),
+            edges: self.edges.clone(),
+            node_values: self.node_values.clone(),
+            edge_values: self.edge_values.clone(),
+            ty: PhantomData,
+        }
+    }
+}
+
+/// A [`SlotGraph`], which maintains the outgoing and incoming edges of every node on every mutation.
+///
+/// This name is kept for code written against the adjacency graph example, which has been folded into [`SlotGraph`].
+pub type AdjGraph<K, N, E> = SlotGraph<K, N, E>;
+
+impl<K: Key, N, E, Ty: EdgeType> Default for SlotGraph<K, N, E, Ty> {
     fn default() -> Self {
-        Self::with_key()
+        Self::empty()
+    }
+}
+
+/// Graphs are equal if they have the same node keys with equal values and the same edge keys between the same node
+/// keys with equal values, regardless of the order edges were inserted in.
+impl<K: Key, N: PartialEq, E: PartialEq, Ty: EdgeType> PartialEq for SlotGraph<K, N, E, Ty> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node_len() == other.node_len()
+            && self.edge_len() == other.edge_len()
+            && self
+                .iter_nodes()
+                .all(|(k, value)| other.get_node(k) == Some(value))
+            && self.iter_edges_full().all(|(k, from, to, value)| {
+                other.get_edge_nodes(k) == Some((from, to)) && other.get_edge(k) == Some(value)
+            })
+    }
+}
+
+impl<K: Key, N: Eq, E: Eq, Ty: EdgeType> Eq for SlotGraph<K, N, E, Ty> {}
+
+/// Formats every node key with its value, and every edge key with its node keys and value, in iteration order.
+impl<K: Key, N: fmt::Debug, E: fmt::Debug, Ty: EdgeType> fmt::Debug for SlotGraph<K, N, E, Ty> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlotGraph")
+            .field("directed", &Ty::is_directed())
+            .field("nodes", &DebugNodes(self))
+            .field("edges", &DebugEdges(self))
+            .finish()
+    }
+}
+
+struct DebugNodes<'a, K: Key, N, E, Ty: EdgeType>(&'a SlotGraph<K, N, E, Ty>);
+
+impl<K: Key, N: fmt::Debug, E, Ty: EdgeType> fmt::Debug for DebugNodes<'_, K, N, E, Ty> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.0.iter_nodes()).finish()
+    }
+}
+
+struct DebugEdges<'a, K: Key, N, E, Ty: EdgeType>(&'a SlotGraph<K, N, E, Ty>);
+
+impl<K: Key, N, E: fmt::Debug, Ty: EdgeType> fmt::Debug for DebugEdges<'_, K, N, E, Ty> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let edges = self.0.iter_edges_full();
+        f.debug_map()
+            .entries(edges.map(|(k, from, to, value)| (k, (from, to, value))))
+            .finish()
     }
 }
 
@@ -57,42 +268,565 @@ impl<N, E> SlotGraph<DefaultKey, N, E> {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Constructs a new, empty [`SlotGraph`] with room for the given numbers of nodes and edges.
+    pub fn with_capacity(nodes: usize, edges: usize) -> Self {
+        Self::empty_with_capacity(nodes, edges)
+    }
+}
+
+impl<N, E> SlotGraph<DefaultKey, N, E, Undirected> {
+    /// Constructs a new, empty undirected [`SlotGraph`].
+    pub fn new_undirected() -> Self {
+        Self::default()
+    }
+
+    /// Constructs a new, empty undirected [`SlotGraph`] with room for the given numbers of nodes and edges.
+    pub fn with_capacity_undirected(nodes: usize, edges: usize) -> Self {
+        Self::empty_with_capacity(nodes, edges)
+    }
+}
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    fn empty() -> Self {
+        Self {
+            nodes: SlotMap::with_key(),
+            edges: SlotMap::with_key(),
+            node_values: SecondaryMap::new(),
+            edge_values: SecondaryMap::new(),
+            ty: PhantomData,
+        }
+    }
+
+    fn empty_with_capacity(nodes: usize, edges: usize) -> Self {
+        Self {
+            nodes: SlotMap::with_capacity_and_key(nodes),
+            edges: SlotMap::with_capacity_and_key(edges),
+            node_values: SecondaryMap::with_capacity(nodes),
+            edge_values: SecondaryMap::with_capacity(edges),
+            ty: PhantomData,
+        }
+    }
+
+    /// Returns whether the edges of the graph are directed.
+    pub fn is_directed(&self) -> bool {
+        Ty::is_directed()
+    }
+}
+
+impl<K: Key, N, E> SlotGraph<K, N, E, Undirected> {
+    /// Constructs a new, empty undirected [`SlotGraph`] with a custom [`SlotMap`] key.
+    pub fn with_key_undirected() -> Self {
+        Self::empty()
+    }
 }
 
 impl<K: Key, N, E> SlotGraph<K, N, E> {
     /// Constructs a new, empty [`SlotGraph`] with a custom [`SlotMap`] key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use slotgraph::SlotGraph;
+    /// use slotmap::new_key_type;
+    ///
+    /// new_key_type! { struct MyKey; }
+    ///
+    /// let mut sg = SlotGraph::<MyKey, _, _>::with_key();
+    /// let n1 = sg.insert_node("n1");
+    /// let n2 = sg.insert_node("n2");
+    /// let e1 = sg.insert_edge(n1, n2, "e1");
+    /// assert_eq!(sg.get_edge_nodes(e1), Some((n1, n2)));
+    /// ```
     pub fn with_key() -> Self {
-        Self {
-            nodes: SlotMap::with_key(),
+        Self::empty()
+    }
+
+    /// Constructs a new, empty [`SlotGraph`] with a custom [`SlotMap`] key and room for the given numbers of nodes and
+    /// edges.
+    pub fn with_capacity_and_key(nodes: usize, edges: usize) -> Self {
+        Self::empty_with_capacity(nodes, edges)
+    }
+}
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Returns a graph with the same node and edge keys and the same structure, but with unit values.
+    pub fn topology(&self) -> SlotGraph<K, (), (), Ty> {
+        SlotGraph {
+            nodes: self.nodes.clone(),
+            edges: self.edges.clone(),
+            node_values: self.nodes.keys().map(|k| (k, ())).collect(),
+            edge_values: self.edges.keys().map(|k| (k, ())).collect(),
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Reverses the direction of every edge in place, keeping all keys and values, in time linear in the size of the
+    /// graph.
+    ///
+    /// Use [`Self::transpose_view`] to look at the reversed graph without modifying it. For undirected graphs, only
+    /// the order of the node keys returned by [`Self::get_edge_nodes`] changes.
+    pub fn reverse(&mut self) {
+        for e in self.edges.values_mut() {
+            std::mem::swap(&mut e.from, &mut e.to);
+        }
+        for adj in self.nodes.values_mut() {
+            std::mem::swap(&mut adj.outgoing, &mut adj.incoming);
+        }
+    }
+}
+
+impl<K: Key, N: Clone, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Returns a graph with the same node keys and values, and a unit edge between every pair of distinct nodes that
+    /// has no edge between them in this graph.
+    ///
+    /// For directed graphs, pairs are ordered, so the complement has an edge from one node to another exactly when
+    /// this graph does not. Self-loops and parallel edges are ignored, so this is the usual complement for simple
+    /// graphs. Takes time quadratic in the number of nodes.
+    pub fn complement(&self) -> SlotGraph<K, N, (), Ty> {
+        let mut nodes = self.nodes.clone();
+        for adj in nodes.values_mut() {
+            adj.outgoing.clear();
+            adj.incoming.clear();
+        }
+        let mut sg = SlotGraph {
+            nodes,
             edges: SlotMap::with_key(),
+            node_values: self.node_values.clone(),
+            edge_values: SecondaryMap::new(),
+            ty: PhantomData,
+        };
+        let keys: Vec<_> = self.nodes.keys().collect();
+        let mut adjacent = SecondaryMap::new();
+        for (i, &from) in keys.iter().enumerate() {
+            adjacent.clear();
+            adjacent.extend(self.outgoing(from).iter().map(|&(_, to)| (to, ())));
+            // undirected pairs are visited once, from the node that comes first
+            let first = if Ty::is_directed() { 0 } else { i + 1 };
+            for &to in &keys[first..] {
+                if to != from && !adjacent.contains_key(to) {
+                    sg.insert_edge(from, to, ());
+                }
+            }
+        }
+        sg
+    }
+}
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Converts every node and edge value with `node_map` and `edge_map`, keeping all keys and the structure.
+    pub fn map<N2, E2, FN, FE>(self, mut node_map: FN, mut edge_map: FE) -> SlotGraph<K, N2, E2, Ty>
+    where
+        FN: FnMut(NodeKey<K>, N) -> N2,
+        FE: FnMut(EdgeKey<K>, E) -> E2,
+    {
+        SlotGraph {
+            nodes: self.nodes,
+            edges: self.edges,
+            node_values: self
+                .node_values
+                .into_iter()
+                .map(|(k, value)| (k, node_map(k, value)))
+                .collect(),
+            edge_values: self
+                .edge_values
+                .into_iter()
+                .map(|(k, value)| (k, edge_map(k, value)))
+                .collect(),
+            ty: PhantomData,
+        }
+    }
+
+    /// Converts every node and edge value with `node_map` and `edge_map`, removing the nodes and edges they return
+    /// `None` for, and keeping the keys of the others.
+    ///
+    /// Edges of removed nodes are removed without passing them to `edge_map`.
+    pub fn filter_map<N2, E2, FN, FE>(
+        self,
+        mut node_map: FN,
+        mut edge_map: FE,
+    ) -> SlotGraph<K, N2, E2, Ty>
+    where
+        FN: FnMut(NodeKey<K>, N) -> Option<N2>,
+        FE: FnMut(EdgeKey<K>, E) -> Option<E2>,
+    {
+        let node_values: SecondaryMap<_, _> = self
+            .node_values
+            .into_iter()
+            .filter_map(|(k, value)| Some((k, node_map(k, value)?)))
+            .collect();
+        let edges = &self.edges;
+        let edge_values: SecondaryMap<_, _> = self
+            .edge_values
+            .into_iter()
+            .filter(|&(k, _)| {
+                let e = edges[k];
+                node_values.contains_key(e.from) && node_values.contains_key(e.to)
+            })
+            .filter_map(|(k, value)| Some((k, edge_map(k, value)?)))
+            .collect();
+
+        let mut nodes = self.nodes;
+        nodes.retain(|k, _| node_values.contains_key(k));
+        let mut edges = self.edges;
+        edges.retain(|k, _| edge_values.contains_key(k));
+        for adj in nodes.values_mut() {
+            adj.outgoing.retain(|&(ek, _)| edges.contains_key(ek));
+            adj.incoming.retain(|&(ek, _)| edges.contains_key(ek));
+        }
+        SlotGraph {
+            nodes,
+            edges,
+            node_values,
+            edge_values,
+            ty: PhantomData,
+        }
+    }
+}
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Rebuilds the graph with a different [`SlotMap`] key type, returning the new graph and the maps translating the
+    /// old node and edge keys to the new ones.
+    #[allow(clippy::type_complexity)]
+    pub fn remap_keys<K2: Key>(
+        self,
+    ) -> (
+        SlotGraph<K2, N, E, Ty>,
+        SecondaryMap<NodeKey<K>, NodeKey<K2>>,
+        SecondaryMap<EdgeKey<K>, EdgeKey<K2>>,
+    ) {
+        let mut sg = SlotGraph::empty();
+        let (node_map, edge_map) = sg.absorb(self);
+        (sg, node_map, edge_map)
+    }
+
+    /// Moves the nodes and edges of another graph into this one, returning the maps translating the keys of the other
+    /// graph to their new keys in this one.
+    ///
+    /// The other graph may use a different [`SlotMap`] key type. Its nodes and edges are inserted in iteration order.
+    #[allow(clippy::type_complexity)]
+    pub fn absorb<K2: Key>(
+        &mut self,
+        other: SlotGraph<K2, N, E, Ty>,
+    ) -> (
+        SecondaryMap<NodeKey<K2>, NodeKey<K>>,
+        SecondaryMap<EdgeKey<K2>, EdgeKey<K>>,
+    ) {
+        let mut node_map = SecondaryMap::with_capacity(other.nodes.len());
+        for (k, value) in other.node_values {
+            node_map.insert(k, self.insert_node(value));
+        }
+        let mut edge_map = SecondaryMap::with_capacity(other.edges.len());
+        for (k, value) in other.edge_values {
+            let e = other.edges[k];
+            edge_map.insert(k, self.insert_edge(node_map[e.from], node_map[e.to], value));
+        }
+        (node_map, edge_map)
+    }
+
+    /// Returns the union of the graph with another graph whose nodes and edges are kept apart, along with the maps
+    /// translating the keys of the other graph to their keys in the union.
+    ///
+    /// The keys of this graph stay valid in the union. See [`Self::absorb`].
+    #[allow(clippy::type_complexity)]
+    pub fn disjoint_union<K2: Key>(
+        mut self,
+        other: SlotGraph<K2, N, E, Ty>,
+    ) -> (
+        Self,
+        SecondaryMap<NodeKey<K2>, NodeKey<K>>,
+        SecondaryMap<EdgeKey<K2>, EdgeKey<K>>,
+    ) {
+        let (node_map, edge_map) = self.absorb(other);
+        (self, node_map, edge_map)
+    }
+}
+
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// The maximum number of nodes a slot graph can hold, 2³² - 2.
+    pub const MAX_NODES: usize = u32::MAX as usize - 1;
+
+    /// The maximum number of edges a slot graph can hold, 2³² - 2.
+    pub const MAX_EDGES: usize = u32::MAX as usize - 1;
+}
+
+// value aggregation methods
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Folds the value of every edge into the value of the node it points to.
+    pub fn accumulate_in<F: FnMut(&mut N, &E)>(&mut self, mut f: F) {
+        for (k, e) in &self.edges {
+            if let (Some(node), Some(edge)) =
+                (self.node_values.get_mut(e.to), self.edge_values.get(k))
+            {
+                f(node, edge);
+            }
+        }
+    }
+
+    /// Folds the value of every edge into the value of the node it points from.
+    pub fn accumulate_out<F: FnMut(&mut N, &E)>(&mut self, mut f: F) {
+        for (k, e) in &self.edges {
+            if let (Some(node), Some(edge)) =
+                (self.node_values.get_mut(e.from), self.edge_values.get(k))
+            {
+                f(node, edge);
+            }
+        }
+    }
+}
+
+impl<K: Key, N: Clone, E: Clone, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Copies a node into another graph together with every edge between it and a node present in `dest`, returning
+    /// the key of the copy.
+    ///
+    /// The node keys of the other endpoints are looked up in `dest` unchanged, so this is meant for graphs sharing a
+    /// key space, such as a clone or a graph split off from this one. Edges to nodes missing from `dest` are skipped,
+    /// while self-loops are copied onto the copy. Returns `None` if the node key is stale.
+    pub fn copy_node_into(
+        &self,
+        key: NodeKey<K>,
+        dest: &mut SlotGraph<K, N, E, Ty>,
+    ) -> Option<NodeKey<K>> {
+        let copy = dest.insert_node(self.get_node(key)?.clone());
+        let translate = |dest: &SlotGraph<K, N, E, Ty>, nk: NodeKey<K>| {
+            if nk == key {
+                Some(copy)
+            } else {
+                dest.nodes.contains_key(nk).then_some(nk)
+            }
+        };
+        for (ek, _) in self.incident(key) {
+            let e = self.edges[ek];
+            if let (Some(from), Some(to)) = (translate(dest, e.from), translate(dest, e.to)) {
+                dest.insert_edge(from, to, self.edge_values[ek].clone());
+            }
+        }
+        Some(copy)
+    }
+}
+
+// raw access
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Returns the [`SecondaryMap`] holding the node values, for bulk operations not covered by the graph's own API.
+    ///
+    /// It contains a value for exactly the nodes in the graph. Only shared access is given out, since inserting or
+    /// removing values directly would desynchronize them from the graph structure.
+    pub fn node_values(&self) -> &SecondaryMap<NodeKey<K>, N> {
+        &self.node_values
+    }
+
+    /// Returns the [`SecondaryMap`] holding the edge values, for bulk operations not covered by the graph's own API.
+    ///
+    /// It contains a value for exactly the edges in the graph. Only shared access is given out, since inserting or
+    /// removing values directly would desynchronize them from the graph structure.
+    pub fn edge_values(&self) -> &SecondaryMap<EdgeKey<K>, E> {
+        &self.edge_values
+    }
+}
+
+// structural helpers
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Moves every node for which `split` returns `true` into a new graph together with the edges between them,
+    /// preserving their keys. Edges between a moved node and a remaining node are removed from both graphs.
+    pub(crate) fn split_off_nodes<F: FnMut(NodeKey<K>) -> bool>(&mut self, mut split: F) -> Self {
+        let mut nodes = self.nodes.clone();
+        nodes.retain(|k, _| split(k));
+        self.nodes.retain(|k, _| !nodes.contains_key(k));
+
+        let mut edges = self.edges.clone();
+        edges.retain(|_, e| nodes.contains_key(e.from) && nodes.contains_key(e.to));
+        self.edges
+            .retain(|_, e| !nodes.contains_key(e.from) && !nodes.contains_key(e.to));
+
+        let node_values = nodes
+            .keys()
+            .filter_map(|k| Some((k, self.node_values.remove(k)?)))
+            .collect();
+        let edge_values = edges
+            .keys()
+            .filter_map(|k| Some((k, self.edge_values.remove(k)?)))
+            .collect();
+        let remaining = &self.edges;
+        self.edge_values.retain(|k, _| remaining.contains_key(k));
+        for adj in self.nodes.values_mut() {
+            adj.outgoing.retain(|&(ek, _)| remaining.contains_key(ek));
+            adj.incoming.retain(|&(ek, _)| remaining.contains_key(ek));
+        }
+        for adj in nodes.values_mut() {
+            adj.outgoing.retain(|&(ek, _)| edges.contains_key(ek));
+            adj.incoming.retain(|&(ek, _)| edges.contains_key(ek));
+        }
+
+        Self {
+            nodes,
+            edges,
+            node_values,
+            edge_values,
+            ty: PhantomData,
+        }
+    }
+
+    /// Drops the values and adjacency entries of edges that are no longer in the graph.
+    fn prune_adjacency(&mut self) {
+        let edges = &self.edges;
+        self.edge_values.retain(|k, _| edges.contains_key(k));
+        for adj in self.nodes.values_mut() {
+            adj.outgoing.retain(|&(ek, _)| edges.contains_key(ek));
+            adj.incoming.retain(|&(ek, _)| edges.contains_key(ek));
+        }
+    }
+
+    /// Removes every edge in the adjacency of a removed node, passing the key, node keys and value of each to
+    /// `removed`.
+    fn remove_incident_edges<F: FnMut(EdgeKey<K>, EdgeNodes<K>, E)>(
+        &mut self,
+        adj: Adjacency<K>,
+        mut removed: F,
+    ) {
+        for (k, _) in adj.outgoing.into_iter().chain(adj.incoming) {
+            // self-loops are listed twice
+            let Some(e) = self.edges.remove(k) else {
+                continue;
+            };
+            unlink::<K, Ty>(&mut self.nodes, k, e);
+            if let Some(value) = self.edge_values.remove(k) {
+                removed(k, e, value);
+            }
         }
     }
 }
 
 // node methods
-impl<K: Key, N, E> SlotGraph<K, N, E> {
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
     /// Insert a new node with the value into the slot graph.
     ///
     /// # Panics
     ///
-    /// Panics if the number of nodes in the graph equals 2³² - 2.
+    /// Panics if the number of nodes in the graph equals [`SlotGraph::MAX_NODES`].
     pub fn insert_node(&mut self, value: N) -> NodeKey<K> {
-        self.nodes.insert(NodeValue { value })
+        let key = self.nodes.insert(Adjacency::new());
+        self.node_values.insert(key, value);
+        key
+    }
+
+    /// Insert a new node with the value into the slot graph, or return the value if the graph already holds
+    /// [`SlotGraph::MAX_NODES`] nodes.
+    pub fn try_insert_node(&mut self, value: N) -> Result<NodeKey<K>, N> {
+        if self.node_len() >= Self::MAX_NODES {
+            return Err(value);
+        }
+        Ok(self.insert_node(value))
+    }
+
+    /// Insert a new node with the value into the slot graph, returning its key and the version of its slot.
+    ///
+    /// A slot is reused after its node is removed, but its version changes, so storing the version alongside the key
+    /// helps to diagnose stale keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of nodes in the graph equals [`SlotGraph::MAX_NODES`].
+    pub fn insert_node_versioned(&mut self, value: N) -> (NodeKey<K>, u32) {
+        let key = self.insert_node(value);
+        (key, key_version(key))
+    }
+
+    /// Returns the slot version of the node key, or `None` if the node key is stale.
+    pub fn node_version(&self, key: NodeKey<K>) -> Option<u32> {
+        self.nodes.contains_key(key).then(|| key_version(key))
     }
 
     /// Removes a node key from the slot graph, returning the value at the given key if it was not previously removed.
+    ///
+    /// Every edge pointing from or to the node is removed as well.
     pub fn remove_node(&mut self, key: NodeKey<K>) -> Option<N> {
-        self.nodes.remove(key).map(|n| n.value)
+        let adj = self.nodes.remove(key)?;
+        self.remove_incident_edges(adj, |_, _, _| {});
+        self.node_values.remove(key)
+    }
+
+    /// Removes a node key from the slot graph together with every edge pointing from or to it, returning the node
+    /// value and the removed edges if the node was not previously removed.
+    pub fn remove_node_detailed(&mut self, key: NodeKey<K>) -> Option<RemovedNode<K, N, E>> {
+        let adj = self.nodes.remove(key)?;
+        let mut edges = Vec::new();
+        self.remove_incident_edges(adj, |k, e, value| edges.push((k, e.from, e.to, value)));
+        let value = self.node_values.remove(key)?;
+        Some(RemovedNode { value, edges })
+    }
+
+    /// Removes every node for which the predicate returns `false` together with its edges, keeping the keys of the
+    /// remaining nodes and edges.
+    ///
+    /// The predicate is called once with the key and a mutable reference to the value of every node, in iteration
+    /// order. The edges are cleaned up in a single pass afterwards, so removing many nodes at once is cheaper than
+    /// removing them one by one.
+    pub fn retain_nodes<F: FnMut(NodeKey<K>, &mut N) -> bool>(&mut self, mut keep: F) {
+        let values = &mut self.node_values;
+        let before = self.nodes.len();
+        self.nodes.retain(|k, _| {
+            let kept = values.get_mut(k).is_some_and(|v| keep(k, v));
+            if !kept {
+                values.remove(k);
+            }
+            kept
+        });
+        if self.nodes.len() == before {
+            return;
+        }
+        let nodes = &self.nodes;
+        self.edges
+            .retain(|_, e| nodes.contains_key(e.from) && nodes.contains_key(e.to));
+        self.prune_adjacency();
+    }
+
+    /// Removes every node and edge, returning an iterator over the keys and values of the removed nodes in iteration
+    /// order.
+    ///
+    /// The values of the edges are dropped; drain them first with [`Self::drain_edges`] to keep them. The graph is
+    /// empty as soon as this returns, whether or not the iterator is consumed.
+    pub fn drain_nodes(&mut self) -> impl Iterator<Item = (NodeKey<K>, N)> {
+        self.nodes.clear();
+        self.edges.clear();
+        self.edge_values.clear();
+        std::mem::take(&mut self.node_values).into_iter()
+    }
+
+    /// Removes every node and edge, keeping the allocated memory for reuse.
+    ///
+    /// All existing keys become stale.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.edges.clear();
+        self.node_values.clear();
+        self.edge_values.clear();
     }
 
     /// Returns a reference to the value corresponding to the node key.
     pub fn get_node(&self, key: NodeKey<K>) -> Option<&N> {
-        self.nodes.get(key).map(|n| &n.value)
+        self.node_values.get(key)
+    }
+
+    /// Returns references to the values corresponding to the node keys in the same order, with `None` for every
+    /// stale key.
+    pub fn get_nodes<I: IntoIterator<Item = NodeKey<K>>>(&self, keys: I) -> Vec<Option<&N>> {
+        keys.into_iter().map(|k| self.get_node(k)).collect()
     }
 
     /// Returns a mutable reference to the value corresponding to the node key.
     pub fn get_node_mut(&mut self, key: NodeKey<K>) -> Option<&mut N> {
-        self.nodes.get_mut(key).map(|n| &mut n.value)
+        self.node_values.get_mut(key)
+    }
+
+    /// Returns mutable references to the values of several nodes at once, or `None` if any key is stale or appears
+    /// more than once.
+    pub fn get_disjoint_node_mut<const M: usize>(
+        &mut self,
+        keys: [NodeKey<K>; M],
+    ) -> Option<[&mut N; M]> {
+        self.node_values.get_disjoint_mut(keys)
     }
 
     /// Returns the number of nodes in the slot graph.
@@ -102,43 +836,229 @@ impl<K: Key, N, E> SlotGraph<K, N, E> {
 
     /// An iterator visiting all the node key-value pairs in arbitrary order.
     pub fn iter_nodes(&self) -> impl Iterator<Item = (NodeKey<K>, &N)> {
-        self.nodes.iter().map(|(k, n)| (k, &n.value))
+        self.node_values.iter()
+    }
+
+    /// An iterator visiting all the node keys in the same order as [`Self::iter_nodes`], without the values.
+    pub fn node_keys(&self) -> impl Iterator<Item = NodeKey<K>> + '_ {
+        self.nodes.keys()
     }
 
     /// An iterator visiting all the node key-value pairs in arbitrary order, returning mutable references to the node values.
     pub fn iter_nodes_mut(&mut self) -> impl Iterator<Item = (NodeKey<K>, &mut N)> {
-        self.nodes.iter_mut().map(|(k, n)| (k, &mut n.value))
+        self.node_values.iter_mut()
+    }
+
+    /// Identifies two nodes by rewiring every edge touching `absorb` to `keep`, combining both values with `merge` and
+    /// removing `absorb`, returning `keep`.
+    ///
+    /// Edges between the two nodes become self-loops on `keep` and are preserved.
+    /// Returns `None` if either key is stale or if both keys are the same.
+    pub fn merge_nodes<F: FnOnce(N, N) -> N>(
+        &mut self,
+        keep: NodeKey<K>,
+        absorb: NodeKey<K>,
+        merge: F,
+    ) -> Option<NodeKey<K>> {
+        if keep == absorb || !self.nodes.contains_key(keep) || !self.nodes.contains_key(absorb) {
+            return None;
+        }
+        let adj = self.nodes.remove(absorb)?;
+        let rewire = |nk: NodeKey<K>| if nk == absorb { keep } else { nk };
+        // an undirected edge between both nodes is already listed in both lists of `keep`, and becomes a self-loop
+        // listed once in each
+        let relist = |other: NodeKey<K>| Ty::is_directed() || other != keep;
+        for (ek, other) in adj.outgoing {
+            let e = &mut self.edges[ek];
+            (e.from, e.to) = (rewire(e.from), rewire(e.to));
+            if let Some(other_adj) = self.nodes.get_mut(other) {
+                retarget_adjacent(&mut other_adj.incoming, ek, keep);
+                if !Ty::is_directed() {
+                    retarget_adjacent(&mut other_adj.outgoing, ek, keep);
+                }
+            }
+            if relist(other) {
+                self.nodes[keep].outgoing.push((ek, rewire(other)));
+            }
+        }
+        for (ek, other) in adj.incoming {
+            let e = &mut self.edges[ek];
+            (e.from, e.to) = (rewire(e.from), rewire(e.to));
+            if let Some(other_adj) = self.nodes.get_mut(other) {
+                retarget_adjacent(&mut other_adj.outgoing, ek, keep);
+                if !Ty::is_directed() {
+                    retarget_adjacent(&mut other_adj.incoming, ek, keep);
+                }
+            }
+            if relist(other) {
+                self.nodes[keep].incoming.push((ek, rewire(other)));
+            }
+        }
+        let absorbed = self.node_values.remove(absorb)?;
+        let kept = self.node_values.remove(keep)?;
+        self.node_values.insert(keep, merge(kept, absorbed));
+        Some(keep)
+    }
+
+    /// Assigns every node a contiguous index in `0..node_len()`, returning the map from node keys to indices and the
+    /// node keys in index order.
+    pub fn index_nodes(&self) -> (SecondaryMap<NodeKey<K>, usize>, Vec<NodeKey<K>>) {
+        let keys: Vec<NodeKey<K>> = self.nodes.keys().collect();
+        let indices = keys.iter().enumerate().map(|(i, &k)| (k, i)).collect();
+        (indices, keys)
     }
 
     pub fn into_node_iter(self) -> impl Iterator<Item = (NodeKey<K>, N)> {
-        self.nodes.into_iter().map(|(k, n)| (k, n.value))
+        self.node_values.into_iter()
     }
 }
 
 // edge methods
-impl<K: Key, N, E> SlotGraph<K, N, E> {
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
     /// Insert a new edge with the given value into the slot graph.
     ///
     /// # Panics
     ///
-    /// Panics if the number of edges in the graph equals 2³² - 2.
+    /// Panics if either node key is stale or if the number of edges in the graph equals [`SlotGraph::MAX_EDGES`].
     pub fn insert_edge(&mut self, from: NodeKey<K>, to: NodeKey<K>, value: E) -> EdgeKey<K> {
-        self.edges.insert(EdgeValue { from, to, value })
+        assert!(
+            self.nodes.contains_key(from) && self.nodes.contains_key(to),
+            "stale node key"
+        );
+        let e = EdgeNodes { from, to };
+        let key = self.edges.insert(e);
+        link::<K, Ty>(&mut self.nodes, key, e);
+        self.edge_values.insert(key, value);
+        key
+    }
+
+    /// Replaces the value of the first edge inserted among those pointing from one node to another, or inserts a new
+    /// edge if there is none, returning the key of the edge and the old value if there was one.
+    ///
+    /// Using this instead of [`Self::insert_edge`] keeps at most one edge between every ordered pair of nodes, or
+    /// unordered pair in an undirected graph.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node key is stale or if a new edge is needed and the number of edges in the graph equals
+    /// [`SlotGraph::MAX_EDGES`].
+    pub fn update_edge(
+        &mut self,
+        from: NodeKey<K>,
+        to: NodeKey<K>,
+        value: E,
+    ) -> (EdgeKey<K>, Option<E>) {
+        match self.find_edge(from, to) {
+            Some(key) => {
+                let old = std::mem::replace(&mut self.edge_values[key], value);
+                (key, Some(old))
+            }
+            None => (self.insert_edge(from, to, value), None),
+        }
+    }
+
+    /// Insert a new edge with the given value into the slot graph, or return the value if either node key is stale or
+    /// the graph already holds [`SlotGraph::MAX_EDGES`] edges.
+    pub fn try_insert_edge(
+        &mut self,
+        from: NodeKey<K>,
+        to: NodeKey<K>,
+        value: E,
+    ) -> Result<EdgeKey<K>, E> {
+        if self.edge_len() >= Self::MAX_EDGES
+            || !self.nodes.contains_key(from)
+            || !self.nodes.contains_key(to)
+        {
+            return Err(value);
+        }
+        Ok(self.insert_edge(from, to, value))
     }
 
     /// Removes an edge key from the slot graph, returning the value at the given key if it was not previously removed.
     pub fn remove_edge(&mut self, key: EdgeKey<K>) -> Option<E> {
-        self.edges.remove(key).map(|e| e.value)
+        let e = self.edges.remove(key)?;
+        unlink::<K, Ty>(&mut self.nodes, key, e);
+        self.edge_values.remove(key)
+    }
+
+    /// Removes every edge for which the predicate returns `true`, returning the keys and values of the removed edges.
+    ///
+    /// The predicate is called with the key, both node keys and the value of every edge.
+    pub fn remove_edges_where<F: FnMut(EdgeKey<K>, NodeKey<K>, NodeKey<K>, &E) -> bool>(
+        &mut self,
+        mut pred: F,
+    ) -> Vec<(EdgeKey<K>, E)> {
+        let mut removed = Vec::new();
+        let values = &mut self.edge_values;
+        let nodes = &mut self.nodes;
+        self.edges.retain(|k, e| {
+            let remove = values.get(k).is_some_and(|v| pred(k, e.from, e.to, v));
+            if remove {
+                unlink::<K, Ty>(nodes, k, *e);
+                removed.extend(values.remove(k).map(|v| (k, v)));
+            }
+            !remove
+        });
+        removed
+    }
+
+    /// Removes every edge for which the predicate returns `false`, keeping the keys of the remaining edges.
+    ///
+    /// The predicate is called once with the key and a mutable reference to the value of every edge, in iteration
+    /// order. Unlike [`Self::remove_edges_where`], the adjacency of the nodes is cleaned up in a single pass
+    /// afterwards.
+    pub fn retain_edges<F: FnMut(EdgeKey<K>, &mut E) -> bool>(&mut self, mut keep: F) {
+        let values = &mut self.edge_values;
+        let before = self.edges.len();
+        self.edges
+            .retain(|k, _| values.get_mut(k).is_some_and(|v| keep(k, v)));
+        if self.edges.len() != before {
+            self.prune_adjacency();
+        }
+    }
+
+    /// Removes every edge while keeping the nodes, returning an iterator over the keys and values of the removed edges
+    /// in iteration order.
+    ///
+    /// The graph has no edges as soon as this returns, whether or not the iterator is consumed.
+    pub fn drain_edges(&mut self) -> impl Iterator<Item = (EdgeKey<K>, E)> {
+        self.edges.clear();
+        for adj in self.nodes.values_mut() {
+            adj.outgoing.clear();
+            adj.incoming.clear();
+        }
+        std::mem::take(&mut self.edge_values).into_iter()
+    }
+
+    /// Removes every edge while keeping the nodes, keeping the allocated memory for reuse.
+    ///
+    /// All existing edge keys become stale.
+    pub fn clear_edges(&mut self) {
+        self.edges.clear();
+        self.edge_values.clear();
+        for adj in self.nodes.values_mut() {
+            adj.outgoing.clear();
+            adj.incoming.clear();
+        }
     }
 
     /// Returns a reference to the value corresponding to the edge key.
     pub fn get_edge(&self, key: EdgeKey<K>) -> Option<&E> {
-        self.edges.get(key).map(|e| &e.value)
+        self.edge_values.get(key)
     }
 
     /// Returns a mutable reference to the value corresponding to the edge key.
     pub fn get_edge_mut(&mut self, key: EdgeKey<K>) -> Option<&mut E> {
-        self.edges.get_mut(key).map(|e| &mut e.value)
+        self.edge_values.get_mut(key)
+    }
+
+    /// Returns mutable references to the values of several edges at once, or `None` if any key is stale or appears
+    /// more than once.
+    pub fn get_disjoint_edge_mut<const M: usize>(
+        &mut self,
+        keys: [EdgeKey<K>; M],
+    ) -> Option<[&mut E; M]> {
+        self.edge_values.get_disjoint_mut(keys)
     }
 
     /// Returns the number of edges in the slot graph.
@@ -148,16 +1068,60 @@ impl<K: Key, N, E> SlotGraph<K, N, E> {
 
     /// An iterator visiting all the edge key-value pairs in arbitrary order.
     pub fn iter_edges(&self) -> impl Iterator<Item = (EdgeKey<K>, &E)> {
-        self.edges.iter().map(|(k, n)| (k, &n.value))
+        self.edge_values.iter()
+    }
+
+    /// An iterator visiting all the edge keys in the same order as [`Self::iter_edges`], without the values.
+    pub fn edge_keys(&self) -> impl Iterator<Item = EdgeKey<K>> + '_ {
+        self.edges.keys()
     }
 
     /// An iterator visiting all the edge key-value pairs in arbitrary order, returning mutable references to the edge values.
     pub fn iter_edges_mut(&mut self) -> impl Iterator<Item = (EdgeKey<K>, &mut E)> {
-        self.edges.iter_mut().map(|(k, e)| (k, &mut e.value))
+        self.edge_values.iter_mut()
+    }
+
+    /// An iterator visiting all the edges in arbitrary order, yielding the edge key, both node keys and the edge value.
+    pub fn iter_edges_full(
+        &self,
+    ) -> impl Iterator<Item = (EdgeKey<K>, NodeKey<K>, NodeKey<K>, &E)> {
+        self.edge_values.iter().map(|(k, value)| {
+            let e = self.edges[k];
+            (k, e.from, e.to, value)
+        })
+    }
+
+    /// An iterator visiting all the edges in arbitrary order, yielding the edge key, both node keys and a mutable reference to the edge value.
+    pub fn iter_edges_full_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (EdgeKey<K>, NodeKey<K>, NodeKey<K>, &mut E)> {
+        let edges = &self.edges;
+        self.edge_values.iter_mut().map(|(k, value)| {
+            let e = edges[k];
+            (k, e.from, e.to, value)
+        })
+    }
+
+    /// Calls the closure for every edge with its key, both node keys and a mutable reference to its value.
+    pub fn reweight_edges<F: FnMut(EdgeKey<K>, NodeKey<K>, NodeKey<K>, &mut E)>(
+        &mut self,
+        mut f: F,
+    ) {
+        for (k, from, to, value) in self.iter_edges_full_mut() {
+            f(k, from, to, value);
+        }
+    }
+
+    /// Assigns every edge a contiguous index in `0..edge_len()`, returning the map from edge keys to indices and the
+    /// edge keys in index order.
+    pub fn index_edges(&self) -> (SecondaryMap<EdgeKey<K>, usize>, Vec<EdgeKey<K>>) {
+        let keys: Vec<EdgeKey<K>> = self.edges.keys().collect();
+        let indices = keys.iter().enumerate().map(|(i, &k)| (k, i)).collect();
+        (indices, keys)
     }
 
     pub fn into_edge_iter(self) -> impl Iterator<Item = (EdgeKey<K>, E)> {
-        self.edges.into_iter().map(|(k, e)| (k, e.value))
+        self.edge_values.into_iter()
     }
 
     pub fn get_edge_nodes(&self, key: EdgeKey<K>) -> Option<(NodeKey<K>, NodeKey<K>)> {
@@ -170,3 +1134,315 @@ impl<K: Key, N, E> SlotGraph<K, N, E> {
         self.edges.iter().map(|(k, e)| (k, (e.from, e.to)))
     }
 }
+
+// adjacency methods
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// An iterator over the keys of the edges pointing from the node, in insertion order.
+    ///
+    /// In an undirected graph these are all edges connected to the node.
+    pub fn out_edges(&self, key: NodeKey<K>) -> impl Iterator<Item = EdgeKey<K>> + '_ {
+        self.outgoing(key).iter().map(|&(ek, _)| ek)
+    }
+
+    /// An iterator over the keys of the edges pointing to the node, in insertion order.
+    ///
+    /// In an undirected graph these are all edges connected to the node.
+    pub fn in_edges(&self, key: NodeKey<K>) -> impl Iterator<Item = EdgeKey<K>> + '_ {
+        self.incoming(key).iter().map(|&(ek, _)| ek)
+    }
+
+    /// An iterator over the keys of the edges pointing from or to the node, the outgoing edges first.
+    ///
+    /// A self-loop is yielded once.
+    pub fn edges_of(&self, key: NodeKey<K>) -> impl Iterator<Item = EdgeKey<K>> + '_ {
+        self.incident(key).map(|(ek, _)| ek)
+    }
+
+    /// An iterator over the nodes pointed to by the edges pointing from the node, once per edge.
+    pub fn successors(&self, key: NodeKey<K>) -> impl Iterator<Item = NodeKey<K>> + '_ {
+        self.outgoing(key).iter().map(|&(_, nk)| nk)
+    }
+
+    /// An iterator over the nodes pointing to the node through an edge, once per edge.
+    pub fn predecessors(&self, key: NodeKey<K>) -> impl Iterator<Item = NodeKey<K>> + '_ {
+        self.incoming(key).iter().map(|&(_, nk)| nk)
+    }
+
+    /// An iterator over the nodes at the other end of every edge pointing from or to the node, once per edge and in
+    /// the same order as [`SlotGraph::edges_of`].
+    ///
+    /// A self-loop yields the node itself once.
+    pub fn neighbors(&self, key: NodeKey<K>) -> impl Iterator<Item = NodeKey<K>> + '_ {
+        self.incident(key).map(|(_, nk)| nk)
+    }
+
+    /// An iterator over the keys of the edges pointing from one node to another, in insertion order.
+    ///
+    /// In an undirected graph these are all edges between the two nodes. Takes time proportional to the smaller of
+    /// the out-degree of `from` and the in-degree of `to`; use an
+    /// [`EdgeIndexedSlotGraph`](crate::EdgeIndexedSlotGraph) for constant-time lookups.
+    pub fn edges_between(
+        &self,
+        from: NodeKey<K>,
+        to: NodeKey<K>,
+    ) -> impl Iterator<Item = EdgeKey<K>> + '_ {
+        let (outgoing, incoming) = (self.outgoing(from), self.incoming(to));
+        let (list, other) = if outgoing.len() <= incoming.len() {
+            (outgoing, to)
+        } else {
+            (incoming, from)
+        };
+        list.iter()
+            .filter(move |&&(_, nk)| nk == other)
+            .map(|&(ek, _)| ek)
+    }
+
+    /// Returns the key of the first edge inserted among those pointing from one node to another, or `None` if there is
+    /// none.
+    ///
+    /// Takes time proportional to the smaller of the out-degree of `from` and the in-degree of `to`.
+    pub fn find_edge(&self, from: NodeKey<K>, to: NodeKey<K>) -> Option<EdgeKey<K>> {
+        self.edges_between(from, to).next()
+    }
+
+    /// Returns whether there is an edge pointing from one node to another.
+    ///
+    /// In an undirected graph the order of the nodes does not matter.
+    pub fn contains_edge_between(&self, from: NodeKey<K>, to: NodeKey<K>) -> bool {
+        self.find_edge(from, to).is_some()
+    }
+
+    /// Returns whether there is an edge between two nodes in either direction.
+    ///
+    /// A node is its own neighbor if it has a self-loop.
+    pub fn is_neighbor(&self, a: NodeKey<K>, b: NodeKey<K>) -> bool {
+        self.edges_connecting(a, b).next().is_some()
+    }
+
+    /// An iterator over the keys of the edges between two nodes in either direction, the edges pointing from `a` to
+    /// `b` first.
+    ///
+    /// A self-loop is yielded once. In an undirected graph this is the same as [`SlotGraph::edges_between`].
+    pub fn edges_connecting(
+        &self,
+        a: NodeKey<K>,
+        b: NodeKey<K>,
+    ) -> impl Iterator<Item = EdgeKey<K>> + '_ {
+        let reverse = (Ty::is_directed() && a != b).then(|| self.edges_between(b, a));
+        self.edges_between(a, b)
+            .chain(reverse.into_iter().flatten())
+    }
+
+    /// The outgoing edges followed by the incoming edges that are not self-loops, with the nodes at their other end.
+    ///
+    /// The outgoing edges of a node in an undirected graph already include every edge connected to it.
+    pub(crate) fn incident(
+        &self,
+        key: NodeKey<K>,
+    ) -> impl Iterator<Item = (EdgeKey<K>, NodeKey<K>)> + '_ {
+        let incoming = self
+            .incoming(key)
+            .iter()
+            .filter(move |&&(_, nk)| Ty::is_directed() && nk != key);
+        self.outgoing(key).iter().chain(incoming).copied()
+    }
+}
+
+// degree methods
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Returns the number of edges pointing from the node, or `None` if the node key is stale.
+    pub fn out_degree(&self, key: NodeKey<K>) -> Option<usize> {
+        self.nodes.get(key).map(|adj| adj.outgoing.len())
+    }
+
+    /// Returns the number of edges pointing to the node, or `None` if the node key is stale.
+    pub fn in_degree(&self, key: NodeKey<K>) -> Option<usize> {
+        self.nodes.get(key).map(|adj| adj.incoming.len())
+    }
+
+    /// Returns the number of edges pointing from every node.
+    pub fn out_degrees(&self) -> SecondaryMap<NodeKey<K>, usize> {
+        self.nodes
+            .iter()
+            .map(|(k, adj)| (k, adj.outgoing.len()))
+            .collect()
+    }
+
+    /// Returns the number of edges pointing to every node.
+    pub fn in_degrees(&self) -> SecondaryMap<NodeKey<K>, usize> {
+        self.nodes
+            .iter()
+            .map(|(k, adj)| (k, adj.incoming.len()))
+            .collect()
+    }
+
+    /// An iterator over the nodes without incoming edges, in arbitrary order.
+    pub fn sources(&self) -> impl Iterator<Item = NodeKey<K>> {
+        self.in_degrees()
+            .into_iter()
+            .filter(|&(_, d)| d == 0)
+            .map(|(k, _)| k)
+    }
+
+    /// An iterator over the nodes without outgoing edges, in arbitrary order.
+    pub fn sinks(&self) -> impl Iterator<Item = NodeKey<K>> {
+        self.out_degrees()
+            .into_iter()
+            .filter(|&(_, d)| d == 0)
+            .map(|(k, _)| k)
+    }
+}
+
+// capacity methods
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// Reserves room for at least `additional` more nodes.
+    pub fn reserve_nodes(&mut self, additional: usize) {
+        self.nodes.reserve(additional);
+        self.node_values.set_capacity(self.nodes.capacity());
+    }
+
+    /// Reserves room for at least `additional` more edges.
+    pub fn reserve_edges(&mut self, additional: usize) {
+        self.edges.reserve(additional);
+        self.edge_values.set_capacity(self.edges.capacity());
+    }
+
+    /// Returns the number of nodes the graph can hold without reallocating.
+    pub fn node_capacity(&self) -> usize {
+        self.nodes.capacity()
+    }
+
+    /// Returns the number of edges the graph can hold without reallocating.
+    pub fn edge_capacity(&self) -> usize {
+        self.edges.capacity()
+    }
+
+    /// Shrinks the lists of adjacent edges of every node to fit their contents.
+    ///
+    /// The slots of removed nodes and edges cannot be released, since they remember their version to keep old keys
+    /// stale, so [`Self::node_capacity`] and [`Self::edge_capacity`] never shrink. To reclaim that memory as well,
+    /// rebuild the graph with fresh keys using [`Self::remap_keys`].
+    pub fn shrink_to_fit(&mut self) {
+        for adj in self.nodes.values_mut() {
+            adj.outgoing.shrink_to_fit();
+            adj.incoming.shrink_to_fit();
+        }
+    }
+}
+
+// adjacency helpers
+impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+    /// The edges pointing from the node and the nodes they point to, empty if the node key is stale.
+    pub(crate) fn outgoing(&self, key: NodeKey<K>) -> &[(EdgeKey<K>, NodeKey<K>)] {
+        self.nodes.get(key).map_or(&[], |adj| &adj.outgoing)
+    }
+
+    /// The edges pointing to the node and the nodes they point from, empty if the node key is stale.
+    pub(crate) fn incoming(&self, key: NodeKey<K>) -> &[(EdgeKey<K>, NodeKey<K>)] {
+        self.nodes.get(key).map_or(&[], |adj| &adj.incoming)
+    }
+}
+
+// keyed reconstruction
+#[cfg(feature = "json")]
+mod keyed {
+    use serde::{de::IgnoredAny, Deserialize, Deserializer};
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    // The structural values are only deserialized by `slot_map_with_keys`, so every one starts out empty and is filled
+    // in afterwards.
+    impl<'de, K: Key> Deserialize<'de> for Adjacency<K> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            IgnoredAny::deserialize(deserializer)?;
+            Ok(Self::new())
+        }
+    }
+
+    impl<'de, K: Key> Deserialize<'de> for EdgeNodes<K> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            IgnoredAny::deserialize(deserializer)?;
+            Ok(Self {
+                from: NodeKey::default(),
+                to: NodeKey::default(),
+            })
+        }
+    }
+
+    /// The number of slots beyond twice the number of keys that [`slot_map_with_keys`] allocates at most, so a small
+    /// document cannot make it allocate billions of vacant slots.
+    const SPARE_SLOTS: usize = 1024;
+
+    /// Builds a slot map holding empty values under exactly the given keys, through the slot layout [`SlotMap`]
+    /// serializes to.
+    ///
+    /// Returns an error if the highest slot index exceeds twice the number of keys plus [`SPARE_SLOTS`], or if the
+    /// resulting slot map does not hold exactly the given keys, as would happen if the layout changed.
+    fn slot_map_with_keys<K: Key, V: for<'de> Deserialize<'de>>(
+        keys: impl Iterator<Item = K>,
+    ) -> Result<SlotMap<K, V>, String> {
+        let keys: Vec<K> = keys.collect();
+        let mut max_idx = 0;
+        for key in &keys {
+            let idx = key.data().as_ffi() as u32;
+            if idx == 0 || idx == u32::MAX {
+                return Err(format!("invalid key {:?}", key.data()));
+            }
+            max_idx = max_idx.max(idx as usize);
+        }
+        let limit = 2 * keys.len() + SPARE_SLOTS;
+        if max_idx > limit {
+            return Err(format!(
+                "slot index {max_idx} is too high for {} keys, at most {limit} is allowed",
+                keys.len()
+            ));
+        }
+
+        let vacant = json!({ "value": null, "version": 0 });
+        let mut slots = vec![vacant; max_idx + 1];
+        for key in &keys {
+            let idx = key.data().as_ffi() as u32 as usize;
+            if !slots[idx]["value"].is_null() {
+                return Err(format!("slot {idx} is used by more than one key"));
+            }
+            slots[idx] = json!({ "value": {}, "version": key_version(*key) });
+        }
+        let map: SlotMap<K, V> =
+            serde_json::from_value(Value::Array(slots)).map_err(|e| e.to_string())?;
+        if map.len() != keys.len() || !keys.iter().all(|&key| map.contains_key(key)) {
+            return Err("the slot map layout is not supported".to_string());
+        }
+        Ok(map)
+    }
+
+    /// An edge with its key, the nodes it points from and to, and its value.
+    pub(crate) type KeyedEdge<K, E> = (EdgeKey<K>, NodeKey<K>, NodeKey<K>, E);
+
+    impl<K: Key, N, E, Ty: EdgeType> SlotGraph<K, N, E, Ty> {
+        /// Builds a graph holding exactly the given nodes and edges under the given keys, linking the edges in order.
+        ///
+        /// Returns an error if two keys share a slot, if an edge refers to a node that is not given, or if a slot
+        /// index is far above the number of nodes or edges. Takes time and memory proportional to the highest slot
+        /// index used, which is bounded by the number of keys.
+        pub(crate) fn from_keyed_parts(
+            nodes: Vec<(NodeKey<K>, N)>,
+            edges: Vec<KeyedEdge<K, E>>,
+        ) -> Result<Self, String> {
+            let mut sg = Self::empty();
+            sg.nodes = slot_map_with_keys(nodes.iter().map(|&(k, _)| k))?;
+            sg.edges = slot_map_with_keys(edges.iter().map(|&(k, ..)| k))?;
+            sg.node_values = nodes.into_iter().collect();
+            for (k, from, to, value) in edges {
+                if !sg.nodes.contains_key(from) || !sg.nodes.contains_key(to) {
+                    return Err(format!("edge {:?} refers to a missing node", k.data()));
+                }
+                let e = EdgeNodes { from, to };
+                sg.edges[k] = e;
+                link::<K, Ty>(&mut sg.nodes, k, e);
+                sg.edge_values.insert(k, value);
+            }
+            Ok(sg)
+        }
+    }
+}
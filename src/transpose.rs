@@ -0,0 +1,94 @@
+//! A borrowed view of a graph with the direction of every edge reversed.
+
+use slotmap::Key;
+
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// A view of a [`SlotGraph`] in which every edge points in the opposite direction, created with
+/// [`SlotGraph::transpose_view`].
+///
+/// The view shares the keys and values of the underlying graph without copying them. It implements [`Adjacency`], so
+/// algorithms such as [`dominators`](crate::algo::dominators) and [`kosaraju_scc`](crate::algo::kosaraju_scc) run on
+/// it directly.
+///
+/// [`Adjacency`]: crate::Adjacency
+pub struct TransposeView<'a, K: Key, N, E> {
+    graph: &'a SlotGraph<K, N, E>,
+}
+
+// Clone and Copy are implemented manually to avoid requiring `N: Clone` and `E: Clone`.
+impl<K: Key, N, E> Clone for TransposeView<'_, K, N, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: Key, N, E> Copy for TransposeView<'_, K, N, E> {}
+
+impl<K: Key, N, E> SlotGraph<K, N, E> {
+    /// Returns a view of the graph with the direction of every edge reversed.
+    pub fn transpose_view(&self) -> TransposeView<'_, K, N, E> {
+        TransposeView { graph: self }
+    }
+}
+
+impl<'a, K: Key, N, E> TransposeView<'a, K, N, E> {
+    /// Returns the underlying graph.
+    pub fn graph(&self) -> &'a SlotGraph<K, N, E> {
+        self.graph
+    }
+
+    /// Returns a reference to the value corresponding to the node key.
+    pub fn get_node(&self, key: NodeKey<K>) -> Option<&'a N> {
+        self.graph.get_node(key)
+    }
+
+    /// Returns a reference to the value corresponding to the edge key.
+    pub fn get_edge(&self, key: EdgeKey<K>) -> Option<&'a E> {
+        self.graph.get_edge(key)
+    }
+
+    /// Returns the node keys the reversed edge points from and to.
+    pub fn get_edge_nodes(&self, key: EdgeKey<K>) -> Option<(NodeKey<K>, NodeKey<K>)> {
+        self.graph.get_edge_nodes(key).map(|(from, to)| (to, from))
+    }
+
+    /// Returns the number of nodes in the graph.
+    pub fn node_len(&self) -> usize {
+        self.graph.node_len()
+    }
+
+    /// Returns the number of edges in the graph.
+    pub fn edge_len(&self) -> usize {
+        self.graph.edge_len()
+    }
+
+    /// An iterator visiting all the reversed edges and the node keys they point from and to in arbitrary order.
+    pub fn iter_edge_nodes(
+        &self,
+    ) -> impl Iterator<Item = (EdgeKey<K>, (NodeKey<K>, NodeKey<K>))> + 'a {
+        self.graph
+            .iter_edge_nodes()
+            .map(|(k, (from, to))| (k, (to, from)))
+    }
+
+    /// An iterator over the keys of the reversed edges pointing from the node.
+    pub fn out_edges(&self, key: NodeKey<K>) -> impl Iterator<Item = EdgeKey<K>> + 'a {
+        self.graph.in_edges(key)
+    }
+
+    /// An iterator over the keys of the reversed edges pointing to the node.
+    pub fn in_edges(&self, key: NodeKey<K>) -> impl Iterator<Item = EdgeKey<K>> + 'a {
+        self.graph.out_edges(key)
+    }
+
+    /// An iterator over the nodes pointed to by the reversed edges pointing from the node, once per edge.
+    pub fn successors(&self, key: NodeKey<K>) -> impl Iterator<Item = NodeKey<K>> + 'a {
+        self.graph.predecessors(key)
+    }
+
+    /// An iterator over the nodes pointing to the node through a reversed edge, once per edge.
+    pub fn predecessors(&self, key: NodeKey<K>) -> impl Iterator<Item = NodeKey<K>> + 'a {
+        self.graph.successors(key)
+    }
+}
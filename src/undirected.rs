@@ -0,0 +1,58 @@
+//! A borrowed view of a graph ignoring the direction of its edges.
+
+use std::collections::HashSet;
+
+use slotmap::Key;
+
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// A view of a [`SlotGraph`] in which every edge connects its nodes in both directions, created with
+/// [`SlotGraph::as_undirected`].
+///
+/// The view shares the keys and values of the underlying graph without copying them. Use an
+/// [`UnSlotGraph`](crate::UnSlotGraph) to own an undirected graph.
+pub struct UndirectedView<'a, K: Key, N, E> {
+    graph: &'a SlotGraph<K, N, E>,
+}
+
+// Clone and Copy are implemented manually to avoid requiring `N: Clone` and `E: Clone`.
+impl<K: Key, N, E> Clone for UndirectedView<'_, K, N, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: Key, N, E> Copy for UndirectedView<'_, K, N, E> {}
+
+impl<K: Key, N, E> SlotGraph<K, N, E> {
+    /// Returns a view of the graph that ignores the direction of its edges.
+    pub fn as_undirected(&self) -> UndirectedView<'_, K, N, E> {
+        UndirectedView { graph: self }
+    }
+}
+
+impl<'a, K: Key, N, E> UndirectedView<'a, K, N, E> {
+    /// Returns the underlying graph.
+    pub fn graph(&self) -> &'a SlotGraph<K, N, E> {
+        self.graph
+    }
+
+    /// An iterator over the keys of the edges pointing from or to the node, yielding self-loops once.
+    pub fn edges(&self, key: NodeKey<K>) -> impl Iterator<Item = EdgeKey<K>> + 'a {
+        self.graph.edges_of(key)
+    }
+
+    /// An iterator over the distinct nodes connected to the node by an edge in either direction.
+    ///
+    /// The node itself is included if it has a self-loop.
+    pub fn neighbors(&self, key: NodeKey<K>) -> impl Iterator<Item = NodeKey<K>> + 'a {
+        let mut seen = HashSet::new();
+        self.graph.neighbors(key).filter(move |&nk| seen.insert(nk))
+    }
+
+    /// Returns the number of edge endpoints at the node, counting self-loops twice, or `None` if the node key is
+    /// stale.
+    pub fn degree(&self, key: NodeKey<K>) -> Option<usize> {
+        Some(self.graph.out_degree(key)? + self.graph.in_degree(key)?)
+    }
+}
@@ -0,0 +1,192 @@
+//! Implementations of the [`petgraph::visit`] traits, so that the algorithms of petgraph run directly on a
+//! [`SlotGraph`].
+//!
+//! Node and edge ids are [`NodeKey`]s and [`EdgeKey`]s. The neighbors and edges of a node are those reached through
+//! its outgoing edges, which in an undirected graph are all its edges. Edge references of an undirected graph
+//! point from the node they were reached from.
+
+use std::{collections::HashSet, slice};
+
+use petgraph::visit::{
+    Data, EdgeRef, GraphBase, IntoEdgeReferences, IntoEdges, IntoNeighbors, IntoNodeIdentifiers,
+    NodeCount, Visitable,
+};
+use slotmap::{secondary, Key};
+
+use crate::edge_type::EdgeType;
+use crate::slotgraph::{EdgeKey, NodeKey, SlotGraph};
+
+/// A reference to an edge of a [`SlotGraph`], yielded by [`IntoEdges`] and [`IntoEdgeReferences`].
+pub struct EdgeReference<'a, K: Key, E> {
+    key: EdgeKey<K>,
+    source: NodeKey<K>,
+    target: NodeKey<K>,
+    weight: &'a E,
+}
+
+// Clone and Copy are implemented manually to avoid requiring `E: Clone`.
+impl<K: Key, E> Clone for EdgeReference<'_, K, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: Key, E> Copy for EdgeReference<'_, K, E> {}
+
+impl<K: Key, E> EdgeRef for EdgeReference<'_, K, E> {
+    type NodeId = NodeKey<K>;
+    type EdgeId = EdgeKey<K>;
+    type Weight = E;
+
+    fn source(&self) -> NodeKey<K> {
+        self.source
+    }
+
+    fn target(&self) -> NodeKey<K> {
+        self.target
+    }
+
+    fn weight(&self) -> &E {
+        self.weight
+    }
+
+    fn id(&self) -> EdgeKey<K> {
+        self.key
+    }
+}
+
+/// An iterator over the neighbors of a node, see [`IntoNeighbors`].
+pub struct Neighbors<'a, K: Key> {
+    adjacent: slice::Iter<'a, (EdgeKey<K>, NodeKey<K>)>,
+}
+
+impl<K: Key> Iterator for Neighbors<'_, K> {
+    type Item = NodeKey<K>;
+
+    fn next(&mut self) -> Option<NodeKey<K>> {
+        self.adjacent.next().map(|&(_, nk)| nk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.adjacent.size_hint()
+    }
+}
+
+/// An iterator over the edges of a node, see [`IntoEdges`].
+pub struct Edges<'a, K: Key, E> {
+    source: NodeKey<K>,
+    adjacent: slice::Iter<'a, (EdgeKey<K>, NodeKey<K>)>,
+    weights: &'a secondary::SecondaryMap<EdgeKey<K>, E>,
+}
+
+impl<'a, K: Key, E> Iterator for Edges<'a, K, E> {
+    type Item = EdgeReference<'a, K, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.adjacent.next().map(|&(key, target)| EdgeReference {
+            key,
+            source: self.source,
+            target,
+            weight: &self.weights[key],
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.adjacent.size_hint()
+    }
+}
+
+/// An iterator over all edges of a graph, see [`IntoEdgeReferences`].
+pub struct EdgeReferences<'a, K: Key, N, E, Ty: EdgeType> {
+    graph: &'a SlotGraph<K, N, E, Ty>,
+    edges: secondary::Iter<'a, EdgeKey<K>, E>,
+}
+
+impl<'a, K: Key, N, E, Ty: EdgeType> Iterator for EdgeReferences<'a, K, N, E, Ty> {
+    type Item = EdgeReference<'a, K, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, weight) = self.edges.next()?;
+        let (source, target) = self.graph.get_edge_nodes(key).expect("edge keys are valid");
+        Some(EdgeReference {
+            key,
+            source,
+            target,
+            weight,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edges.size_hint()
+    }
+}
+
+impl<K: Key, N, E, Ty: EdgeType> GraphBase for SlotGraph<K, N, E, Ty> {
+    type NodeId = NodeKey<K>;
+    type EdgeId = EdgeKey<K>;
+}
+
+impl<K: Key, N, E, Ty: EdgeType> Data for SlotGraph<K, N, E, Ty> {
+    type NodeWeight = N;
+    type EdgeWeight = E;
+}
+
+impl<K: Key, N, E, Ty: EdgeType> NodeCount for SlotGraph<K, N, E, Ty> {
+    fn node_count(&self) -> usize {
+        self.node_len()
+    }
+}
+
+impl<K: Key, N, E, Ty: EdgeType> Visitable for SlotGraph<K, N, E, Ty> {
+    type Map = HashSet<NodeKey<K>>;
+
+    fn visit_map(&self) -> Self::Map {
+        HashSet::with_capacity(self.node_len())
+    }
+
+    fn reset_map(&self, map: &mut Self::Map) {
+        map.clear();
+    }
+}
+
+impl<'a, K: Key, N, E, Ty: EdgeType> IntoNeighbors for &'a SlotGraph<K, N, E, Ty> {
+    type Neighbors = Neighbors<'a, K>;
+
+    fn neighbors(self, a: NodeKey<K>) -> Self::Neighbors {
+        Neighbors {
+            adjacent: self.outgoing(a).iter(),
+        }
+    }
+}
+
+impl<'a, K: Key, N, E, Ty: EdgeType> IntoEdgeReferences for &'a SlotGraph<K, N, E, Ty> {
+    type EdgeRef = EdgeReference<'a, K, E>;
+    type EdgeReferences = EdgeReferences<'a, K, N, E, Ty>;
+
+    fn edge_references(self) -> Self::EdgeReferences {
+        EdgeReferences {
+            graph: self,
+            edges: self.edge_values().iter(),
+        }
+    }
+}
+
+impl<'a, K: Key, N, E, Ty: EdgeType> IntoEdges for &'a SlotGraph<K, N, E, Ty> {
+    type Edges = Edges<'a, K, E>;
+
+    fn edges(self, a: NodeKey<K>) -> Self::Edges {
+        Edges {
+            source: a,
+            adjacent: self.outgoing(a).iter(),
+            weights: self.edge_values(),
+        }
+    }
+}
+
+impl<'a, K: Key, N, E, Ty: EdgeType> IntoNodeIdentifiers for &'a SlotGraph<K, N, E, Ty> {
+    type NodeIdentifiers = secondary::Keys<'a, NodeKey<K>, N>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        self.node_values().keys()
+    }
+}
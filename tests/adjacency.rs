@@ -0,0 +1,197 @@
+use slotgraph::{SlotGraph, UnSlotGraph};
+
+#[test]
+fn neighbors_and_incident_edges() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node(());
+    let n2 = sg.insert_node(());
+    let n3 = sg.insert_node(());
+    let e1 = sg.insert_edge(n1, n2, ());
+    let e2 = sg.insert_edge(n3, n1, ());
+    let e3 = sg.insert_edge(n1, n1, ());
+    let e4 = sg.insert_edge(n1, n2, ());
+
+    assert_eq!(sg.out_edges(n1).collect::<Vec<_>>(), vec![e1, e3, e4]);
+    assert_eq!(sg.in_edges(n1).collect::<Vec<_>>(), vec![e2, e3]);
+    assert_eq!(sg.edges_of(n1).collect::<Vec<_>>(), vec![e1, e3, e4, e2]);
+    assert_eq!(sg.neighbors(n1).collect::<Vec<_>>(), vec![n2, n1, n2, n3]);
+    assert_eq!(sg.neighbors(n2).collect::<Vec<_>>(), vec![n1, n1]);
+
+    sg.remove_edge(e1);
+    assert_eq!(sg.out_edges(n1).collect::<Vec<_>>(), vec![e3, e4]);
+    assert_eq!(sg.in_edges(n2).collect::<Vec<_>>(), vec![e4]);
+
+    sg.remove_node(n3);
+    assert_eq!(sg.in_edges(n1).collect::<Vec<_>>(), vec![e3]);
+    assert_eq!(sg.neighbors(n3).count(), 0);
+}
+
+#[test]
+fn adjacency_follows_structural_changes() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(1);
+    let b = sg.insert_node(2);
+    let c = sg.insert_node(3);
+    let ab = sg.insert_edge(a, b, ());
+    let bc = sg.insert_edge(b, c, ());
+    let ca = sg.insert_edge(c, a, ());
+
+    sg.merge_nodes(a, b, |x, y| x + y);
+    assert_eq!(sg.out_edges(a).collect::<Vec<_>>(), vec![ab, bc]);
+    assert_eq!(sg.in_edges(a).collect::<Vec<_>>(), vec![ca, ab]);
+    assert_eq!(sg.predecessors(c).collect::<Vec<_>>(), vec![a]);
+    assert_eq!(sg.successors(c).collect::<Vec<_>>(), vec![a]);
+
+    sg.remove_edges_where(|k, _, _, _| k == ab);
+    assert_eq!(sg.out_degree(a), Some(1));
+    assert_eq!(sg.in_degree(a), Some(1));
+
+    let d = sg.insert_node(4);
+    sg.insert_edge(c, d, ());
+    let unreachable = sg.split_off_unreachable(&[d]);
+    assert_eq!(sg.node_len(), 1);
+    assert_eq!(sg.in_degree(d), Some(0));
+    assert_eq!(unreachable.out_edges(c).collect::<Vec<_>>(), vec![ca]);
+    assert_eq!(unreachable.in_edges(a).collect::<Vec<_>>(), vec![ca]);
+}
+
+#[test]
+#[should_panic]
+fn insert_edge_with_stale_node_panics() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node(());
+    let n2 = sg.insert_node(());
+    sg.remove_node(n2);
+    sg.insert_edge(n1, n2, ());
+}
+
+#[test]
+fn try_insert_edge_with_stale_node() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node(());
+    let n2 = sg.insert_node(());
+    sg.remove_node(n2);
+    assert_eq!(sg.try_insert_edge(n1, n2, "e"), Err("e"));
+}
+
+#[test]
+fn retain_edges() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let ab = sg.insert_edge(a, b, 1);
+    let ba = sg.insert_edge(b, a, 2);
+    let bb = sg.insert_edge(b, b, 3);
+
+    sg.retain_edges(|_, w| {
+        *w += 1;
+        *w != 3
+    });
+    assert_eq!(sg.edge_len(), 2);
+    assert_eq!(sg.get_edge(ab), Some(&2));
+    assert_eq!(sg.get_edge(ba), None);
+    assert_eq!(sg.out_edges(b).collect::<Vec<_>>(), vec![bb]);
+    assert_eq!(sg.in_edges(a).count(), 0);
+    assert_eq!(sg.node_len(), 2);
+}
+
+#[test]
+fn edges_between_parallel_edges() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let ab1 = sg.insert_edge(a, b, ());
+    let ba = sg.insert_edge(b, a, ());
+    sg.insert_edge(a, c, ());
+    let ab2 = sg.insert_edge(a, b, ());
+    let aa = sg.insert_edge(a, a, ());
+
+    assert_eq!(sg.edges_between(a, b).collect::<Vec<_>>(), vec![ab1, ab2]);
+    assert_eq!(sg.edges_between(b, a).collect::<Vec<_>>(), vec![ba]);
+    assert_eq!(sg.edges_between(b, c).count(), 0);
+    assert_eq!(sg.edges_between(a, a).collect::<Vec<_>>(), vec![aa]);
+    assert_eq!(
+        sg.edges_connecting(b, a).collect::<Vec<_>>(),
+        vec![ba, ab1, ab2]
+    );
+    assert_eq!(sg.edges_connecting(a, a).collect::<Vec<_>>(), vec![aa]);
+
+    let mut ug = UnSlotGraph::new_undirected();
+    let x = ug.insert_node(());
+    let y = ug.insert_node(());
+    let xy = ug.insert_edge(x, y, ());
+    let yx = ug.insert_edge(y, x, ());
+    assert_eq!(ug.edges_between(y, x).collect::<Vec<_>>(), vec![xy, yx]);
+    assert_eq!(ug.edges_connecting(x, y).collect::<Vec<_>>(), vec![xy, yx]);
+}
+
+#[test]
+fn find_edge() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let first = sg.insert_edge(a, b, ());
+    let second = sg.insert_edge(a, b, ());
+    assert_eq!(sg.find_edge(a, b), Some(first));
+    assert_eq!(sg.find_edge(b, a), None);
+    sg.remove_edge(first);
+    assert_eq!(sg.find_edge(a, b), Some(second));
+    sg.remove_node(b);
+    assert_eq!(sg.find_edge(a, b), None);
+}
+
+#[test]
+fn update_edge_upserts() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let (ab, old) = sg.update_edge(a, b, 1);
+    assert_eq!(old, None);
+    assert_eq!(sg.update_edge(a, b, 2), (ab, Some(1)));
+    assert_eq!(sg.get_edge(ab), Some(&2));
+    let (ba, old) = sg.update_edge(b, a, 3);
+    assert_ne!(ba, ab);
+    assert_eq!(old, None);
+    assert_eq!(sg.edge_len(), 2);
+
+    let mut ug = UnSlotGraph::new_undirected();
+    let x = ug.insert_node(());
+    let y = ug.insert_node(());
+    let (xy, _) = ug.update_edge(x, y, 'a');
+    assert_eq!(ug.update_edge(y, x, 'b'), (xy, Some('a')));
+    assert_eq!(ug.edge_len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "stale node key")]
+fn update_edge_with_stale_node_panics() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    sg.remove_node(b);
+    sg.update_edge(a, b, ());
+}
+
+#[test]
+fn contains_edge_between_and_is_neighbor() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(c, c, ());
+
+    assert!(sg.contains_edge_between(a, b));
+    assert!(!sg.contains_edge_between(b, a));
+    assert!(sg.is_neighbor(b, a));
+    assert!(!sg.is_neighbor(a, c));
+    assert!(sg.is_neighbor(c, c) && !sg.is_neighbor(a, a));
+
+    let mut ug = UnSlotGraph::new_undirected();
+    let x = ug.insert_node(());
+    let y = ug.insert_node(());
+    ug.insert_edge(x, y, ());
+    assert!(ug.contains_edge_between(y, x));
+    assert!(ug.is_neighbor(y, x));
+}
@@ -0,0 +1,105 @@
+use slotgraph::{algo::Color, SlotGraph, UnSlotGraph};
+
+#[test]
+fn is_bipartite_coloring() {
+    let mut sg = SlotGraph::new();
+    let nodes: Vec<_> = (0..6).map(|i| sg.insert_node(i)).collect();
+    for (from, to) in [(0, 1), (2, 1), (2, 3), (3, 0), (4, 5)] {
+        sg.insert_edge(nodes[from], nodes[to], ());
+    }
+
+    let colors = sg.is_bipartite().unwrap();
+    assert_eq!(colors.len(), 6);
+    assert_eq!(colors[nodes[0]], Color::Red);
+    assert_eq!(colors[nodes[4]], Color::Red);
+    for (_, from, to, _) in sg.iter_edges_full() {
+        assert_eq!(colors[from], colors[to].other());
+    }
+}
+
+#[test]
+fn is_bipartite_odd_cycle() {
+    let mut ug = UnSlotGraph::new_undirected();
+    let nodes: Vec<_> = (0..6).map(|i| ug.insert_node(i)).collect();
+    // a pentagon with a tail
+    for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (2, 5)] {
+        ug.insert_edge(nodes[from], nodes[to], ());
+    }
+
+    let cycle = ug.is_bipartite().unwrap_err();
+    assert_eq!(cycle.len(), 5);
+    assert!(!cycle.contains(&nodes[5]));
+    for i in 0..cycle.len() {
+        let next = cycle[(i + 1) % cycle.len()];
+        assert!(ug.neighbors(cycle[i]).any(|nk| nk == next));
+    }
+
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    sg.insert_edge(a, a, ());
+    assert_eq!(sg.is_bipartite().unwrap_err(), vec![a]);
+}
+
+#[test]
+fn maximum_matching_assignment() {
+    let mut sg = SlotGraph::new();
+    let tasks: Vec<_> = (0..4).map(|i| sg.insert_node(i)).collect();
+    let workers: Vec<_> = (0..4).map(|i| sg.insert_node(10 + i)).collect();
+    // tasks 2 and 3 compete for worker 2, and worker 3 can do nothing
+    for (t, w) in [(0, 0), (0, 1), (1, 0), (2, 1), (2, 2), (3, 2)] {
+        sg.insert_edge(tasks[t], workers[w], ());
+    }
+
+    let matching = sg.maximum_matching().unwrap();
+    assert_eq!(matching.len(), 3);
+    let mut matched = Vec::new();
+    for ek in matching.edges() {
+        let (from, to) = sg.get_edge_nodes(ek).unwrap();
+        assert_eq!(matching.mate(from), Some(to));
+        assert_eq!(matching.mate(to), Some(from));
+        matched.extend([from, to]);
+    }
+    matched.sort();
+    matched.dedup();
+    assert_eq!(matched.len(), 6);
+    let unmatched: Vec<_> = matching.unmatched().collect();
+    assert_eq!(unmatched.len(), 2);
+    assert!(unmatched.contains(&workers[3]));
+    assert_eq!(matching.mate(workers[3]), None);
+}
+
+#[test]
+fn maximum_matching_with_partition() {
+    let mut ug = UnSlotGraph::new_undirected();
+    let a = ug.insert_node(());
+    let b = ug.insert_node(());
+    let c = ug.insert_node(());
+    ug.insert_edge(a, b, ());
+    ug.insert_edge(b, c, ());
+    ug.insert_edge(c, a, ());
+    assert!(ug.maximum_matching().is_err());
+
+    // with a and b on the same side, only the edges to c can be matched
+    let colors = [(a, Color::Red), (b, Color::Red), (c, Color::Blue)]
+        .into_iter()
+        .collect();
+    let matching = ug.maximum_matching_with(&colors);
+    assert_eq!(matching.len(), 1);
+    assert!(matching.mate(c).is_some());
+}
+
+#[test]
+fn maximum_matching_long_augmenting_paths() {
+    // a path whose middle edges are found first, so every phase has to reroute along it
+    let mut ug = UnSlotGraph::new_undirected();
+    let nodes: Vec<_> = (0..2000).map(|i| ug.insert_node(i)).collect();
+    for i in (1..nodes.len() - 1).step_by(2) {
+        ug.insert_edge(nodes[i], nodes[i + 1], ());
+    }
+    for i in (0..nodes.len() - 1).step_by(2) {
+        ug.insert_edge(nodes[i], nodes[i + 1], ());
+    }
+    let matching = ug.maximum_matching().unwrap();
+    assert_eq!(matching.len(), 1000);
+    assert_eq!(matching.unmatched().count(), 0);
+}
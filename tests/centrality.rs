@@ -0,0 +1,202 @@
+use slotgraph::{SlotGraph, UnSlotGraph};
+
+#[test]
+fn closeness_star() {
+    let mut sg = SlotGraph::new();
+    let center = sg.insert_node(());
+    let leaves: Vec<_> = (0..5).map(|_| sg.insert_node(())).collect();
+    for &leaf in &leaves {
+        sg.insert_edge(center, leaf, ());
+        sg.insert_edge(leaf, center, ());
+    }
+
+    let closeness = sg.closeness_centrality();
+    assert_eq!(closeness[center], 1.0);
+    for &leaf in &leaves {
+        assert!(closeness[leaf] < closeness[center]);
+        // one neighbor at distance 1, four at distance 2
+        assert!((closeness[leaf] - 5.0 / 9.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn closeness_disconnected() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let isolated = sg.insert_node(());
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(b, c, ());
+
+    let closeness = sg.closeness_centrality();
+    // reaches 2 of 3 other nodes at a total distance of 3
+    assert!((closeness[a] - (2.0 / 3.0) * (2.0 / 3.0)).abs() < 1e-12);
+    assert!((closeness[b] - 1.0 / 3.0).abs() < 1e-12);
+    assert_eq!(closeness[c], 0.0);
+    assert_eq!(closeness[isolated], 0.0);
+}
+
+#[test]
+fn pagerank_cycle_and_sink() {
+    let mut sg = SlotGraph::new();
+    let nodes: Vec<_> = (0..3).map(|_| sg.insert_node(())).collect();
+    for i in 0..3 {
+        sg.insert_edge(nodes[i], nodes[(i + 1) % 3], ());
+    }
+    let rank = sg.pagerank(0.85, 1e-12, 100);
+    for &nk in &nodes {
+        assert!((rank[nk] - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    // a hub everyone links to, which links to a sink
+    let mut sg = SlotGraph::new();
+    let hub = sg.insert_node(());
+    let sink = sg.insert_node(());
+    let leaves: Vec<_> = (0..4).map(|_| sg.insert_node(())).collect();
+    for &leaf in &leaves {
+        sg.insert_edge(leaf, hub, ());
+    }
+    sg.insert_edge(hub, sink, ());
+
+    let rank = sg.pagerank(0.85, 1e-10, 1000);
+    let total: f64 = rank.values().sum();
+    assert!((total - 1.0).abs() < 1e-9);
+    assert!(rank[sink] > rank[hub]);
+    assert!(rank[hub] > rank[leaves[0]]);
+    assert!((rank[leaves[0]] - rank[leaves[3]]).abs() < 1e-12);
+
+    // without following links, every node is equally likely
+    let rank = sg.pagerank(0.0, 1e-10, 1000);
+    assert!((rank[hub] - 1.0 / 6.0).abs() < 1e-12);
+    assert!(SlotGraph::<_, (), ()>::new()
+        .pagerank(0.85, 1e-6, 10)
+        .is_empty());
+}
+
+#[test]
+fn betweenness_path_and_bridge() {
+    // two triangles joined by a bridge between c and d
+    let mut ug = UnSlotGraph::new_undirected();
+    let nodes: Vec<_> = (0..6).map(|_| ug.insert_node(())).collect();
+    let mut edges = Vec::new();
+    for (from, to) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)] {
+        edges.push(ug.insert_edge(nodes[from], nodes[to], ()));
+    }
+
+    let betweenness = ug.betweenness_centrality();
+    // every path between the triangles crosses the bridge
+    assert_eq!(betweenness.edges[edges[3]], 9.0);
+    assert_eq!(betweenness.nodes[nodes[2]], 6.0);
+    assert_eq!(betweenness.nodes[nodes[3]], 6.0);
+    assert_eq!(betweenness.nodes[nodes[0]], 0.0);
+    // a-b, plus a and b to the three nodes across the bridge
+    assert_eq!(betweenness.edges[edges[0]], 1.0);
+    assert_eq!(betweenness.edges[edges[1]], 4.0);
+}
+
+#[test]
+fn betweenness_directed_ties_and_weights() {
+    // a diamond, where the two paths from a to d split the credit
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let d = sg.insert_node(());
+    let ab = sg.insert_edge(a, b, 1);
+    let ac = sg.insert_edge(a, c, 1);
+    let bd = sg.insert_edge(b, d, 1);
+    let cd = sg.insert_edge(c, d, 3);
+
+    let betweenness = sg.betweenness_centrality();
+    assert_eq!(betweenness.nodes[b], 0.5);
+    assert_eq!(betweenness.nodes[c], 0.5);
+    assert_eq!(betweenness.edges[ab], 1.5);
+    assert_eq!(betweenness.edges[cd], 1.5);
+
+    // weighted, the path through c is longer
+    let betweenness = sg.betweenness_centrality_weighted(|_, &w| w);
+    assert_eq!(betweenness.nodes[b], 1.0);
+    assert_eq!(betweenness.nodes[c], 0.0);
+    assert_eq!(betweenness.edges[ac], 1.0);
+    assert_eq!(betweenness.edges[bd], 2.0);
+    assert_eq!(betweenness.edges[cd], 1.0);
+}
+
+#[test]
+fn degree_centrality_directed_and_undirected() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(a, c, ());
+    sg.insert_edge(c, c, ());
+
+    let out = sg.out_degree_centrality();
+    let inc = sg.in_degree_centrality();
+    let total = sg.degree_centrality();
+    assert_eq!((out[a], out[b], out[c]), (1.0, 0.0, 0.5));
+    assert_eq!((inc[a], inc[b], inc[c]), (0.0, 0.5, 1.0));
+    assert_eq!((total[a], total[b], total[c]), (1.0, 0.5, 1.5));
+
+    let mut ug = UnSlotGraph::new_undirected();
+    let a = ug.insert_node(());
+    let b = ug.insert_node(());
+    let c = ug.insert_node(());
+    ug.insert_edge(a, b, ());
+    ug.insert_edge(a, c, ());
+    ug.insert_edge(c, c, ());
+    let out = ug.out_degree_centrality();
+    let total = ug.degree_centrality();
+    assert_eq!((out[a], out[b], out[c]), (1.0, 0.5, 1.0));
+    assert_eq!(ug.in_degree_centrality()[c], 1.0);
+    assert_eq!((total[a], total[b], total[c]), (1.0, 0.5, 1.5));
+}
+
+#[test]
+fn eigenvector_centrality_star_and_path() {
+    let mut ug = UnSlotGraph::new_undirected();
+    let center = ug.insert_node(());
+    let leaves: Vec<_> = (0..4).map(|_| ug.insert_node(())).collect();
+    for &leaf in &leaves {
+        ug.insert_edge(center, leaf, ());
+    }
+    let centrality = ug.eigenvector_centrality(1e-12, 1000);
+    // the leading eigenvector of a star with four leaves is (2, 1, 1, 1, 1)
+    let norm = 8f64.sqrt();
+    assert!((centrality[center] - 2.0 / norm).abs() < 1e-9);
+    assert!((centrality[leaves[2]] - 1.0 / norm).abs() < 1e-9);
+
+    let mut ug = UnSlotGraph::new_undirected();
+    let nodes: Vec<_> = (0..3).map(|_| ug.insert_node(())).collect();
+    ug.insert_edge(nodes[0], nodes[1], ());
+    ug.insert_edge(nodes[1], nodes[2], ());
+    let centrality = ug.eigenvector_centrality(1e-12, 1000);
+    assert!((centrality[nodes[1]] - 0.5f64.sqrt()).abs() < 1e-9);
+    assert!((centrality[nodes[0]] - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn hits_citations() {
+    // two surveys citing the same three papers, one of which cites another
+    let mut sg = SlotGraph::new();
+    let surveys: Vec<_> = (0..2).map(|_| sg.insert_node(())).collect();
+    let papers: Vec<_> = (0..3).map(|_| sg.insert_node(())).collect();
+    for &survey in &surveys {
+        for &paper in &papers {
+            sg.insert_edge(survey, paper, ());
+        }
+    }
+    sg.insert_edge(papers[0], papers[1], ());
+
+    let hits = sg.hits(1e-12, 1000);
+    let hubs: f64 = hits.hubs.values().sum();
+    let authorities: f64 = hits.authorities.values().sum();
+    assert!((hubs - 1.0).abs() < 1e-9 && (authorities - 1.0).abs() < 1e-9);
+    assert!(hits.hubs[surveys[0]] > hits.hubs[papers[0]]);
+    assert!((hits.hubs[surveys[0]] - hits.hubs[surveys[1]]).abs() < 1e-12);
+    assert_eq!(hits.hubs[papers[2]], 0.0);
+    assert!(hits.authorities[papers[1]] > hits.authorities[papers[2]]);
+    assert_eq!(hits.authorities[surveys[0]], 0.0);
+}
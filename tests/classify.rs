@@ -0,0 +1,127 @@
+use slotgraph::algo::{GraphClass, GraphStats};
+use slotgraph::SlotGraph;
+
+fn diamond() -> SlotGraph<slotmap::DefaultKey, (), ()> {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let d = sg.insert_node(());
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(a, c, ());
+    sg.insert_edge(b, d, ());
+    sg.insert_edge(c, d, ());
+    sg
+}
+
+#[test]
+fn classify_simple_dag() {
+    let class = diamond().classify();
+    assert_eq!(
+        class,
+        GraphClass {
+            self_loops: false,
+            parallel_edges: false,
+            acyclic: true,
+        }
+    );
+    assert!(class.is_simple_dag());
+}
+
+#[test]
+fn classify_multigraph() {
+    let mut sg = diamond();
+    let (from, to) = sg.iter_edge_nodes().next().unwrap().1;
+    sg.insert_edge(from, to, ());
+    let class = sg.classify();
+    assert!(class.parallel_edges);
+    assert!(!class.self_loops);
+    assert!(class.acyclic);
+    assert!(!class.is_simple_dag());
+
+    // an edge in the opposite direction is not parallel, but does create a cycle
+    let mut sg = diamond();
+    sg.insert_edge(to, from, ());
+    let class = sg.classify();
+    assert!(!class.parallel_edges);
+    assert!(!class.acyclic);
+}
+
+#[test]
+fn classify_self_loop() {
+    let mut sg = diamond();
+    let n = sg.insert_node(());
+    sg.insert_edge(n, n, ());
+    let class = sg.classify();
+    assert!(class.self_loops);
+    assert!(!class.parallel_edges);
+    assert!(!class.acyclic);
+}
+
+#[test]
+fn classify_cyclic() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(b, c, ());
+    sg.insert_edge(c, a, ());
+    let class = sg.classify();
+    assert_eq!(
+        class,
+        GraphClass {
+            self_loops: false,
+            parallel_edges: false,
+            acyclic: false,
+        }
+    );
+}
+
+#[test]
+fn first_parallel_edge() {
+    let mut sg = diamond();
+    assert!(!sg.has_parallel_edges());
+    assert_eq!(sg.first_parallel_edge(), None);
+
+    let (from, to) = sg.iter_edge_nodes().next().unwrap().1;
+    sg.insert_edge(to, from, ());
+    assert_eq!(sg.first_parallel_edge(), None);
+
+    sg.insert_edge(from, to, ());
+    assert!(sg.has_parallel_edges());
+    assert_eq!(sg.first_parallel_edge(), Some((from, to)));
+}
+
+#[test]
+fn stats() {
+    let mut sg = diamond();
+    let (a, _) = sg.iter_edge_nodes().next().unwrap().1;
+    sg.insert_edge(a, a, ());
+    sg.insert_node(());
+    assert_eq!(
+        sg.stats(),
+        GraphStats {
+            node_count: 5,
+            edge_count: 5,
+            self_loops: 1,
+            max_out_degree: 3,
+            max_in_degree: 2,
+            is_acyclic: false,
+        }
+    );
+    assert_eq!(
+        diamond().stats(),
+        GraphStats {
+            node_count: 4,
+            edge_count: 4,
+            self_loops: 0,
+            max_out_degree: 2,
+            max_in_degree: 2,
+            is_acyclic: true,
+        }
+    );
+    let empty = SlotGraph::<slotmap::DefaultKey, (), ()>::new().stats();
+    assert_eq!(empty.max_out_degree, 0);
+    assert!(empty.is_acyclic);
+}
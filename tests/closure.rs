@@ -0,0 +1,60 @@
+use slotgraph::{algo::transitive_closure, SlotGraph};
+
+#[test]
+fn transitive_closure_matches_descendants() {
+    let mut sg = SlotGraph::new();
+    let nodes: Vec<_> = (0..150).map(|i| sg.insert_node(i)).collect();
+    // a chain with a cycle in the middle and a self-loop at the end, spanning several words per row
+    for pair in nodes.windows(2) {
+        sg.insert_edge(pair[0], pair[1], ());
+    }
+    sg.insert_edge(nodes[80], nodes[70], ());
+    sg.insert_edge(nodes[149], nodes[149], ());
+    let isolated = sg.insert_node(150);
+
+    let closure = transitive_closure(&sg);
+    for (a, _) in sg.iter_nodes() {
+        let descendants = sg.descendants(a);
+        for (b, _) in sg.iter_nodes() {
+            assert_eq!(closure.reaches(a, b), descendants.contains(&b));
+        }
+    }
+    assert!(closure.reaches(nodes[75], nodes[75]));
+    assert!(!closure.reaches(nodes[10], nodes[10]));
+    assert!(closure.reaches(nodes[149], nodes[149]));
+    assert_eq!(closure.reachable_from(nodes[140]).count(), 9);
+    assert_eq!(closure.reachable_from(isolated).count(), 0);
+    assert_eq!(closure.index(isolated), Some(150));
+}
+
+#[test]
+fn transitive_reduction_dag() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("b");
+    let c = sg.insert_node("c");
+    let d = sg.insert_node("d");
+    let ab = sg.insert_edge(a, b, ());
+    let bc = sg.insert_edge(b, c, ());
+    let ac = sg.insert_edge(a, c, ());
+    let cd = sg.insert_edge(c, d, ());
+    let ad = sg.insert_edge(a, d, ());
+    let bc2 = sg.insert_edge(b, c, ());
+
+    let mut redundant = sg.redundant_edges().unwrap();
+    redundant.sort();
+    let mut expected = vec![ac, ad, bc2];
+    expected.sort();
+    assert_eq!(redundant, expected);
+
+    assert_eq!(sg.transitive_reduction().unwrap().len(), 3);
+    let mut remaining: Vec<_> = sg.iter_edges().map(|(ek, _)| ek).collect();
+    remaining.sort();
+    let mut expected = vec![ab, bc, cd];
+    expected.sort();
+    assert_eq!(remaining, expected);
+
+    sg.insert_edge(d, a, ());
+    assert!(sg.transitive_reduction().is_err());
+    assert_eq!(sg.edge_len(), 4);
+}
@@ -0,0 +1,53 @@
+use slotgraph::{SlotGraph, UnSlotGraph};
+
+#[test]
+fn louvain_two_cliques() {
+    // two four-cliques joined by a single edge
+    let mut ug = UnSlotGraph::new_undirected();
+    let nodes: Vec<_> = (0..8).map(|i| ug.insert_node(i)).collect();
+    for clique in [&nodes[..4], &nodes[4..]] {
+        for i in 0..4 {
+            for j in i + 1..4 {
+                ug.insert_edge(clique[i], clique[j], ());
+            }
+        }
+    }
+    ug.insert_edge(nodes[3], nodes[4], ());
+
+    let communities = ug.louvain_communities();
+    assert_eq!(communities.count, 2);
+    for i in 0..4 {
+        assert_eq!(communities.ids[nodes[i]], 0);
+        assert_eq!(communities.ids[nodes[4 + i]], 1);
+    }
+    // 12 of 13 edges inside, and two communities with half the degree each
+    let expected = 12.0 / 13.0 - 0.5;
+    assert!((communities.modularity - expected).abs() < 1e-12);
+}
+
+#[test]
+fn louvain_weighted_and_isolated() {
+    // a directed ring whose heavy edges pair up the nodes
+    let mut sg = SlotGraph::new();
+    let nodes: Vec<_> = (0..6).map(|i| sg.insert_node(i)).collect();
+    for i in 0..6 {
+        let weight = if i % 2 == 0 { 10.0 } else { 1.0 };
+        sg.insert_edge(nodes[i], nodes[(i + 1) % 6], weight);
+    }
+    let isolated = sg.insert_node(6);
+
+    let communities = sg.louvain_communities_weighted(|_, &w| w);
+    assert_eq!(communities.count, 4);
+    for i in (0..6).step_by(2) {
+        assert_eq!(communities.ids[nodes[i]], communities.ids[nodes[i + 1]]);
+        assert_ne!(
+            communities.ids[nodes[i]],
+            communities.ids[nodes[(i + 2) % 6]]
+        );
+    }
+    assert_eq!(communities.ids[isolated], 3);
+    assert!(communities.modularity > 0.5);
+
+    let empty = SlotGraph::<_, (), ()>::new().louvain_communities();
+    assert_eq!((empty.count, empty.modularity), (0, 0.0));
+}
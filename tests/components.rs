@@ -0,0 +1,44 @@
+use slotgraph::SlotGraph;
+
+#[test]
+fn component_sizes() {
+    let mut sg = SlotGraph::new();
+    for _ in 0..2 {
+        let a = sg.insert_node(());
+        let b = sg.insert_node(());
+        let c = sg.insert_node(());
+        sg.insert_edge(a, b, ());
+        sg.insert_edge(b, c, ());
+        sg.insert_edge(c, a, ());
+    }
+    sg.insert_node(());
+    assert_eq!(sg.component_sizes(), vec![3, 3, 1]);
+    assert_eq!(
+        SlotGraph::<_, (), ()>::new().component_sizes(),
+        Vec::<usize>::new()
+    );
+}
+
+#[test]
+fn connected_components_labels() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let d = sg.insert_node(());
+    let e = sg.insert_node(());
+    sg.insert_edge(b, a, ());
+    sg.insert_edge(c, d, ());
+    sg.insert_edge(d, d, ());
+
+    let (ids, count) = sg.connected_components();
+    assert_eq!(count, 3);
+    assert_eq!(ids.len(), 5);
+    assert_eq!((ids[a], ids[b]), (0, 0));
+    assert_eq!((ids[c], ids[d]), (1, 1));
+    assert_eq!(ids[e], 2);
+
+    let (ids, count) = SlotGraph::<_, (), ()>::new().connected_components();
+    assert!(ids.is_empty());
+    assert_eq!(count, 0);
+}
@@ -0,0 +1,45 @@
+use std::collections::HashSet;
+
+use slotgraph::{SlotGraph, UnSlotGraph};
+
+#[test]
+fn articulation_points_and_bridges() {
+    // two triangles joined through a path c - d - e, with a pendant f on e
+    let mut ug = UnSlotGraph::new_undirected();
+    let nodes: Vec<_> = (0..8).map(|i| ug.insert_node(i)).collect();
+    let [a, b, c, d, e, f, g, h] = nodes[..] else {
+        unreachable!()
+    };
+    ug.insert_edge(a, b, ());
+    ug.insert_edge(b, c, ());
+    ug.insert_edge(c, a, ());
+    let cd = ug.insert_edge(c, d, ());
+    let de = ug.insert_edge(d, e, ());
+    ug.insert_edge(e, g, ());
+    ug.insert_edge(g, h, ());
+    ug.insert_edge(h, e, ());
+    let ef = ug.insert_edge(e, f, ());
+    ug.insert_edge(a, a, ());
+
+    let points: HashSet<_> = ug.articulation_points().into_iter().collect();
+    assert_eq!(points, HashSet::from([c, d, e]));
+    let bridges: HashSet<_> = ug.bridges().into_iter().collect();
+    assert_eq!(bridges, HashSet::from([cd, de, ef]));
+
+    // a parallel edge is not a bridge
+    ug.insert_edge(e, d, ());
+    let bridges: HashSet<_> = ug.bridges().into_iter().collect();
+    assert_eq!(bridges, HashSet::from([cd, ef]));
+}
+
+#[test]
+fn articulation_points_ignore_direction() {
+    let mut sg = SlotGraph::new();
+    let hub = sg.insert_node(());
+    let leaves: Vec<_> = (0..3).map(|_| sg.insert_node(())).collect();
+    sg.insert_edge(leaves[0], hub, ());
+    sg.insert_edge(hub, leaves[1], ());
+    sg.insert_edge(leaves[2], hub, ());
+    assert_eq!(sg.articulation_points(), vec![hub]);
+    assert_eq!(sg.bridges().len(), 3);
+}
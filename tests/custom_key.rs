@@ -0,0 +1,117 @@
+use slotgraph::{
+    slotgraph::{EdgeKey, NodeKey},
+    SlotGraph,
+};
+use slotmap::{new_key_type, Key, SecondaryMap};
+
+new_key_type! {
+    struct MyKey;
+}
+
+#[test]
+fn custom_key_round_trip() {
+    let mut sg = SlotGraph::<MyKey, _, _>::with_key();
+    let n1: NodeKey<MyKey> = sg.insert_node("n1");
+    let n2 = sg.insert_node("n2");
+    let e1: EdgeKey<MyKey> = sg.insert_edge(n1, n2, "e1");
+    let e2 = sg.insert_edge(n2, n2, "e2");
+
+    assert_eq!(sg.get_node(n1), Some(&"n1"));
+    assert_eq!(sg.get_node(n2), Some(&"n2"));
+    assert_eq!(sg.get_edge(e1), Some(&"e1"));
+    assert_eq!(sg.get_edge_nodes(e1), Some((n1, n2)));
+    assert_eq!(sg.get_edge_nodes(e2), Some((n2, n2)));
+
+    // keys survive a round trip through their key data
+    let n1_copy = NodeKey::<MyKey>::from(n1.data());
+    let e1_copy = EdgeKey::<MyKey>::from(e1.data());
+    assert_eq!(n1_copy, n1);
+    assert_eq!(e1_copy, e1);
+    assert_eq!(sg.get_node(n1_copy), Some(&"n1"));
+
+    // node keys wrapping a custom key can key secondary maps
+    let mut labels = SecondaryMap::new();
+    labels.insert(n1, 1);
+    assert_eq!(labels.get(n1_copy), Some(&1));
+
+    assert_eq!(sg.remove_node(n1), Some("n1"));
+    assert_eq!(sg.get_node(n1_copy), None);
+    assert!(!n1.is_null());
+    assert!(NodeKey::<MyKey>::null().is_null());
+}
+
+#[test]
+fn key_conversions() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node("n1");
+    let n2 = sg.insert_node("n2");
+    let e1 = sg.insert_edge(n1, n2, "e1");
+
+    let n1_ffi = NodeKey::from_ffi(n1.as_ffi());
+    let e1_ffi = EdgeKey::from_ffi(e1.as_ffi());
+    assert_eq!(n1_ffi, n1);
+    assert_eq!(e1_ffi, e1);
+    assert_eq!(sg.get_node(n1_ffi), Some(&"n1"));
+    assert_eq!(sg.get_edge(e1_ffi), Some(&"e1"));
+
+    // raw keys can be used with maps keyed on the underlying key type
+    let mut raw: SecondaryMap<slotmap::DefaultKey, &str> = SecondaryMap::new();
+    raw.insert(n2.into_inner(), "raw");
+    let n2_inner = NodeKey::from_inner(n2.into_inner());
+    assert_eq!(n2_inner, n2);
+    assert_eq!(sg.get_node(n2_inner), Some(&"n2"));
+    assert_eq!(raw.get(n2_inner.into_inner()), Some(&"raw"));
+    assert_eq!(
+        sg.get_edge(EdgeKey::from_inner(e1.into_inner())),
+        Some(&"e1")
+    );
+
+    sg.remove_node(n1);
+    assert_eq!(sg.get_node(NodeKey::from_ffi(n1.as_ffi())), None);
+}
+
+#[test]
+fn remap_keys() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node("n1");
+    let n2 = sg.insert_node("n2");
+    let n3 = sg.insert_node("n3");
+    let e1 = sg.insert_edge(n1, n2, "e1");
+    let e2 = sg.insert_edge(n2, n3, "e2");
+    let e3 = sg.insert_edge(n3, n3, "e3");
+    let edges: Vec<_> = sg.iter_edge_nodes().collect();
+
+    let (remapped, node_map, edge_map) = sg.remap_keys::<MyKey>();
+    assert_eq!(remapped.node_len(), 3);
+    assert_eq!(remapped.edge_len(), 3);
+    assert_eq!(remapped.get_node(node_map[n2]), Some(&"n2"));
+    assert_eq!(remapped.get_edge(edge_map[e1]), Some(&"e1"));
+    assert_eq!(remapped.get_edge(edge_map[e2]), Some(&"e2"));
+    assert_eq!(remapped.get_edge(edge_map[e3]), Some(&"e3"));
+    for (ek, (from, to)) in edges {
+        assert_eq!(
+            remapped.get_edge_nodes(edge_map[ek]),
+            Some((node_map[from], node_map[to]))
+        );
+    }
+}
+
+#[test]
+fn with_capacity_and_key() {
+    let mut sg: SlotGraph<MyKey, _, _> = SlotGraph::with_capacity_and_key(4, 4);
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("b");
+    let e = sg.insert_edge(a, b, "e");
+    assert_eq!(sg.get_edge_nodes(e), Some((a, b)));
+}
+
+#[test]
+fn default_and_clone() {
+    let mut sg: SlotGraph<MyKey, String, u32> = SlotGraph::default();
+    let a = sg.insert_node("a".to_string());
+    sg.insert_edge(a, a, 7);
+    let copy = sg.clone();
+    assert_eq!(copy.get_node(a), Some(&"a".to_string()));
+    assert_eq!(copy.edge_len(), 1);
+    assert!(format!("{copy:?}").contains("\"a\""));
+}
@@ -0,0 +1,67 @@
+use slotgraph::SlotGraph;
+
+#[test]
+fn feedback_edge_set_single_cycle() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let d = sg.insert_node(());
+    sg.insert_edge(a, b, "ab");
+    sg.insert_edge(b, c, "bc");
+    sg.insert_edge(c, a, "ca");
+    sg.insert_edge(c, d, "cd");
+    sg.insert_edge(a, d, "ad");
+    assert!(!sg.classify().acyclic);
+
+    let feedback = sg.feedback_edge_set();
+    assert_eq!(feedback.len(), 1);
+    let removed = sg.break_cycles();
+    assert_eq!(removed.len(), 1);
+    assert_eq!(removed[0].0, feedback[0]);
+    assert!(["ab", "bc", "ca"].contains(&removed[0].1));
+    assert!(sg.classify().acyclic);
+    assert_eq!(sg.edge_len(), 4);
+    assert!(sg.feedback_edge_set().is_empty());
+}
+
+#[test]
+fn feedback_edge_set_self_loops() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let aa = sg.insert_edge(a, a, ());
+    sg.insert_edge(a, b, ());
+    let bb = sg.insert_edge(b, b, ());
+
+    let mut feedback = sg.feedback_edge_set();
+    feedback.sort();
+    let mut expected = vec![aa, bb];
+    expected.sort();
+    assert_eq!(feedback, expected);
+    sg.break_cycles();
+    assert!(sg.classify().acyclic);
+}
+
+#[test]
+fn find_cycle_path() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let d = sg.insert_node(());
+    sg.insert_edge(a, b, ());
+    let bc = sg.insert_edge(b, c, ());
+    let cd = sg.insert_edge(c, d, ());
+    let db = sg.insert_edge(d, b, ());
+    assert!(sg.has_cycle());
+    assert!(!sg.is_dag());
+    assert_eq!(sg.find_cycle(), Some(vec![(b, bc), (c, cd), (d, db)]));
+
+    sg.remove_edge(db);
+    assert!(sg.is_dag());
+    assert_eq!(sg.find_cycle(), None);
+
+    let dd = sg.insert_edge(d, d, ());
+    assert_eq!(sg.find_cycle(), Some(vec![(d, dd)]));
+}
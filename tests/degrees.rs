@@ -0,0 +1,53 @@
+use std::collections::HashSet;
+
+use slotgraph::SlotGraph;
+
+#[test]
+fn degree_maps() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node(());
+    let n2 = sg.insert_node(());
+    let n3 = sg.insert_node(());
+    let isolated = sg.insert_node(());
+    sg.insert_edge(n1, n2, ());
+    sg.insert_edge(n1, n3, ());
+    sg.insert_edge(n2, n3, ());
+    sg.insert_edge(n3, n3, ());
+
+    let out_degrees = sg.out_degrees();
+    let in_degrees = sg.in_degrees();
+    assert_eq!(out_degrees.values().sum::<usize>(), sg.edge_len());
+    assert_eq!(in_degrees.values().sum::<usize>(), sg.edge_len());
+    assert_eq!(out_degrees.len(), sg.node_len());
+    for (k, _) in sg.iter_nodes() {
+        assert_eq!(sg.out_degree(k), Some(out_degrees[k]));
+        assert_eq!(sg.in_degree(k), Some(in_degrees[k]));
+    }
+    assert_eq!(out_degrees[isolated], 0);
+    assert_eq!(in_degrees[isolated], 0);
+    assert_eq!(out_degrees[n1], 2);
+    assert_eq!(in_degrees[n3], 3);
+
+    sg.remove_node(isolated);
+    assert_eq!(sg.out_degree(isolated), None);
+}
+
+#[test]
+fn sources_and_sinks() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let d = sg.insert_node(());
+    let e = sg.insert_node(());
+    let isolated = sg.insert_node(());
+    sg.insert_edge(a, c, ());
+    sg.insert_edge(b, c, ());
+    sg.insert_edge(c, d, ());
+    sg.insert_edge(c, e, ());
+
+    let sources: HashSet<_> = sg.sources().collect();
+    let sinks: HashSet<_> = sg.sinks().collect();
+    assert_eq!(sources, HashSet::from([a, b, isolated]));
+    assert_eq!(sinks, HashSet::from([d, e, isolated]));
+}
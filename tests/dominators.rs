@@ -0,0 +1,122 @@
+use std::collections::HashSet;
+
+use slotgraph::{
+    algo::{dominance_frontiers, dominators, post_dominators},
+    SlotGraph,
+};
+
+#[test]
+fn dominators_of_cfg() {
+    // entry -> a -> (b | c) -> d -> a (loop) and d -> exit
+    let mut sg = SlotGraph::new();
+    let entry = sg.insert_node("entry");
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("b");
+    let c = sg.insert_node("c");
+    let d = sg.insert_node("d");
+    let exit = sg.insert_node("exit");
+    let dead = sg.insert_node("dead");
+    sg.insert_edge(entry, a, ());
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(a, c, ());
+    sg.insert_edge(b, d, ());
+    sg.insert_edge(c, d, ());
+    sg.insert_edge(d, a, ());
+    sg.insert_edge(d, exit, ());
+    sg.insert_edge(entry, exit, ());
+    sg.insert_edge(dead, d, ());
+
+    let idom = dominators(&sg, entry);
+    assert_eq!(idom.len(), 6);
+    assert_eq!(idom[entry], entry);
+    assert_eq!(idom[a], entry);
+    assert_eq!(idom[b], a);
+    assert_eq!(idom[c], a);
+    assert_eq!(idom[d], a);
+    assert_eq!(idom[exit], entry);
+    assert!(!idom.contains_key(dead));
+
+    sg.remove_node(entry);
+    assert!(dominators(&sg, entry).is_empty());
+}
+
+#[test]
+fn dominators_irreducible() {
+    // two entries into the cycle between x and y
+    let mut sg = SlotGraph::new();
+    let entry = sg.insert_node(());
+    let x = sg.insert_node(());
+    let y = sg.insert_node(());
+    let z = sg.insert_node(());
+    sg.insert_edge(entry, x, ());
+    sg.insert_edge(entry, y, ());
+    sg.insert_edge(x, y, ());
+    sg.insert_edge(y, x, ());
+    sg.insert_edge(y, z, ());
+
+    let idom = dominators(&sg, entry);
+    assert_eq!(idom[x], entry);
+    assert_eq!(idom[y], entry);
+    assert_eq!(idom[z], y);
+}
+
+#[test]
+fn dominance_frontiers_of_cfg() {
+    // entry -> a -> (b | c) -> d -> a (loop) and d -> exit, with a -> entry back edge
+    let mut sg = SlotGraph::new();
+    let entry = sg.insert_node(());
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let d = sg.insert_node(());
+    let exit = sg.insert_node(());
+    sg.insert_edge(entry, a, ());
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(a, c, ());
+    sg.insert_edge(b, d, ());
+    sg.insert_edge(c, d, ());
+    sg.insert_edge(d, a, ());
+    sg.insert_edge(d, exit, ());
+    sg.insert_edge(exit, entry, ());
+
+    let df = dominance_frontiers(&sg, entry);
+    assert_eq!(df.len(), 6);
+    assert_eq!(df[b], HashSet::from([d]));
+    assert_eq!(df[c], HashSet::from([d]));
+    assert_eq!(df[d], HashSet::from([a, entry]));
+    assert_eq!(df[a], HashSet::from([a, entry]));
+    assert_eq!(df[exit], HashSet::from([entry]));
+    assert_eq!(df[entry], HashSet::from([entry]));
+}
+
+#[test]
+fn post_dominators_through_view() {
+    // entry -> (a | b) -> c -> exit, with a shortcut from b to exit
+    let mut sg = SlotGraph::new();
+    let entry = sg.insert_node("entry");
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("b");
+    let c = sg.insert_node("c");
+    let exit = sg.insert_node("exit");
+    let stuck = sg.insert_node("stuck");
+    sg.insert_edge(entry, a, ());
+    sg.insert_edge(entry, b, ());
+    sg.insert_edge(a, c, ());
+    sg.insert_edge(b, c, ());
+    sg.insert_edge(c, exit, ());
+    sg.insert_edge(b, exit, ());
+    sg.insert_edge(a, stuck, ());
+
+    let ipdom = post_dominators(&sg, exit);
+    assert_eq!(ipdom.len(), 5);
+    assert_eq!(ipdom[exit], exit);
+    assert_eq!(ipdom[c], exit);
+    assert_eq!(ipdom[a], c);
+    assert_eq!(ipdom[b], exit);
+    assert_eq!(ipdom[entry], exit);
+    assert!(!ipdom.contains_key(stuck));
+
+    let view = sg.transpose_view();
+    assert_eq!(dominators(&view, exit), ipdom);
+    assert_eq!(dominance_frontiers(&view, exit)[a], HashSet::from([entry]));
+}
@@ -0,0 +1,140 @@
+use std::io::ErrorKind;
+
+use slotgraph::{Dot, SlotGraph, UnSlotGraph};
+
+#[test]
+fn directed_dot() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("say \"b\"");
+    sg.insert_edge(a, b, 1);
+    sg.insert_edge(b, b, 2);
+
+    let dot = sg.to_dot(|_, n| n.to_string(), |_, e| e.to_string());
+    assert_eq!(
+        dot,
+        "digraph {\n    0 [label=\"a\"]\n    1 [label=\"say \\\"b\\\"\"]\n    0 -> 1 [label=\"1\"]\n    1 -> 1 [label=\"2\"]\n}\n"
+    );
+}
+
+#[test]
+fn undirected_dot_with_attributes() {
+    let mut ug = UnSlotGraph::new_undirected();
+    let a = ug.insert_node("a");
+    let b = ug.insert_node("b");
+    ug.insert_edge(a, b, 5);
+
+    let dot = Dot::new(&ug, |_, n| n.to_string(), |_, _| String::new())
+        .with_node_attributes(move |k, _| {
+            if k == a {
+                "shape=box".to_string()
+            } else {
+                String::new()
+            }
+        })
+        .with_edge_attributes(|_, &w| format!("weight={w}"))
+        .to_string();
+    assert_eq!(
+        dot,
+        "graph {\n    0 [label=\"a\", shape=box]\n    1 [label=\"b\"]\n    0 -- 1 [label=\"\", weight=5]\n}\n"
+    );
+}
+
+#[test]
+fn from_dot() {
+    let input = r#"
+        /* dependencies */
+        strict digraph deps {
+            rankdir = LR; node [shape=box]
+            a [label="first \"node\""];
+            a -> b -> c [label=uses, color=red]
+            // a comment
+            c -> a
+            # preprocessor output
+            "d e"; <x<b>y</b>>
+        }
+    "#;
+    let (sg, ids) = SlotGraph::from_dot(input).unwrap();
+    assert_eq!(sg.node_len(), 5);
+    assert_eq!(sg.edge_len(), 3);
+    assert_eq!(sg.get_node(ids["a"]).unwrap(), "first \"node\"");
+    assert_eq!(sg.get_node(ids["d e"]).unwrap(), "d e");
+    assert!(ids.contains_key("x<b>y</b>"));
+    let labels: Vec<_> = sg
+        .out_edges(ids["a"])
+        .chain(sg.out_edges(ids["b"]))
+        .chain(sg.out_edges(ids["c"]))
+        .map(|ek| sg.get_edge(ek).unwrap().as_str())
+        .collect();
+    assert_eq!(labels, vec!["uses", "uses", ""]);
+    assert_eq!(sg.successors(ids["c"]).collect::<Vec<_>>(), vec![ids["a"]]);
+}
+
+#[test]
+fn from_dot_hash() {
+    let input = "# 1 \"deps.gv\"\ndigraph { a [color=\"#ff0000\", label=x]; a -> b }\n";
+    let (sg, ids) = SlotGraph::from_dot(input).unwrap();
+    assert_eq!(sg.get_node(ids["a"]).unwrap(), "x");
+    assert_eq!(sg.edge_len(), 1);
+
+    // `#` only starts a comment at the start of a line
+    let err = SlotGraph::from_dot("digraph { a -> b # c }").err().unwrap();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn from_dot_strict() {
+    let input = "strict graph { a -- b [label=x]; b -- a; a -- b [label=y]; a -- a; a -- a }";
+    let (sg, ids) = UnSlotGraph::from_dot_undirected(input).unwrap();
+    assert_eq!(sg.edge_len(), 2);
+    let ek = sg.find_edge(ids["a"], ids["b"]).unwrap();
+    assert_eq!(sg.get_edge(ek).unwrap(), "y");
+
+    let (sg, _) = SlotGraph::from_dot("digraph { a -> b; a -> b; b -> a }").unwrap();
+    assert_eq!(sg.edge_len(), 3);
+    let (sg, _) = SlotGraph::from_dot("strict digraph { a -> b; a -> b; b -> a }").unwrap();
+    assert_eq!(sg.edge_len(), 2);
+}
+
+#[test]
+fn dot_round_trip() {
+    let mut ug = UnSlotGraph::new_undirected();
+    let a = ug.insert_node("line\nbreak");
+    let b = ug.insert_node("back\\slash");
+    ug.insert_edge(a, b, "e");
+
+    let (sg, ids) =
+        UnSlotGraph::from_dot_undirected(&ug.to_dot(|_, n| n.to_string(), |_, e| e.to_string()))
+            .unwrap();
+    assert_eq!(sg.get_node(ids["0"]).unwrap(), "line\nbreak");
+    assert_eq!(sg.get_node(ids["1"]).unwrap(), "back\\slash");
+    assert_eq!(sg.successors(ids["0"]).collect::<Vec<_>>(), vec![ids["1"]]);
+    assert_eq!(sg.successors(ids["1"]).collect::<Vec<_>>(), vec![ids["0"]]);
+
+    let err = SlotGraph::from_dot(&ug.to_dot(|_, n| n.to_string(), |_, e| e.to_string()))
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn from_dot_invalid() {
+    for input in [
+        "digraph { a -- b }",
+        "graph { a -> b }",
+        "digraph { a -> }",
+        "digraph { subgraph s { a } }",
+        "digraph { a:n -> b }",
+        "digraph { a [label] }",
+        "digraph { \"a }",
+        "digraph { a }  b",
+        "tree { a }",
+    ] {
+        let err = SlotGraph::from_dot(input).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData, "{input}");
+    }
+    for input in ["graph { a -> b }", "digraph { a -> b }"] {
+        let err = UnSlotGraph::from_dot_undirected(input).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData, "{input}");
+    }
+}
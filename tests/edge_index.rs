@@ -0,0 +1,108 @@
+use slotgraph::{EdgeIndexedSlotGraph, SlotGraph};
+use slotmap::DefaultKey;
+
+/// A small linear congruential generator, so the test is deterministic without extra dependencies.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((self.0 >> 33) % bound as u64) as usize
+    }
+}
+
+fn brute_force(sg: &SlotGraph<DefaultKey, usize, usize>) -> impl Fn(usize, usize) -> bool + '_ {
+    let keys: Vec<_> = sg.iter_nodes().map(|(k, _)| k).collect();
+    move |a, b| {
+        sg.iter_edge_nodes()
+            .any(|(_, nodes)| nodes == (keys[a], keys[b]))
+    }
+}
+
+#[test]
+fn contains_edge_between_matches_scan() {
+    let mut eg = EdgeIndexedSlotGraph::<DefaultKey, usize, usize>::default();
+    let nodes: Vec<_> = (0..30).map(|i| eg.insert_node(i)).collect();
+    let mut rng = Lcg(42);
+    let mut edges = Vec::new();
+    for i in 0..600 {
+        let from = nodes[rng.next(nodes.len())];
+        let to = nodes[rng.next(nodes.len())];
+        edges.push(eg.insert_edge(from, to, i));
+    }
+    for _ in 0..300 {
+        let ek = edges.swap_remove(rng.next(edges.len()));
+        assert!(eg.remove_edge(ek).is_some());
+    }
+
+    let exists = brute_force(eg.graph());
+    for (a, &from) in nodes.iter().enumerate() {
+        for (b, &to) in nodes.iter().enumerate() {
+            assert_eq!(eg.contains_edge_between(from, to), exists(a, b));
+            for &ek in eg.edges_between(from, to) {
+                assert_eq!(eg.graph().get_edge_nodes(ek), Some((from, to)));
+            }
+            // the index agrees with the adjacency lists, in the same order
+            let scanned: Vec<_> = eg.graph().edges_between(from, to).collect();
+            assert_eq!(scanned, eg.edges_between(from, to));
+            assert_eq!(eg.find_edge(from, to), eg.graph().find_edge(from, to));
+            assert_eq!(eg.graph().contains_edge_between(from, to), exists(a, b));
+            assert_eq!(eg.is_neighbor(from, to), exists(a, b) || exists(b, a));
+            assert_eq!(eg.graph().is_neighbor(from, to), eg.is_neighbor(from, to));
+        }
+    }
+    let indexed: usize = nodes
+        .iter()
+        .flat_map(|&from| nodes.iter().map(move |&to| (from, to)))
+        .map(|(from, to)| eg.edges_between(from, to).len())
+        .sum();
+    assert_eq!(indexed, eg.graph().edge_len());
+}
+
+#[test]
+fn edge_index_survives_delegated_mutation() {
+    // deduplicating edges while building, counting repeats in the edge values
+    let mut eg = EdgeIndexedSlotGraph::<DefaultKey, &str, usize>::default();
+    eg.reserve_nodes(3);
+    eg.reserve_edges(4);
+    let a = eg.insert_node("a");
+    let b = eg.insert_node("b");
+    let c = eg.try_insert_node("c").unwrap();
+    for (from, to) in [(a, b), (b, c), (a, b), (c, a), (a, b)] {
+        match eg.find_edge(from, to) {
+            Some(ek) => *eg.get_edge_mut(ek).unwrap() += 1,
+            None => {
+                eg.try_insert_edge(from, to, 1).unwrap();
+            }
+        }
+    }
+    // reads go through to the wrapped graph
+    assert_eq!(eg.edge_len(), 3);
+    assert_eq!(eg.get_edge(eg.find_edge(a, b).unwrap()), Some(&3));
+    *eg.get_node_mut(a).unwrap() = "A";
+    assert_eq!(eg.get_node(a), Some(&"A"));
+
+    let (ek, old) = eg.update_edge(b, a, 7);
+    assert_eq!((old, eg.find_edge(b, a)), (None, Some(ek)));
+    assert_eq!(eg.update_edge(b, a, 8), (ek, Some(7)));
+
+    eg.retain_edges(|_, &mut count| count > 1);
+    assert_eq!(eg.edge_len(), 2);
+    assert!(!eg.contains_edge_between(b, c));
+    assert!(eg.contains_edge_between(a, b) && eg.contains_edge_between(b, a));
+
+    eg.retain_nodes(|nk, _| nk != b);
+    assert_eq!(eg.edge_len(), 0);
+    assert!(eg.edges_between(a, b).is_empty());
+
+    eg.insert_edge(a, c, 1);
+    eg.clear_edges();
+    assert!(!eg.contains_edge_between(a, c));
+    eg.insert_edge(c, c, 1);
+    eg.clear();
+    assert_eq!(eg.node_len(), 0);
+    assert!(eg.edges_between(c, c).is_empty());
+}
@@ -0,0 +1,26 @@
+use std::io::ErrorKind;
+
+use slotgraph::SlotGraph;
+
+#[test]
+fn from_edge_reader() {
+    let input = b"# a small graph\n\n1 2\n2 3\n   \n  # indented comment\n3\t1\n1 2\n10 10\n";
+    let (sg, ids) = SlotGraph::from_edge_reader(&input[..]).unwrap();
+    assert_eq!(sg.node_len(), 4);
+    assert_eq!(sg.edge_len(), 5);
+    assert_eq!(ids.len(), 4);
+    assert!(sg
+        .iter_edge_nodes()
+        .any(|(_, nodes)| nodes == (ids[&3], ids[&1])));
+    assert!(sg
+        .iter_edge_nodes()
+        .any(|(_, nodes)| nodes == (ids[&10], ids[&10])));
+}
+
+#[test]
+fn from_edge_reader_invalid() {
+    for input in ["1 2\n3\n", "1 x\n", "1 2 3\n"] {
+        let err = SlotGraph::from_edge_reader(input.as_bytes()).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
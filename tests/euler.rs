@@ -0,0 +1,74 @@
+use slotgraph::{algo::eulerian_path, SlotGraph, UnSlotGraph};
+
+#[test]
+fn directed_eulerian_path() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let d = sg.insert_node(());
+    // a circuit a-b-c-a with a detour c-c and a tail b-d
+    sg.insert_edge(b, c, ());
+    sg.insert_edge(c, a, ());
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(c, c, ());
+    sg.insert_edge(b, d, ());
+
+    let path = eulerian_path(&sg).unwrap();
+    assert_eq!(path.len(), 5);
+    let nodes: Vec<_> = path
+        .iter()
+        .map(|&ek| sg.get_edge_nodes(ek).unwrap())
+        .collect();
+    assert_eq!(nodes[0].0, b);
+    assert_eq!(nodes[4].1, d);
+    assert!(nodes.windows(2).all(|w| w[0].1 == w[1].0));
+
+    sg.insert_edge(a, d, ());
+    assert_eq!(eulerian_path(&sg), None);
+}
+
+#[test]
+fn undirected_eulerian_path() {
+    // the houses of Nikolaus: a house shape with both diagonals
+    let mut ug = UnSlotGraph::new_undirected();
+    let nodes: Vec<_> = (0..5).map(|i| ug.insert_node(i)).collect();
+    for (a, b) in [
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (3, 0),
+        (0, 2),
+        (1, 3),
+        (2, 4),
+        (4, 3),
+    ] {
+        ug.insert_edge(nodes[a], nodes[b], ());
+    }
+
+    let path = eulerian_path(&ug).unwrap();
+    assert_eq!(path.len(), 8);
+    let mut at = nodes[0];
+    for ek in path {
+        let (from, to) = ug.get_edge_nodes(ek).unwrap();
+        at = if from == at {
+            to
+        } else {
+            assert_eq!(to, at);
+            from
+        };
+    }
+    assert_eq!(at, nodes[1]);
+
+    // two separate triangles have no path through both
+    let mut ug = UnSlotGraph::new_undirected();
+    let nodes: Vec<_> = (0..6).map(|i| ug.insert_node(i)).collect();
+    for (a, b) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)] {
+        ug.insert_edge(nodes[a], nodes[b], ());
+    }
+    assert_eq!(eulerian_path(&ug), None);
+    assert_eq!(
+        eulerian_path(&UnSlotGraph::<_, (), ()>::new_undirected()),
+        Some(vec![])
+    );
+}
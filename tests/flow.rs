@@ -0,0 +1,84 @@
+use slotgraph::{
+    algo::{max_flow, min_cost_flow},
+    SlotGraph,
+};
+
+#[test]
+fn max_flow_network() {
+    let mut sg = SlotGraph::new();
+    let s = sg.insert_node("s");
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("b");
+    let c = sg.insert_node("c");
+    let d = sg.insert_node("d");
+    let t = sg.insert_node("t");
+    sg.insert_edge(s, a, 10);
+    sg.insert_edge(s, c, 10);
+    sg.insert_edge(a, b, 4);
+    sg.insert_edge(a, c, 2);
+    sg.insert_edge(a, d, 8);
+    sg.insert_edge(c, d, 9);
+    sg.insert_edge(d, b, 6);
+    sg.insert_edge(b, t, 10);
+    sg.insert_edge(d, t, 10);
+
+    let (total, flows) = max_flow(&sg, s, t, |_, &c| c);
+    assert_eq!(total, 19);
+    for (ek, &capacity) in sg.iter_edges() {
+        assert!(flows[ek] >= 0 && flows[ek] <= capacity);
+    }
+    // flow is conserved at every inner node
+    for (nk, _) in sg.iter_nodes().filter(|&(nk, _)| nk != s && nk != t) {
+        let inflow: i32 = sg.in_edges(nk).map(|ek| flows[ek]).sum();
+        let outflow: i32 = sg.out_edges(nk).map(|ek| flows[ek]).sum();
+        assert_eq!(inflow, outflow);
+    }
+    let into_sink: i32 = sg.in_edges(t).map(|ek| flows[ek]).sum();
+    assert_eq!(into_sink, 19);
+}
+
+#[test]
+fn max_flow_needs_cancelling() {
+    // the shortest first path s-a-b-t must be partly undone through the a-b edge
+    let mut sg = SlotGraph::new();
+    let s = sg.insert_node(());
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let t = sg.insert_node(());
+    sg.insert_edge(s, a, 1);
+    sg.insert_edge(s, b, 1);
+    sg.insert_edge(a, b, 1);
+    sg.insert_edge(a, t, 1);
+    sg.insert_edge(b, t, 1);
+    assert_eq!(max_flow(&sg, s, t, |_, &c| c).0, 2);
+    assert_eq!(max_flow(&sg, t, s, |_, &c| c).0, 0);
+    assert_eq!(max_flow(&sg, s, s, |_, &c| c).0, 0);
+}
+
+#[test]
+fn min_cost_flow_prefers_cheap_routes() {
+    // every edge holds its capacity and its cost per unit
+    let mut sg = SlotGraph::new();
+    let s = sg.insert_node(());
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let t = sg.insert_node(());
+    let sa = sg.insert_edge(s, a, (2, 1));
+    let sb = sg.insert_edge(s, b, (2, 4));
+    let ab = sg.insert_edge(a, b, (1, 1));
+    let at = sg.insert_edge(a, t, (1, 5));
+    let bt = sg.insert_edge(b, t, (3, 1));
+
+    let result = min_cost_flow(&sg, s, t, |_, &(c, _)| c, |_, &(_, w)| w);
+    assert_eq!(result.flow, 4);
+    assert_eq!(result.cost, 19);
+    assert_eq!(result.flows[sa], 2);
+    assert_eq!(result.flows[sb], 2);
+    assert_eq!(result.flows[ab], 1);
+    assert_eq!(result.flows[at], 1);
+    assert_eq!(result.flows[bt], 3);
+    assert_eq!(result.flow, max_flow(&sg, s, t, |_, &(c, _)| c).0);
+
+    let none = min_cost_flow(&sg, t, s, |_, &(c, _)| c, |_, &(_, w)| w);
+    assert_eq!((none.flow, none.cost), (0, 0));
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "rand")]
+
+use std::collections::HashSet;
+
+use rand::{rngs::StdRng, SeedableRng};
+use slotgraph::{generate, Directed, Undirected};
+
+#[test]
+fn erdos_renyi_extremes() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let (sg, nodes) = generate::erdos_renyi::<Directed, _>(5, 1.0, &mut rng);
+    assert_eq!(nodes.len(), 5);
+    assert_eq!(sg.edge_len(), 20);
+    let (ug, _) = generate::erdos_renyi::<Undirected, _>(5, 1.0, &mut rng);
+    assert_eq!(ug.edge_len(), 10);
+    let (ug, _) = generate::erdos_renyi::<Undirected, _>(5, 0.0, &mut rng);
+    assert_eq!((ug.node_len(), ug.edge_len()), (5, 0));
+
+    let (ug, _) = generate::erdos_renyi::<Undirected, _>(200, 0.1, &mut rng);
+    // 19900 possible edges, so about 1990 are expected
+    assert!((1800..2200).contains(&ug.edge_len()));
+}
+
+#[test]
+fn barabasi_albert_attachment() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let (ug, nodes) = generate::barabasi_albert::<Undirected, _>(500, 3, &mut rng);
+    assert_eq!(ug.node_len(), 500);
+    assert_eq!(ug.edge_len(), 3 + 496 * 3);
+    for &nk in &nodes[4..] {
+        let targets: HashSet<_> = ug
+            .out_edges(nk)
+            .filter_map(|ek| ug.get_edge_nodes(ek))
+            .filter(|&(from, _)| from == nk)
+            .map(|(_, to)| to)
+            .collect();
+        assert_eq!(targets.len(), 3);
+    }
+    // early nodes collect far more edges than late ones
+    let degree = |nk| ug.out_edges(nk).count();
+    assert!(degree(nodes[0]) > 10 * degree(nodes[499]));
+}
+
+#[test]
+fn watts_strogatz_ring_and_rewiring() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let (ug, nodes) = generate::watts_strogatz::<Undirected, _>(10, 4, 0.0, &mut rng);
+    assert_eq!(ug.edge_len(), 20);
+    for (i, &nk) in nodes.iter().enumerate() {
+        let neighbors: HashSet<_> = ug.neighbors(nk).collect();
+        let expected: HashSet<_> = [1, 2, 8, 9].map(|offset| nodes[(i + offset) % 10]).into();
+        assert_eq!(neighbors, expected);
+    }
+
+    let (sg, _) = generate::watts_strogatz::<Directed, _>(100, 6, 0.5, &mut rng);
+    assert_eq!(sg.edge_len(), 300);
+    let pairs: HashSet<_> = sg.iter_edge_nodes().map(|(_, pair)| pair).collect();
+    assert_eq!(pairs.len(), 300);
+    assert!(pairs.iter().all(|(from, to)| from != to));
+}
@@ -0,0 +1,389 @@
+use slotgraph::{algo::is_isomorphic_keyed, SlotGraph, UnSlotGraph};
+
+#[test]
+fn topology() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node(String::from("n1"));
+    let n2 = sg.insert_node(String::from("n2"));
+    let n3 = sg.insert_node(String::from("n3"));
+    sg.insert_edge(n1, n2, String::from("e1"));
+    let e2 = sg.insert_edge(n2, n3, String::from("e2"));
+    sg.insert_edge(n3, n1, String::from("e3"));
+    sg.remove_edge(e2);
+
+    let topo = sg.topology();
+    assert_eq!(topo.node_len(), sg.node_len());
+    assert_eq!(topo.edge_len(), sg.edge_len());
+    for (nk, _) in sg.iter_nodes() {
+        assert_eq!(topo.get_node(nk), Some(&()));
+    }
+    for (ek, nodes) in sg.iter_edge_nodes() {
+        assert_eq!(topo.get_edge_nodes(ek), Some(nodes));
+    }
+    assert_eq!(topo.get_edge_nodes(e2), None);
+}
+
+#[test]
+fn diff() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node(1);
+    let n2 = sg.insert_node(2);
+    let n3 = sg.insert_node(3);
+    sg.insert_edge(n1, n2, "e1");
+    sg.insert_edge(n2, n3, "e2");
+
+    let mut other = sg.clone();
+    assert!(sg.diff(&other).is_empty());
+    let e3 = other.insert_edge(n3, n1, "e3");
+    *other.get_node_mut(n2).unwrap() = 20;
+
+    let diff = sg.diff(&other);
+    assert_eq!(diff.added_edges, vec![e3]);
+    assert_eq!(diff.changed_nodes, vec![n2]);
+    assert!(diff.added_nodes.is_empty());
+    assert!(diff.removed_nodes.is_empty());
+    assert!(diff.removed_edges.is_empty());
+    assert!(diff.changed_edges.is_empty());
+
+    let reverse = other.diff(&sg);
+    assert_eq!(reverse.removed_edges, vec![e3]);
+    assert_eq!(reverse.changed_nodes, vec![n2]);
+}
+
+#[test]
+fn copy_node_into() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("b");
+    let c = sg.insert_node("c");
+    sg.insert_edge(a, b, "ab");
+    sg.insert_edge(c, a, "ca");
+    sg.insert_edge(a, a, "aa");
+
+    let mut dest = sg.clone();
+    dest.remove_edges_where(|_, _, _, _| true);
+    dest.remove_node(a);
+    dest.remove_node(c);
+
+    let copy = sg.copy_node_into(a, &mut dest).unwrap();
+    assert_eq!(dest.get_node(copy), Some(&"a"));
+    assert_eq!(dest.node_len(), 2);
+    let mut edges: Vec<_> = dest
+        .iter_edges_full()
+        .map(|(_, from, to, &value)| (from, to, value))
+        .collect();
+    edges.sort_by_key(|&(_, _, value)| value);
+    assert_eq!(edges, vec![(copy, copy, "aa"), (copy, b, "ab")]);
+
+    sg.remove_node(c);
+    assert_eq!(sg.copy_node_into(c, &mut dest), None);
+}
+
+#[test]
+fn raw_values() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node(1);
+    let n2 = sg.insert_node(2);
+    let n3 = sg.insert_node(3);
+    sg.remove_node(n2);
+    sg.insert_edge(n1, n3, "e1");
+
+    let raw: Vec<_> = sg.node_values().iter().collect();
+    let iterated: Vec<_> = sg.iter_nodes().collect();
+    assert_eq!(raw, iterated);
+    assert_eq!(sg.node_values().values().sum::<i32>(), 4);
+    assert!(!sg.node_values().contains_key(n2));
+
+    let raw: Vec<_> = sg.edge_values().iter().collect();
+    assert_eq!(raw, sg.iter_edges().collect::<Vec<_>>());
+}
+
+#[test]
+fn disjoint_union() {
+    let mut a = SlotGraph::new();
+    let a1 = a.insert_node("a1");
+    let a2 = a.insert_node("a2");
+    let ea = a.insert_edge(a1, a2, "ea");
+
+    let mut b = SlotGraph::new();
+    let b1 = b.insert_node("b1");
+    let b2 = b.insert_node("b2");
+    b.remove_node(b1);
+    let b3 = b.insert_node("b3");
+    let eb = b.insert_edge(b3, b2, "eb");
+    let el = b.insert_edge(b2, b2, "el");
+
+    let (union, node_map, edge_map) = a.disjoint_union(b.clone());
+    assert_eq!((union.node_len(), union.edge_len()), (4, 3));
+    assert_eq!(union.get_node(a1), Some(&"a1"));
+    assert_eq!(union.get_edge_nodes(ea), Some((a1, a2)));
+    assert!(!node_map.contains_key(b1));
+    assert_eq!(union.get_node(node_map[b3]), Some(&"b3"));
+    assert_eq!(union.get_edge(edge_map[eb]), Some(&"eb"));
+    assert_eq!(
+        union.get_edge_nodes(edge_map[eb]),
+        Some((node_map[b3], node_map[b2]))
+    );
+    assert_eq!(
+        union.get_edge_nodes(edge_map[el]),
+        Some((node_map[b2], node_map[b2]))
+    );
+
+    // absorbing into a graph with its own nodes keeps them apart
+    let mut c = b.clone();
+    let (node_map, _) = c.absorb(b);
+    assert_eq!(c.node_len(), 4);
+    assert_ne!(node_map[b2], b2);
+    assert_eq!(c.get_node(node_map[b2]), c.get_node(b2));
+}
+
+#[test]
+fn complement() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("b");
+    let c = sg.insert_node("c");
+    sg.insert_edge(a, b, 1);
+    sg.insert_edge(a, b, 2);
+    sg.insert_edge(b, a, 3);
+    sg.insert_edge(c, c, 4);
+
+    let co = sg.complement();
+    assert_eq!(co.node_len(), 3);
+    assert_eq!(co.get_node(c), Some(&"c"));
+    let mut pairs: Vec<_> = co.iter_edge_nodes().map(|(_, pair)| pair).collect();
+    pairs.sort();
+    let mut expected = vec![(a, c), (b, c), (c, a), (c, b)];
+    expected.sort();
+    assert_eq!(pairs, expected);
+
+    let mut ug = UnSlotGraph::new_undirected();
+    let nodes: Vec<_> = (0..4).map(|i| ug.insert_node(i)).collect();
+    ug.insert_edge(nodes[1], nodes[0], ());
+    ug.insert_edge(nodes[2], nodes[3], ());
+    let co = ug.complement();
+    assert_eq!(co.edge_len(), 4);
+    assert!(!co.neighbors(nodes[0]).any(|nk| nk == nodes[1]));
+    assert_eq!(co.complement().edge_len(), 2);
+}
+
+#[test]
+fn map_values() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("bb");
+    let e = sg.insert_edge(a, b, 2);
+    let l = sg.insert_edge(b, b, 3);
+
+    let mapped = sg.map(|nk, name| (nk, name.to_string()), |_, w| f64::from(w) / 2.0);
+    assert_eq!(mapped.get_node(b), Some(&(b, "bb".to_string())));
+    assert_eq!(mapped.get_edge(e), Some(&1.0));
+    assert_eq!(mapped.get_edge_nodes(l), Some((b, b)));
+    assert_eq!(mapped.successors(a).collect::<Vec<_>>(), vec![b]);
+}
+
+#[test]
+fn filter_map_keeps_keys() {
+    let mut sg = SlotGraph::new();
+    let nodes: Vec<_> = (0..4).map(|i| sg.insert_node(i)).collect();
+    let e01 = sg.insert_edge(nodes[0], nodes[1], 1);
+    let e12 = sg.insert_edge(nodes[1], nodes[2], 2);
+    let e20 = sg.insert_edge(nodes[2], nodes[0], 3);
+    let e33 = sg.insert_edge(nodes[3], nodes[3], 4);
+    let e02 = sg.insert_edge(nodes[0], nodes[2], 5);
+
+    let mut seen = Vec::new();
+    let filtered = sg.filter_map(
+        |_, i| (i != 1).then(|| i * 10),
+        |ek, w| {
+            seen.push(ek);
+            (w != 5).then_some(w)
+        },
+    );
+    assert_eq!(filtered.node_len(), 3);
+    assert_eq!(filtered.get_node(nodes[2]), Some(&20));
+    assert_eq!(filtered.get_node(nodes[1]), None);
+    // edges of the removed node are dropped without being mapped
+    seen.sort();
+    let mut expected = vec![e20, e33, e02];
+    expected.sort();
+    assert_eq!(seen, expected);
+    assert_eq!(filtered.get_edge(e01), None);
+    assert_eq!(filtered.get_edge(e12), None);
+    assert_eq!(filtered.get_edge(e02), None);
+
+    assert_eq!(filtered.edge_len(), 2);
+    assert_eq!(filtered.get_edge_nodes(e20), Some((nodes[2], nodes[0])));
+    assert_eq!(filtered.get_edge(e33), Some(&4));
+    assert_eq!(filtered.successors(nodes[0]).count(), 0);
+    assert_eq!(
+        filtered.predecessors(nodes[0]).collect::<Vec<_>>(),
+        vec![nodes[2]]
+    );
+}
+
+#[test]
+fn clear_and_clear_edges() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("b");
+    let ab = sg.insert_edge(a, b, 1);
+    sg.insert_edge(b, b, 2);
+
+    sg.clear_edges();
+    assert_eq!((sg.node_len(), sg.edge_len()), (2, 0));
+    assert_eq!(sg.get_node(a), Some(&"a"));
+    assert_eq!(sg.get_edge(ab), None);
+    assert_eq!(sg.out_degree(b), Some(0));
+    let ba = sg.insert_edge(b, a, 3);
+    assert_ne!(ba, ab);
+    assert_eq!(sg.successors(b).collect::<Vec<_>>(), vec![a]);
+
+    sg.clear();
+    assert_eq!((sg.node_len(), sg.edge_len()), (0, 0));
+    assert_eq!(sg.get_node(a), None);
+    assert_eq!(sg.get_edge_nodes(ba), None);
+    let c = sg.insert_node("c");
+    assert_ne!(c, a);
+    assert_eq!(sg.iter_nodes().count(), 1);
+}
+
+#[test]
+fn with_capacity_and_reserve() {
+    let mut sg = SlotGraph::with_capacity(100, 200);
+    assert_eq!((sg.node_len(), sg.edge_len()), (0, 0));
+    assert!(sg.node_capacity() >= 100 && sg.edge_capacity() >= 200);
+    let nodes: Vec<_> = (0..100).map(|i| sg.insert_node(i)).collect();
+    assert!(sg.node_capacity() >= 100);
+    sg.reserve_nodes(1000);
+    sg.reserve_edges(1000);
+    assert!(sg.node_capacity() >= 1100 && sg.edge_capacity() >= 1000);
+    for pair in nodes.windows(2) {
+        sg.insert_edge(pair[0], pair[1], ());
+    }
+    assert_eq!(sg.edge_len(), 99);
+
+    let mut ug = UnSlotGraph::with_capacity_undirected(2, 1);
+    let a = ug.insert_node(());
+    let b = ug.insert_node(());
+    ug.insert_edge(a, b, ());
+    assert_eq!(ug.neighbors(b).collect::<Vec<_>>(), vec![a]);
+}
+
+#[test]
+fn shrink_to_fit_keeps_slots() {
+    let mut sg = SlotGraph::new();
+    let hub = sg.insert_node(0);
+    let leaves: Vec<_> = (1..50).map(|i| sg.insert_node(i)).collect();
+    for &leaf in &leaves {
+        sg.insert_edge(hub, leaf, ());
+    }
+    let capacity = sg.node_capacity();
+    for &leaf in &leaves[1..] {
+        sg.remove_node(leaf);
+    }
+
+    sg.shrink_to_fit();
+    // removed slots stay reserved, so old keys remain stale
+    assert_eq!(sg.node_capacity(), capacity);
+    assert_eq!(sg.get_node(leaves[1]), None);
+    assert_eq!(sg.successors(hub).collect::<Vec<_>>(), vec![leaves[0]]);
+    let (compacted, node_map, _) = sg.remap_keys::<slotmap::DefaultKey>();
+    assert!(compacted.node_capacity() < capacity);
+    assert_eq!(compacted.get_node(node_map[leaves[0]]), Some(&1));
+}
+
+#[test]
+fn debug_format() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("b");
+    sg.insert_edge(a, b, 1.5);
+    assert_eq!(
+        format!("{sg:?}"),
+        "SlotGraph { directed: true, \
+         nodes: {NodeKey(DefaultKey(1v1)): \"a\", NodeKey(DefaultKey(2v1)): \"b\"}, \
+         edges: {EdgeKey(DefaultKey(1v1)): (NodeKey(DefaultKey(1v1)), NodeKey(DefaultKey(2v1)), 1.5)} }"
+    );
+    let ug: UnSlotGraph<_, (), ()> = UnSlotGraph::new_undirected();
+    assert_eq!(
+        format!("{ug:?}"),
+        "SlotGraph { directed: false, nodes: {}, edges: {} }"
+    );
+}
+
+#[test]
+fn equality_by_key() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("b");
+    let ab = sg.insert_edge(a, b, 1);
+    let ba = sg.insert_edge(b, a, 2);
+
+    let mut other = sg.clone();
+    assert_eq!(sg, other);
+    *other.get_edge_mut(ab).unwrap() = 3;
+    assert_ne!(sg, other);
+    *other.get_edge_mut(ab).unwrap() = 1;
+    // the same values under fresh keys are not equal
+    other.remove_edge(ba);
+    other.insert_edge(b, a, 2);
+    assert_ne!(sg, other);
+    assert!(is_isomorphic_keyed(&sg, &other));
+
+    let mut rebuilt = SlotGraph::new();
+    let b2 = rebuilt.insert_node("b");
+    let a2 = rebuilt.insert_node("a");
+    rebuilt.insert_edge(b2, a2, 2);
+    rebuilt.insert_edge(a2, b2, 1);
+    assert!(is_isomorphic_keyed(&sg, &rebuilt));
+    *rebuilt.get_node_mut(a2).unwrap() = "c";
+    assert!(!is_isomorphic_keyed(&sg, &rebuilt));
+}
+
+#[test]
+fn drain_nodes_and_edges() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(String::from("a"));
+    let b = sg.insert_node(String::from("b"));
+    let ab = sg.insert_edge(a, b, vec![1]);
+    let bb = sg.insert_edge(b, b, vec![2]);
+
+    let edges: Vec<_> = sg.drain_edges().collect();
+    assert_eq!(edges, vec![(ab, vec![1]), (bb, vec![2])]);
+    assert_eq!((sg.node_len(), sg.edge_len()), (2, 0));
+    assert_eq!(sg.out_degree(b), Some(0));
+    sg.insert_edge(b, a, vec![3]);
+
+    let mut pool = Vec::new();
+    let mut drain = sg.drain_nodes();
+    pool.extend(drain.next());
+    drop(drain);
+    assert_eq!(pool, vec![(a, String::from("a"))]);
+    assert_eq!((sg.node_len(), sg.edge_len()), (0, 0));
+    assert_eq!(sg.iter_nodes().count(), 0);
+    let c = sg.insert_node(String::from("c"));
+    assert_eq!(sg.out_degree(c), Some(0));
+}
+
+#[test]
+fn node_and_edge_keys() {
+    let mut sg = SlotGraph::new();
+    let nodes: Vec<_> = (0..4).map(|i| sg.insert_node(i)).collect();
+    let edges: Vec<_> = (0..3)
+        .map(|i| sg.insert_edge(nodes[i], nodes[i + 1], i))
+        .collect();
+    sg.remove_node(nodes[1]);
+
+    let keys: Vec<_> = sg.node_keys().collect();
+    assert_eq!(keys, vec![nodes[0], nodes[2], nodes[3]]);
+    assert!(sg.iter_nodes().map(|(nk, _)| nk).eq(keys.iter().copied()));
+    assert_eq!(sg.edge_keys().collect::<Vec<_>>(), vec![edges[2]]);
+
+    // collect the keys, then mutate in a second pass
+    for nk in sg.node_keys().collect::<Vec<_>>() {
+        let value = *sg.get_node(nk).unwrap();
+        sg.insert_edge(nk, nk, value * 10);
+    }
+    assert_eq!(sg.edge_keys().count(), 4);
+}
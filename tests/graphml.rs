@@ -0,0 +1,98 @@
+#![cfg(feature = "graphml")]
+
+use std::io::ErrorKind;
+
+use slotgraph::SlotGraph;
+
+#[test]
+fn graphml_round_trip() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(("a", "<red & blue>"));
+    let b = sg.insert_node(("b", ""));
+    sg.insert_edge(a, b, 2.5);
+    sg.insert_edge(b, b, 1.0);
+
+    let mut out = Vec::new();
+    sg.to_graphml(
+        &mut out,
+        |_, &(name, color)| {
+            let mut attributes = vec![("name".to_string(), name.to_string())];
+            if !color.is_empty() {
+                attributes.push(("color".to_string(), color.to_string()));
+            }
+            attributes
+        },
+        |_, w| vec![("weight".to_string(), w.to_string())],
+    )
+    .unwrap();
+    let xml = String::from_utf8(out).unwrap();
+    assert!(xml.contains(r#"edgedefault="directed""#));
+    assert!(xml.contains("&lt;red &amp; blue&gt;"));
+
+    let (read, ids) = SlotGraph::from_graphml(
+        &xml,
+        |_, attributes| {
+            (
+                attributes["name"].clone(),
+                attributes.get("color").cloned().unwrap_or_default(),
+            )
+        },
+        |attributes| attributes["weight"].parse::<f64>().unwrap(),
+    )
+    .unwrap();
+    assert_eq!(read.node_len(), 2);
+    assert_eq!(read.edge_len(), 2);
+    let a = ids["n0"];
+    let b = ids["n1"];
+    assert_eq!(
+        read.get_node(a),
+        Some(&("a".to_string(), "<red & blue>".to_string()))
+    );
+    assert_eq!(read.get_node(b), Some(&("b".to_string(), String::new())));
+    let ab = read.out_edges(a).next().unwrap();
+    assert_eq!(read.get_edge_nodes(ab), Some((a, b)));
+    assert_eq!(read.get_edge(ab), Some(&2.5));
+}
+
+#[test]
+fn from_graphml_defaults_and_nesting() {
+    let xml = r#"<?xml version="1.0"?>
+        <graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+          <key id="k0" for="node" attr.name="color"><default>yellow</default></key>
+          <key id="k1" for="edge"/>
+          <graph id="G" edgedefault="undirected">
+            <edge source="x" target="y"><data key="k1">&#65;&amp;B</data></edge>
+            <node id="x"><data key="k0">green</data></node>
+            <node id="y">
+              <graph id="inner"><node id="z"/></graph>
+            </node>
+          </graph>
+        </graphml>"#;
+    let (sg, ids) = SlotGraph::from_graphml(
+        xml,
+        |id, attributes| format!("{id}:{}", attributes["color"]),
+        |attributes| attributes["k1"].clone(),
+    )
+    .unwrap();
+    assert_eq!(sg.node_len(), 3);
+    assert_eq!(sg.get_node(ids["x"]).unwrap(), "x:green");
+    assert_eq!(sg.get_node(ids["y"]).unwrap(), "y:yellow");
+    assert_eq!(sg.get_node(ids["z"]).unwrap(), "z:yellow");
+    let e = sg.out_edges(ids["x"]).next().unwrap();
+    assert_eq!(sg.get_edge(e).unwrap(), "A&B");
+}
+
+#[test]
+fn from_graphml_invalid() {
+    for xml in [
+        r#"<graphml><graph><node id="a"/><edge source="a" target="b"/></graph></graphml>"#,
+        r#"<graphml><graph><node id="a"/><node id="a"/></graph></graphml>"#,
+        r#"<graphml><graph><node/></graph></graphml>"#,
+        r#"<graphml><graph><node id="a"></graph></graphml>"#,
+    ] {
+        let err = SlotGraph::from_graphml(xml, |_, _| (), |_| ())
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData, "{xml}");
+    }
+}
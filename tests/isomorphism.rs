@@ -0,0 +1,185 @@
+use slotgraph::{
+    algo::{
+        is_isomorphic, is_isomorphic_matching, subgraph_isomorphisms,
+        subgraph_isomorphisms_matching,
+    },
+    SlotGraph, UnSlotGraph,
+};
+
+#[test]
+fn is_isomorphic_relabeled() {
+    // a directed square with a diagonal and a self-loop, inserted in different orders
+    let mut a = SlotGraph::new();
+    let an: Vec<_> = (0..4).map(|i| a.insert_node(i)).collect();
+    for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (2, 2)] {
+        a.insert_edge(an[from], an[to], ());
+    }
+    let mut b = SlotGraph::new();
+    let bn: Vec<_> = (0..4).map(|i| b.insert_node(i)).collect();
+    for (from, to) in [(3, 3), (1, 3), (2, 1), (3, 0), (0, 2), (2, 3)] {
+        b.insert_edge(bn[from], bn[to], ());
+    }
+    assert!(is_isomorphic(&a, &b));
+
+    // the same square with the diagonal reversed
+    let mut c = SlotGraph::new();
+    let cn: Vec<_> = (0..4).map(|i| c.insert_node(i)).collect();
+    for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 0), (2, 0), (2, 2)] {
+        c.insert_edge(cn[from], cn[to], ());
+    }
+    assert!(!is_isomorphic(&a, &c));
+
+    b.insert_edge(bn[0], bn[1], ());
+    assert!(!is_isomorphic(&a, &b));
+    assert!(is_isomorphic(
+        &SlotGraph::<_, (), ()>::new(),
+        &SlotGraph::<_, u8, u8>::new()
+    ));
+}
+
+#[test]
+fn is_isomorphic_undirected_components() {
+    // two triangles are not a hexagon, though every node has degree two
+    let mut triangles = UnSlotGraph::new_undirected();
+    let hexagon_nodes: Vec<_> = (0..6).map(|_| triangles.insert_node(())).collect();
+    for (from, to) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)] {
+        triangles.insert_edge(hexagon_nodes[from], hexagon_nodes[to], ());
+    }
+    let mut hexagon = UnSlotGraph::new_undirected();
+    let nodes: Vec<_> = (0..6).map(|_| hexagon.insert_node(())).collect();
+    for i in 0..6 {
+        hexagon.insert_edge(nodes[i], nodes[(i + 1) % 6], ());
+    }
+    assert!(!is_isomorphic(&triangles, &hexagon));
+
+    let mut other = UnSlotGraph::new_undirected();
+    let nodes: Vec<_> = (0..6).map(|_| other.insert_node(())).collect();
+    for (from, to) in [(5, 0), (3, 1), (0, 2), (4, 1), (2, 5), (3, 4)] {
+        other.insert_edge(nodes[from], nodes[to], ());
+    }
+    assert!(is_isomorphic(&triangles, &other));
+}
+
+#[test]
+fn is_isomorphic_matching_values() {
+    let mut a = SlotGraph::new();
+    let x = a.insert_node("x");
+    let y = a.insert_node("y");
+    a.insert_edge(x, y, 1);
+    a.insert_edge(x, y, 2);
+    a.insert_edge(y, x, 3);
+
+    let mut b = SlotGraph::new();
+    let y2 = b.insert_node("y".to_string());
+    let x2 = b.insert_node("x".to_string());
+    b.insert_edge(y2, x2, 3);
+    b.insert_edge(x2, y2, 2);
+    b.insert_edge(x2, y2, 1);
+
+    assert!(is_isomorphic_matching(&a, &b, |n, m| n == m, |e, f| e == f));
+    // parallel edges have to be paired one-to-one
+    assert!(!is_isomorphic_matching(
+        &a,
+        &b,
+        |n, m| n == m,
+        |e, _| *e == 1
+    ));
+    assert!(!is_isomorphic_matching(&a, &b, |_, _| true, |e, f| e != f));
+    b.get_node_mut(x2).unwrap().push('!');
+    assert!(!is_isomorphic_matching(&a, &b, |n, m| n == m, |_, _| true));
+    assert!(is_isomorphic(&a, &b));
+}
+
+#[test]
+fn subgraph_isomorphisms_all_mappings() {
+    // a path a -> b -> c in a host with two ways to continue from its middle
+    let mut pattern = SlotGraph::new();
+    let a = pattern.insert_node(());
+    let b = pattern.insert_node(());
+    let c = pattern.insert_node(());
+    pattern.insert_edge(a, b, ());
+    pattern.insert_edge(b, c, ());
+
+    let mut host = SlotGraph::new();
+    let nodes: Vec<_> = (0..5).map(|i| host.insert_node(i)).collect();
+    for (from, to) in [(0, 1), (1, 2), (1, 3), (3, 4), (2, 0), (0, 2)] {
+        host.insert_edge(nodes[from], nodes[to], ());
+    }
+
+    let mut found: Vec<_> = subgraph_isomorphisms(&pattern, &host)
+        .map(|mapping| {
+            assert_eq!(mapping.len(), 3);
+            [mapping[a], mapping[b], mapping[c]].map(|nk| *host.get_node(nk).unwrap())
+        })
+        .collect();
+    found.sort();
+    // extra host edges between mapped nodes, like 0 -> 2 for the path 0 -> 1 -> 2, are allowed
+    assert_eq!(
+        found,
+        vec![[0, 1, 2], [0, 1, 3], [1, 2, 0], [1, 3, 4], [2, 0, 1]]
+    );
+
+    pattern.insert_edge(c, a, ());
+    let cycles: Vec<_> = subgraph_isomorphisms(&pattern, &host).collect();
+    assert_eq!(cycles.len(), 3);
+    assert!(cycles.iter().all(|m| host.get_node(m[a]).unwrap() < &3));
+}
+
+#[test]
+fn subgraph_isomorphisms_matching_values() {
+    // an addition of zero, matched by operator and operand position
+    let mut pattern = SlotGraph::new();
+    let add = pattern.insert_node(Some("add"));
+    let zero = pattern.insert_node(Some("0"));
+    let any = pattern.insert_node(None);
+    pattern.insert_edge(add, zero, 1);
+    pattern.insert_edge(add, any, 0);
+
+    let mut host = SlotGraph::new();
+    let sum = host.insert_node("add");
+    let x = host.insert_node("x");
+    let z = host.insert_node("0");
+    let inner = host.insert_node("add");
+    host.insert_edge(sum, x, 0);
+    host.insert_edge(sum, inner, 1);
+    host.insert_edge(inner, x, 0);
+    host.insert_edge(inner, z, 1);
+
+    let matches: Vec<_> = subgraph_isomorphisms_matching(
+        &pattern,
+        &host,
+        |p, h| p.is_none_or(|p| p == *h),
+        |p, h| p == h,
+    )
+    .collect();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(
+        (matches[0][add], matches[0][zero], matches[0][any]),
+        (inner, z, x)
+    );
+
+    // an empty pattern matches once, and a pattern larger than the host never does
+    assert_eq!(
+        subgraph_isomorphisms(&SlotGraph::<_, (), ()>::new(), &host).count(),
+        1
+    );
+    assert_eq!(subgraph_isomorphisms(&host, &pattern).count(), 0);
+}
+
+#[test]
+fn subgraph_isomorphisms_undirected() {
+    // a triangle in a square with one diagonal
+    let mut triangle = UnSlotGraph::new_undirected();
+    let t: Vec<_> = (0..3).map(|_| triangle.insert_node(())).collect();
+    for i in 0..3 {
+        triangle.insert_edge(t[i], t[(i + 1) % 3], ());
+    }
+    let mut square = UnSlotGraph::new_undirected();
+    let s: Vec<_> = (0..4).map(|_| square.insert_node(())).collect();
+    for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)] {
+        square.insert_edge(s[from], s[to], ());
+    }
+    // two triangles, each matched in its six orientations
+    assert_eq!(subgraph_isomorphisms(&triangle, &square).count(), 12);
+    assert!(subgraph_isomorphisms(&triangle, &square).all(|m| m[t[0]] != s[1] || m[t[1]] != s[3]));
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "json")]
+
+use slotgraph::{SlotGraph, UnSlotGraph};
+use slotmap::DefaultKey;
+
+#[test]
+fn json_round_trip_preserves_keys() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node("a".to_string());
+    let removed = sg.insert_node("removed".to_string());
+    let b = sg.insert_node("b".to_string());
+    sg.remove_node(removed);
+    let c = sg.insert_node("c".to_string());
+    let ab = sg.insert_edge(a, b, 1.5);
+    let bc = sg.insert_edge(b, c, 2.0);
+    let cc = sg.insert_edge(c, c, 0.0);
+    sg.remove_edge(bc);
+    let ca = sg.insert_edge(c, a, -1.0);
+
+    let json = sg.to_json().unwrap();
+    let read: SlotGraph<_, String, f64> = SlotGraph::from_json(&json).unwrap();
+    assert_eq!(read.node_len(), 3);
+    assert_eq!(read.edge_len(), 3);
+    for k in [a, b, c] {
+        assert_eq!(read.get_node(k), sg.get_node(k));
+    }
+    for k in [ab, cc, ca] {
+        assert_eq!(read.get_edge(k), sg.get_edge(k));
+        assert_eq!(read.get_edge_nodes(k), sg.get_edge_nodes(k));
+    }
+    assert!(read.get_node(removed).is_none());
+    assert!(read.get_edge(bc).is_none());
+    assert_eq!(read.out_edges(c).collect::<Vec<_>>(), vec![ca, cc]);
+    assert_eq!(read.in_edges(a).collect::<Vec<_>>(), vec![ca]);
+    assert_eq!(read.to_json().unwrap(), json);
+}
+
+#[test]
+fn undirected_json_round_trip() {
+    let mut ug = UnSlotGraph::new_undirected();
+    let a = ug.insert_node(1);
+    let b = ug.insert_node(2);
+    let e = ug.insert_edge(a, b, ());
+
+    let json = ug.to_json().unwrap();
+    assert!(json.starts_with(r#"{"directed":false,"#));
+    let read: UnSlotGraph<_, i32, ()> = UnSlotGraph::from_json(&json).unwrap();
+    assert_eq!(read.neighbors(b).collect::<Vec<_>>(), vec![a]);
+    assert_eq!(read.edges_of(b).collect::<Vec<_>>(), vec![e]);
+    assert!(SlotGraph::<DefaultKey, i32, ()>::from_json(&json).is_err());
+}
+
+#[test]
+fn from_json_invalid() {
+    let key = |idx: u32| format!(r#"{{"idx":{idx},"version":1}}"#);
+    for json in [
+        "[]".to_string(),
+        format!(
+            r#"{{"directed":true,"nodes":[{{"key":{},"value":0}},{{"key":{},"value":0}}],"edges":[]}}"#,
+            key(1),
+            key(1)
+        ),
+        format!(
+            r#"{{"directed":true,"nodes":[{{"key":{},"value":0}}],"edges":[{{"key":{},"from":{},"to":{},"value":0}}]}}"#,
+            key(1),
+            key(1),
+            key(1),
+            key(2)
+        ),
+        format!(
+            r#"{{"directed":true,"nodes":[{{"key":{},"value":0}}],"edges":[]}}"#,
+            key(u32::MAX)
+        ),
+    ] {
+        assert!(
+            SlotGraph::<DefaultKey, i32, i32>::from_json(&json).is_err(),
+            "{json}"
+        );
+    }
+}
+
+#[test]
+fn from_json_rejects_huge_slot_index() {
+    let json = r#"{"directed":true,"nodes":[{"key":{"idx":4294967294,"version":1},"value":0}],"edges":[]}"#;
+    assert!(SlotGraph::<DefaultKey, i32, i32>::from_json(json).is_err());
+
+    // a sparse graph left behind by removals still reads back under its keys
+    let mut sg = SlotGraph::<DefaultKey, i32, i32>::new();
+    let nodes: Vec<_> = (0..1000).map(|i| sg.insert_node(i)).collect();
+    let e = sg.insert_edge(nodes[999], nodes[999], 7);
+    sg.retain_nodes(|nk, _| nk == nodes[999]);
+    let read = SlotGraph::<DefaultKey, i32, i32>::from_json(&sg.to_json().unwrap()).unwrap();
+    assert_eq!(read.get_node(nodes[999]), Some(&999));
+    assert_eq!(read.get_edge(e), Some(&7));
+}
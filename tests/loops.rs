@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use slotgraph::{algo::natural_loops, SlotGraph};
+
+#[test]
+fn nested_natural_loops() {
+    // entry -> outer -> inner -> inner_latch -> inner, inner_latch -> outer_latch -> outer, outer -> exit
+    let mut sg = SlotGraph::new();
+    let entry = sg.insert_node("entry");
+    let outer = sg.insert_node("outer");
+    let inner = sg.insert_node("inner");
+    let inner_latch = sg.insert_node("inner_latch");
+    let outer_latch = sg.insert_node("outer_latch");
+    let exit = sg.insert_node("exit");
+    sg.insert_edge(entry, outer, ());
+    sg.insert_edge(outer, inner, ());
+    sg.insert_edge(inner, inner_latch, ());
+    let inner_back = sg.insert_edge(inner_latch, inner, ());
+    let inner_exit = sg.insert_edge(inner_latch, outer_latch, ());
+    let outer_back = sg.insert_edge(outer_latch, outer, ());
+    let outer_exit = sg.insert_edge(outer, exit, ());
+    let self_loop = sg.insert_edge(exit, exit, ());
+
+    let loops = natural_loops(&sg, entry);
+    assert_eq!(loops.len(), 3);
+
+    assert_eq!(loops[0].header, outer);
+    assert_eq!(loops[0].back_edges, vec![outer_back]);
+    assert_eq!(
+        loops[0].body,
+        HashSet::from([outer, inner, inner_latch, outer_latch])
+    );
+    assert_eq!(loops[0].exits, vec![outer_exit]);
+
+    assert_eq!(loops[1].header, inner);
+    assert_eq!(loops[1].back_edges, vec![inner_back]);
+    assert_eq!(loops[1].body, HashSet::from([inner, inner_latch]));
+    assert_eq!(loops[1].exits, vec![inner_exit]);
+
+    assert_eq!(loops[2].header, exit);
+    assert_eq!(loops[2].back_edges, vec![self_loop]);
+    assert_eq!(loops[2].body, HashSet::from([exit]));
+    assert!(loops[2].exits.is_empty());
+}
+
+#[test]
+fn irreducible_cycle_is_not_a_natural_loop() {
+    let mut sg = SlotGraph::new();
+    let entry = sg.insert_node(());
+    let x = sg.insert_node(());
+    let y = sg.insert_node(());
+    sg.insert_edge(entry, x, ());
+    sg.insert_edge(entry, y, ());
+    sg.insert_edge(x, y, ());
+    sg.insert_edge(y, x, ());
+    assert!(natural_loops(&sg, entry).is_empty());
+}
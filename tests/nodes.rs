@@ -0,0 +1,264 @@
+use slotgraph::{entry::NodeEntry, SlotGraph};
+
+#[test]
+fn merge_nodes() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node(1);
+    let n2 = sg.insert_node(2);
+    let n3 = sg.insert_node(4);
+    let e1 = sg.insert_edge(n1, n2, "e1");
+    let e2 = sg.insert_edge(n2, n3, "e2");
+    let e3 = sg.insert_edge(n3, n1, "e3");
+
+    assert_eq!(sg.merge_nodes(n1, n2, |a, b| a + b), Some(n1));
+    assert_eq!(sg.get_node(n1), Some(&3));
+    assert_eq!(sg.get_node(n2), None);
+    assert_eq!(sg.node_len(), 2);
+    assert_eq!(sg.get_edge_nodes(e1), Some((n1, n1)));
+    assert_eq!(sg.get_edge_nodes(e2), Some((n1, n3)));
+    assert_eq!(sg.get_edge_nodes(e3), Some((n3, n1)));
+    assert_eq!(sg.edge_len(), 3);
+
+    assert_eq!(sg.merge_nodes(n1, n2, |a, b| a + b), None);
+    assert_eq!(sg.merge_nodes(n2, n3, |a, b| a + b), None);
+    assert_eq!(sg.merge_nodes(n1, n1, |a, b| a + b), None);
+    assert_eq!(sg.get_node(n1), Some(&3));
+}
+
+#[test]
+fn index_nodes() {
+    let mut sg = SlotGraph::<_, _, ()>::new();
+    let keys: Vec<_> = (0..5).map(|i| sg.insert_node(i)).collect();
+    sg.remove_node(keys[1]);
+    let (forward, reverse) = sg.index_nodes();
+    assert_eq!(reverse.len(), sg.node_len());
+    assert_eq!(forward.len(), sg.node_len());
+    for (key, _) in sg.iter_nodes() {
+        assert!(forward[key] < sg.node_len());
+        assert_eq!(reverse[forward[key]], key);
+    }
+}
+
+#[test]
+fn remove_node_detailed() {
+    let mut sg = SlotGraph::new();
+    let hub = sg.insert_node("hub");
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("b");
+    let e1 = sg.insert_edge(hub, a, 1);
+    let e2 = sg.insert_edge(b, hub, 2);
+    let e3 = sg.insert_edge(hub, hub, 3);
+    let e4 = sg.insert_edge(a, b, 4);
+
+    let removed = sg.remove_node_detailed(hub).unwrap();
+    assert_eq!(removed.value, "hub");
+    let mut edges = removed.edges;
+    edges.sort_by_key(|&(_, _, _, value)| value);
+    assert_eq!(
+        edges,
+        vec![(e1, hub, a, 1), (e2, b, hub, 2), (e3, hub, hub, 3)]
+    );
+
+    assert_eq!(sg.edge_len(), 1);
+    assert_eq!(sg.get_edge(e4), Some(&4));
+    for (ek, _, _, _) in edges {
+        assert_eq!(sg.get_edge(ek), None);
+    }
+    assert!(sg.remove_node_detailed(hub).is_none());
+}
+
+#[test]
+fn node_versions() {
+    let mut sg = SlotGraph::<_, _, ()>::new();
+    let (old_key, old_version) = sg.insert_node_versioned("old");
+    assert_eq!(sg.node_version(old_key), Some(old_version));
+    sg.remove_node(old_key);
+    assert_eq!(sg.node_version(old_key), None);
+
+    let (new_key, new_version) = sg.insert_node_versioned("new");
+    // the slot is reused with a different version
+    assert_eq!(new_key.as_ffi() as u32, old_key.as_ffi() as u32);
+    assert_ne!(new_version, old_version);
+    assert_ne!(new_key, old_key);
+    assert_eq!(sg.get_node(old_key), None);
+    assert_eq!(sg.get_node(new_key), Some(&"new"));
+    assert_eq!(sg.node_version(new_key), Some(new_version));
+}
+
+#[test]
+fn get_nodes() {
+    let mut sg = SlotGraph::<_, _, ()>::new();
+    let n1 = sg.insert_node("n1");
+    let n2 = sg.insert_node("n2");
+    let n3 = sg.insert_node("n3");
+    sg.remove_node(n2);
+    assert_eq!(
+        sg.get_nodes([n3, n2, n1, n3]),
+        vec![Some(&"n3"), None, Some(&"n1"), Some(&"n3")]
+    );
+    assert!(sg.get_nodes([]).is_empty());
+}
+
+#[test]
+fn try_insert() {
+    // filling a graph to its limit takes tens of gigabytes, so only the limit itself and the success path are tested
+    assert_eq!(
+        SlotGraph::<slotmap::DefaultKey, (), ()>::MAX_NODES,
+        (1 << 32) - 2
+    );
+    assert_eq!(
+        SlotGraph::<slotmap::DefaultKey, (), ()>::MAX_EDGES,
+        (1 << 32) - 2
+    );
+
+    let mut sg = SlotGraph::new();
+    let n1 = sg.try_insert_node("n1").unwrap();
+    let n2 = sg.try_insert_node("n2").unwrap();
+    let e1 = sg.try_insert_edge(n1, n2, "e1").unwrap();
+    assert_eq!(sg.get_node(n1), Some(&"n1"));
+    assert_eq!(sg.get_edge(e1), Some(&"e1"));
+    assert_eq!(sg.get_edge_nodes(e1), Some((n1, n2)));
+}
+
+#[test]
+fn accumulate() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node(0);
+    let n2 = sg.insert_node(0);
+    let n3 = sg.insert_node(0);
+    sg.insert_edge(n1, n2, 5);
+    sg.insert_edge(n1, n3, 7);
+    sg.insert_edge(n2, n3, 11);
+    sg.insert_edge(n3, n3, 13);
+
+    let mut expected = slotmap::SecondaryMap::new();
+    for (_, to, _, &weight) in sg
+        .iter_edges_full()
+        .map(|(k, from, to, w)| (k, to, from, w))
+    {
+        *expected.entry(to).unwrap().or_insert(0) += weight;
+    }
+    sg.accumulate_in(|node, &weight| *node += weight);
+    for (nk, &value) in sg.iter_nodes() {
+        assert_eq!(value, expected.get(nk).copied().unwrap_or(0));
+    }
+    assert_eq!(sg.get_node(n3), Some(&31));
+
+    sg.iter_nodes_mut().for_each(|(_, value)| *value = 0);
+    sg.accumulate_out(|node, &weight| *node += weight);
+    assert_eq!(sg.get_node(n1), Some(&12));
+    assert_eq!(sg.get_node(n2), Some(&11));
+    assert_eq!(sg.get_node(n3), Some(&13));
+}
+
+#[test]
+fn remove_node_removes_incident_edges() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node("n1");
+    let n2 = sg.insert_node("n2");
+    let n3 = sg.insert_node("n3");
+    let e1 = sg.insert_edge(n1, n2, "e1");
+    let e2 = sg.insert_edge(n2, n3, "e2");
+    let e3 = sg.insert_edge(n3, n2, "e3");
+    let e4 = sg.insert_edge(n2, n2, "e4");
+    let e5 = sg.insert_edge(n1, n3, "e5");
+
+    assert_eq!(sg.remove_node(n2), Some("n2"));
+    assert_eq!(sg.edge_len(), 1);
+    for ek in [e1, e2, e3, e4] {
+        assert_eq!(sg.get_edge(ek), None);
+        assert_eq!(sg.get_edge_nodes(ek), None);
+    }
+    assert_eq!(sg.get_edge_nodes(e5), Some((n1, n3)));
+    for (_, (from, to)) in sg.iter_edge_nodes() {
+        assert!(sg.get_node(from).is_some());
+        assert!(sg.get_node(to).is_some());
+    }
+}
+
+#[test]
+fn retain_nodes() {
+    let mut sg = SlotGraph::new();
+    let nodes: Vec<_> = (0..6).map(|i| sg.insert_node(i)).collect();
+    for i in 0..6 {
+        sg.insert_edge(nodes[i], nodes[(i + 1) % 6], i);
+    }
+    let chord = sg.insert_edge(nodes[0], nodes[2], 10);
+
+    sg.retain_nodes(|_, value| {
+        *value *= 10;
+        *value % 20 == 0
+    });
+    assert_eq!(sg.node_len(), 3);
+    assert_eq!(sg.get_node(nodes[2]), Some(&20));
+    assert_eq!(sg.get_node(nodes[1]), None);
+    assert_eq!(sg.edge_len(), 1);
+    assert_eq!(sg.get_edge(chord), Some(&10));
+    assert_eq!(sg.successors(nodes[0]).collect::<Vec<_>>(), vec![nodes[2]]);
+    assert_eq!(sg.predecessors(nodes[4]).count(), 0);
+    assert_eq!(sg.in_degree(nodes[2]), Some(1));
+}
+
+#[test]
+fn node_entry() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(1);
+    let b = sg.insert_node(2);
+    sg.insert_edge(a, b, ());
+
+    let (key, value) = sg.node_entry(a).and_modify(|v| *v += 10).or_insert(0);
+    assert_eq!(key, a);
+    *value += 100;
+    assert_eq!(sg.get_node(a), Some(&111));
+
+    match sg.node_entry(b) {
+        NodeEntry::Occupied(mut entry) => {
+            assert_eq!(entry.insert(20), 2);
+            assert_eq!(*entry.get(), 20);
+            let removed = entry.remove_detailed();
+            assert_eq!((removed.value, removed.edges.len()), (20, 1));
+        }
+        NodeEntry::Vacant(_) => unreachable!(),
+    }
+    assert_eq!(sg.edge_len(), 0);
+
+    // a stale key gets a new node under a fresh key
+    let entry = sg.node_entry(b);
+    assert_eq!(entry.key(), b);
+    let NodeEntry::Vacant(entry) = entry else {
+        unreachable!()
+    };
+    let (c, value) = entry.insert(3);
+    *value += 1;
+    assert_ne!(c, b);
+    assert_eq!(sg.get_node(c), Some(&4));
+    let (d, value) = sg.node_entry(b).or_insert_with(|| 5);
+    assert_eq!(*value, 5);
+    assert!(d != b && d != c);
+    assert_eq!(sg.get_node(d), Some(&5));
+    assert_eq!(sg.node_len(), 3);
+}
+
+#[test]
+fn get_disjoint_node_mut() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(10);
+    let b = sg.insert_node(20);
+    let e = sg.insert_edge(a, b, 5);
+    let f = sg.insert_edge(b, a, 7);
+
+    // move weight along an edge, mutating both endpoints
+    let (from, to) = sg.get_edge_nodes(e).unwrap();
+    let [source, target] = sg.get_disjoint_node_mut([from, to]).unwrap();
+    *source -= 5;
+    *target += 5;
+    assert_eq!(sg.get_nodes([a, b]), vec![Some(&5), Some(&25)]);
+    assert!(sg.get_disjoint_node_mut([a, a]).is_none());
+
+    let [x, y] = sg.get_disjoint_edge_mut([e, f]).unwrap();
+    std::mem::swap(x, y);
+    assert_eq!(sg.get_edge(e), Some(&7));
+    sg.remove_node(b);
+    assert!(sg.get_disjoint_node_mut([a, b]).is_none());
+    assert!(sg.get_disjoint_edge_mut([e]).is_none());
+}
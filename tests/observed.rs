@@ -0,0 +1,69 @@
+use slotgraph::{
+    slotgraph::{EdgeKey, NodeKey},
+    ObservedSlotGraph, SlotGraph, SlotGraphObserver,
+};
+use slotmap::{DefaultKey, Key};
+
+#[derive(Default)]
+struct Counter {
+    nodes: usize,
+    edges: usize,
+    removed_edge_values: Vec<&'static str>,
+}
+
+impl<K: Key, N> SlotGraphObserver<K, N, &'static str> for Counter {
+    fn on_insert_node(&mut self, graph: &SlotGraph<K, N, &'static str>, key: NodeKey<K>) {
+        assert!(graph.get_node(key).is_some());
+        self.nodes += 1;
+    }
+
+    fn on_remove_node(
+        &mut self,
+        graph: &SlotGraph<K, N, &'static str>,
+        key: NodeKey<K>,
+        _value: &N,
+    ) {
+        assert!(graph.get_node(key).is_none());
+        self.nodes -= 1;
+    }
+
+    fn on_insert_edge(&mut self, graph: &SlotGraph<K, N, &'static str>, key: EdgeKey<K>) {
+        assert!(graph.get_edge(key).is_some());
+        self.edges += 1;
+    }
+
+    fn on_remove_edge(
+        &mut self,
+        graph: &SlotGraph<K, N, &'static str>,
+        key: EdgeKey<K>,
+        _nodes: (NodeKey<K>, NodeKey<K>),
+        value: &&'static str,
+    ) {
+        assert!(graph.get_edge(key).is_none());
+        self.edges -= 1;
+        self.removed_edge_values.push(value);
+    }
+}
+
+#[test]
+fn observer_counts() {
+    let mut og = ObservedSlotGraph::<DefaultKey, (), _, _>::new(Counter::default());
+    let n1 = og.insert_node(());
+    let n2 = og.insert_node(());
+    let n3 = og.insert_node(());
+    let e1 = og.insert_edge(n1, n2, "e1");
+    og.insert_edge(n2, n3, "e2");
+    og.insert_edge(n3, n1, "e3");
+    assert_eq!(og.remove_edge(e1), Some("e1"));
+    assert_eq!(og.remove_edge(e1), None);
+    assert_eq!(og.remove_node(n1), Some(()));
+    assert_eq!(og.remove_node(n1), None);
+
+    // removing `n1` also removed `e3`
+    let counter = og.observer();
+    assert_eq!(counter.nodes, 2);
+    assert_eq!(counter.edges, 1);
+    assert_eq!(counter.removed_edge_values, vec!["e1", "e3"]);
+    assert_eq!(og.graph().node_len(), counter.nodes);
+    assert_eq!(og.graph().edge_len(), counter.edges);
+}
@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use slotgraph::{algo::toposort, SlotGraph};
+
+#[test]
+fn resolution_order_diamond() {
+    let mut sg = SlotGraph::new();
+    let top = sg.insert_node(());
+    let left = sg.insert_node(());
+    let right = sg.insert_node(());
+    let bottom = sg.insert_node(());
+    let extra = sg.insert_node(());
+    sg.insert_edge(top, left, ());
+    sg.insert_edge(top, right, ());
+    sg.insert_edge(left, bottom, ());
+    sg.insert_edge(right, bottom, ());
+    sg.insert_edge(top, bottom, ());
+
+    let batches = sg.resolution_order().unwrap();
+    let batches: Vec<HashSet<_>> = batches
+        .into_iter()
+        .map(|b| b.into_iter().collect())
+        .collect();
+    assert_eq!(
+        batches,
+        vec![
+            HashSet::from([top, extra]),
+            HashSet::from([left, right]),
+            HashSet::from([bottom]),
+        ]
+    );
+}
+
+#[test]
+fn resolution_order_cycle() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let d = sg.insert_node(());
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(b, c, ());
+    sg.insert_edge(c, b, ());
+    sg.insert_edge(c, d, ());
+
+    let unresolved: HashSet<_> = sg.resolution_order().unwrap_err().into_iter().collect();
+    assert_eq!(unresolved, HashSet::from([b, c, d]));
+}
+
+#[test]
+fn dependency_depth() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let d = sg.insert_node(());
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(b, c, ());
+    sg.insert_edge(a, c, ());
+    sg.insert_edge(d, c, ());
+
+    let depth = sg.dependency_depth().unwrap();
+    assert_eq!(depth[a], 0);
+    assert_eq!(depth[d], 0);
+    assert_eq!(depth[b], 1);
+    assert_eq!(depth[c], 2);
+
+    sg.insert_edge(c, a, ());
+    let unresolved: HashSet<_> = sg.dependency_depth().unwrap_err().into_iter().collect();
+    assert_eq!(unresolved, HashSet::from([a, b, c]));
+}
+
+#[test]
+fn toposort_order() {
+    let mut sg = SlotGraph::new();
+    let shirt = sg.insert_node("shirt");
+    let tie = sg.insert_node("tie");
+    let socks = sg.insert_node("socks");
+    let jacket = sg.insert_node("jacket");
+    sg.insert_edge(shirt, tie, ());
+    sg.insert_edge(tie, jacket, ());
+    sg.insert_edge(shirt, jacket, ());
+
+    assert_eq!(toposort(&sg), Ok(vec![shirt, socks, tie, jacket]));
+}
+
+#[test]
+fn toposort_cycle() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let d = sg.insert_node(());
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(b, c, ());
+    sg.insert_edge(c, b, ());
+    sg.insert_edge(c, d, ());
+
+    let cycle = toposort(&sg).unwrap_err();
+    assert!([b, c].contains(&cycle.node_id()));
+
+    sg.remove_node(c);
+    sg.insert_edge(d, d, ());
+    assert_eq!(toposort(&sg).unwrap_err().node_id(), d);
+}
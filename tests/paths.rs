@@ -0,0 +1,27 @@
+use slotgraph::SlotGraph;
+
+#[test]
+fn shortest_path_edges_multigraph() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node("n1");
+    let n2 = sg.insert_node("n2");
+    let n3 = sg.insert_node("n3");
+    let n4 = sg.insert_node("n4");
+    sg.insert_edge(n1, n2, "a");
+    sg.insert_edge(n1, n2, "b");
+    sg.insert_edge(n2, n3, "c");
+    sg.insert_edge(n2, n3, "d");
+    sg.insert_edge(n3, n4, "e");
+    sg.insert_edge(n1, n3, "f");
+
+    let nodes = sg.shortest_path(n1, n4).unwrap();
+    let edges = sg.shortest_path_edges(n1, n4).unwrap();
+    assert_eq!(nodes, vec![n1, n3, n4]);
+    assert_eq!(edges.len(), nodes.len() - 1);
+    for (ek, pair) in edges.iter().zip(nodes.windows(2)) {
+        assert_eq!(sg.get_edge_nodes(*ek), Some((pair[0], pair[1])));
+    }
+
+    assert_eq!(sg.shortest_path_edges(n1, n1), Some(vec![]));
+    assert_eq!(sg.shortest_path_edges(n4, n1), None);
+}
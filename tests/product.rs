@@ -0,0 +1,84 @@
+use slotgraph::{
+    algo::is_isomorphic,
+    generate,
+    product::{cartesian_product, tensor_product},
+    SlotGraph, Undirected,
+};
+
+#[test]
+fn cartesian_product_of_paths_is_grid() {
+    let (a, an) = generate::path::<Undirected>(3);
+    let (b, bn) = generate::path::<Undirected>(2);
+    let (grid, nodes) = cartesian_product(&a, &b, |_, _| ());
+    assert_eq!((grid.node_len(), grid.edge_len()), (6, 7));
+    let (expected, _) = generate::grid::<Undirected>(2, 3);
+    assert!(is_isomorphic(&grid, &expected));
+    assert_eq!(grid.neighbors(nodes[&(an[1], bn[0])]).count(), 3);
+}
+
+#[test]
+fn cartesian_product_values() {
+    // a two-state machine and a toggle, composed into their joint state space
+    let mut machine = SlotGraph::new();
+    let idle = machine.insert_node("idle");
+    let busy = machine.insert_node("busy");
+    machine.insert_edge(idle, busy, "start");
+    let mut toggle = SlotGraph::new();
+    let off = toggle.insert_node(false);
+    let on = toggle.insert_node(true);
+    toggle.insert_edge(off, on, "flip");
+    toggle.insert_edge(on, off, "flip");
+
+    let (states, nodes) = cartesian_product(&machine, &toggle, |&m, &t| (m, t));
+    assert_eq!((states.node_len(), states.edge_len()), (4, 6));
+    assert_eq!(states.get_node(nodes[&(busy, on)]), Some(&("busy", true)));
+    let starts: Vec<_> = states
+        .iter_edges_full()
+        .filter(|&(_, _, _, &value)| value == "start")
+        .map(|(_, from, to, _)| (from, to))
+        .collect();
+    assert_eq!(
+        starts,
+        vec![
+            (nodes[&(idle, off)], nodes[&(busy, off)]),
+            (nodes[&(idle, on)], nodes[&(busy, on)])
+        ]
+    );
+}
+
+#[test]
+fn tensor_product_edges() {
+    let mut a = SlotGraph::new();
+    let a1 = a.insert_node(1);
+    let a2 = a.insert_node(2);
+    a.insert_edge(a1, a2, 10);
+    a.insert_edge(a2, a2, 20);
+    let mut b = SlotGraph::new();
+    let b1 = b.insert_node(3);
+    let b2 = b.insert_node(4);
+    b.insert_edge(b1, b2, 1);
+
+    let (product, nodes) = tensor_product(&a, &b, |x, y| x * y, |x, y| x + y);
+    assert_eq!((product.node_len(), product.edge_len()), (4, 2));
+    assert_eq!(product.get_node(nodes[&(a2, b2)]), Some(&8));
+    let mut edges: Vec<_> = product
+        .iter_edges_full()
+        .map(|(_, from, to, &value)| (from, to, value))
+        .collect();
+    edges.sort_by_key(|&(_, _, value)| value);
+    assert_eq!(
+        edges,
+        vec![
+            (nodes[&(a1, b1)], nodes[&(a2, b2)], 11),
+            (nodes[&(a2, b1)], nodes[&(a2, b2)], 21)
+        ]
+    );
+
+    // two undirected edges give two crossing edges
+    let (k2, _) = generate::path::<Undirected>(2);
+    let (product, _) = tensor_product(&k2, &k2, |_, _| (), |_, _| ());
+    assert_eq!(product.edge_len(), 2);
+    assert!(product
+        .iter_nodes()
+        .all(|(nk, _)| product.neighbors(nk).count() == 1));
+}
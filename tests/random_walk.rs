@@ -0,0 +1,35 @@
+#![cfg(feature = "rand")]
+
+use rand::{rngs::StdRng, SeedableRng};
+use slotgraph::SlotGraph;
+
+#[test]
+fn random_walk_forced_path() {
+    let mut sg = SlotGraph::new();
+    let nodes: Vec<_> = (0..4).map(|i| sg.insert_node(i)).collect();
+    for pair in nodes.windows(2) {
+        sg.insert_edge(pair[0], pair[1], 1.0);
+    }
+    let mut rng = StdRng::seed_from_u64(7);
+
+    let walk = sg.random_walk(nodes[0], 10, |_, &w| w, &mut rng);
+    assert_eq!(walk, nodes);
+    let walk = sg.random_walk(nodes[0], 2, |_, &w| w, &mut rng);
+    assert_eq!(walk, nodes[..3]);
+}
+
+#[test]
+fn random_walk_weights() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    sg.insert_edge(a, b, 0.0);
+    sg.insert_edge(a, c, 2.0);
+    sg.insert_edge(c, a, f64::NAN);
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for _ in 0..20 {
+        assert_eq!(sg.random_walk(a, 5, |_, &w| w, &mut rng), vec![a, c]);
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use slotgraph::SlotGraph;
+
+#[test]
+fn can_reach_chain() {
+    let mut sg = SlotGraph::new();
+    let chain: Vec<_> = (0..5).map(|i| sg.insert_node(i)).collect();
+    for pair in chain.windows(2) {
+        sg.insert_edge(pair[0], pair[1], ());
+    }
+    let other = sg.insert_node(5);
+    sg.insert_edge(chain[4], other, ());
+
+    let tail = chain[4];
+    let expected: HashSet<_> = chain[..4].iter().copied().collect();
+    assert_eq!(sg.can_reach(tail), expected);
+    assert!(sg.can_reach(chain[0]).is_empty());
+
+    let expected: HashSet<_> = chain[1..].iter().copied().chain([other]).collect();
+    assert_eq!(sg.descendants(chain[0]), expected);
+}
+
+#[test]
+fn can_reach_cycle() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(b, a, ());
+    assert_eq!(sg.can_reach(a), HashSet::from([a, b]));
+}
+
+#[test]
+fn split_off_unreachable() {
+    let mut sg = SlotGraph::new();
+    let root = sg.insert_node("root");
+    let child = sg.insert_node("child");
+    let orphan = sg.insert_node("orphan");
+    let orphan_child = sg.insert_node("orphan_child");
+    let kept_edge = sg.insert_edge(root, child, "kept");
+    let cross_edge = sg.insert_edge(orphan, child, "cross");
+    let removed_edge = sg.insert_edge(orphan, orphan_child, "removed");
+    let original: HashSet<_> = sg.iter_nodes().map(|(k, _)| k).collect();
+
+    let removed = sg.split_off_unreachable(&[root]);
+
+    let kept: HashSet<_> = sg.iter_nodes().map(|(k, _)| k).collect();
+    let split: HashSet<_> = removed.iter_nodes().map(|(k, _)| k).collect();
+    assert_eq!(kept, HashSet::from([root, child]));
+    assert_eq!(split, HashSet::from([orphan, orphan_child]));
+    assert!(kept.is_disjoint(&split));
+    assert_eq!(&kept | &split, original);
+
+    assert_eq!(removed.get_node(orphan), Some(&"orphan"));
+    assert_eq!(removed.get_edge(removed_edge), Some(&"removed"));
+    assert_eq!(
+        removed.get_edge_nodes(removed_edge),
+        Some((orphan, orphan_child))
+    );
+    assert_eq!(removed.edge_len(), 1);
+    assert_eq!(sg.get_edge(kept_edge), Some(&"kept"));
+    assert_eq!(sg.get_edge(cross_edge), None);
+    assert_eq!(sg.edge_len(), 1);
+}
+
+#[test]
+fn in_same_scc() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let tail = sg.insert_node(());
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(b, c, ());
+    sg.insert_edge(c, a, ());
+    sg.insert_edge(c, tail, ());
+
+    assert!(sg.in_same_scc(a, c));
+    assert!(sg.in_same_scc(b, a));
+    assert!(!sg.in_same_scc(a, tail));
+    assert!(!sg.in_same_scc(tail, c));
+    assert!(sg.in_same_scc(tail, tail));
+    assert!(sg.has_path(a, tail));
+    assert!(!sg.has_path(tail, a));
+
+    sg.remove_node(tail);
+    assert!(!sg.in_same_scc(tail, tail));
+}
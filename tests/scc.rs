@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use slotgraph::{
+    algo::{condensation, kosaraju_scc, tarjan_scc, tarjan_scc_ids, toposort},
+    SlotGraph,
+};
+
+#[test]
+fn tarjan_scc_reverse_topological() {
+    let mut sg = SlotGraph::new();
+    let nodes: Vec<_> = (0..7).map(|i| sg.insert_node(i)).collect();
+    for (from, to) in [
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (2, 3),
+        (3, 4),
+        (4, 3),
+        (5, 4),
+        (6, 6),
+    ] {
+        sg.insert_edge(nodes[from], nodes[to], ());
+    }
+
+    let components = tarjan_scc(&sg);
+    let sets: Vec<HashSet<_>> = components
+        .iter()
+        .map(|c| c.iter().map(|&nk| *sg.get_node(nk).unwrap()).collect())
+        .collect();
+    assert_eq!(sets.len(), 4);
+    for expected in [vec![0, 1, 2], vec![3, 4], vec![5], vec![6]] {
+        assert!(sets.contains(&expected.into_iter().collect()));
+    }
+
+    let ids = tarjan_scc_ids(&sg);
+    assert_eq!(ids.len(), 7);
+    assert_eq!(ids[nodes[0]], ids[nodes[2]]);
+    assert_ne!(ids[nodes[2]], ids[nodes[3]]);
+    for (_, from, to, _) in sg.iter_edges_full() {
+        assert!(ids[from] >= ids[to]);
+    }
+}
+
+#[test]
+fn kosaraju_scc_through_view() {
+    let mut sg = SlotGraph::new();
+    let nodes: Vec<_> = (0..6).map(|i| sg.insert_node(i)).collect();
+    for (from, to) in [(0, 1), (1, 0), (1, 2), (2, 3), (3, 2), (4, 3), (5, 5)] {
+        sg.insert_edge(nodes[from], nodes[to], ());
+    }
+    let as_sets = |components: &[Vec<_>]| -> Vec<Vec<i32>> {
+        let mut sets: Vec<Vec<_>> = components
+            .iter()
+            .map(|c| c.iter().map(|&nk| *sg.get_node(nk).unwrap()).collect())
+            .collect();
+        sets.iter_mut().for_each(|set| set.sort_unstable());
+        sets.sort_unstable();
+        sets
+    };
+
+    let components = kosaraju_scc(&sg);
+    assert_eq!(as_sets(&components), as_sets(&tarjan_scc(&sg)));
+    // topological order: every edge points to the same or a later component
+    let position = |components: &[Vec<_>], nk| components.iter().position(|c| c.contains(&nk));
+    for (_, from, to, _) in sg.iter_edges_full() {
+        assert!(position(&components, from) <= position(&components, to));
+    }
+
+    // the transposed graph has the same components, in topological order of the reversed edges
+    let transposed = kosaraju_scc(&sg.transpose_view());
+    assert_eq!(as_sets(&transposed), as_sets(&components));
+    for (_, from, to, _) in sg.iter_edges_full() {
+        assert!(position(&transposed, from) >= position(&transposed, to));
+    }
+}
+
+#[test]
+fn tarjan_scc_deep_chain() {
+    let mut sg = SlotGraph::new();
+    let nodes: Vec<_> = (0..100_000).map(|_| sg.insert_node(())).collect();
+    for pair in nodes.windows(2) {
+        sg.insert_edge(pair[0], pair[1], ());
+    }
+    sg.insert_edge(nodes[nodes.len() - 1], nodes[0], ());
+    assert_eq!(tarjan_scc(&sg).len(), 1);
+}
+
+#[test]
+fn condensation_is_acyclic() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let d = sg.insert_node(());
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(b, a, ());
+    let ac = sg.insert_edge(a, c, ());
+    let bc = sg.insert_edge(b, c, ());
+    sg.insert_edge(c, c, ());
+    let cd = sg.insert_edge(c, d, ());
+
+    let condensed = condensation(&sg);
+    assert_eq!(condensed.node_len(), 3);
+    assert_eq!(condensed.edge_len(), 2);
+    let order = toposort(&condensed).unwrap();
+    let members: Vec<HashSet<_>> = order
+        .iter()
+        .map(|&ck| condensed.get_node(ck).unwrap().iter().copied().collect())
+        .collect();
+    assert_eq!(
+        members,
+        vec![
+            HashSet::from([a, b]),
+            HashSet::from([c]),
+            HashSet::from([d])
+        ]
+    );
+    let first = condensed.out_edges(order[0]).next().unwrap();
+    let mut merged = condensed.get_edge(first).unwrap().clone();
+    merged.sort();
+    let mut expected = vec![ac, bc];
+    expected.sort();
+    assert_eq!(merged, expected);
+    let second = condensed.out_edges(order[1]).next().unwrap();
+    assert_eq!(condensed.get_edge(second).unwrap(), &vec![cd]);
+}
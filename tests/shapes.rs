@@ -0,0 +1,47 @@
+use std::collections::HashSet;
+
+use slotgraph::{generate, Directed, Undirected};
+
+#[test]
+fn complete_path_cycle_star() {
+    let (sg, _) = generate::complete::<Directed>(4);
+    assert_eq!((sg.node_len(), sg.edge_len()), (4, 12));
+    let (ug, _) = generate::complete::<Undirected>(4);
+    assert_eq!(ug.edge_len(), 6);
+
+    let (sg, nodes) = generate::path::<Directed>(4);
+    let pairs: Vec<_> = sg.iter_edge_nodes().map(|(_, pair)| pair).collect();
+    assert_eq!(
+        pairs,
+        vec![
+            (nodes[0], nodes[1]),
+            (nodes[1], nodes[2]),
+            (nodes[2], nodes[3])
+        ]
+    );
+    assert_eq!(generate::path::<Directed>(0).0.node_len(), 0);
+
+    let (sg, nodes) = generate::cycle::<Directed>(4);
+    assert_eq!(sg.edge_len(), 4);
+    assert_eq!(sg.successors(nodes[3]).collect::<Vec<_>>(), vec![nodes[0]]);
+    let (sg, nodes) = generate::cycle::<Directed>(1);
+    assert_eq!(sg.successors(nodes[0]).collect::<Vec<_>>(), vec![nodes[0]]);
+
+    let (ug, nodes) = generate::star::<Undirected>(5);
+    assert_eq!(ug.edge_len(), 4);
+    assert_eq!(ug.neighbors(nodes[0]).count(), 4);
+    assert_eq!(ug.neighbors(nodes[3]).collect::<Vec<_>>(), vec![nodes[0]]);
+}
+
+#[test]
+fn grid_layout() {
+    let (ug, nodes) = generate::grid::<Undirected>(3, 2);
+    assert_eq!((ug.node_len(), ug.edge_len()), (6, 7));
+    let at = |x: usize, y: usize| nodes[y * 3 + x];
+    let neighbors: HashSet<_> = ug.neighbors(at(1, 0)).collect();
+    assert_eq!(neighbors, HashSet::from([at(0, 0), at(2, 0), at(1, 1)]));
+    let neighbors: HashSet<_> = ug.neighbors(at(2, 1)).collect();
+    assert_eq!(neighbors, HashSet::from([at(1, 1), at(2, 0)]));
+    assert_eq!(generate::grid::<Directed>(4, 1).0.edge_len(), 3);
+    assert_eq!(generate::grid::<Directed>(0, 5).0.node_len(), 0);
+}
@@ -0,0 +1,142 @@
+use slotgraph::{
+    algo::{astar, bellman_ford, dijkstra},
+    SlotGraph, UnSlotGraph,
+};
+
+#[test]
+fn dijkstra_distances_and_paths() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("b");
+    let c = sg.insert_node("c");
+    let d = sg.insert_node("d");
+    let unreachable = sg.insert_node("e");
+    let ab = sg.insert_edge(a, b, 4u32);
+    sg.insert_edge(a, c, 1);
+    let cb = sg.insert_edge(c, b, 2);
+    let ca = sg.insert_edge(c, a, 1);
+    let bd = sg.insert_edge(b, d, 5);
+    sg.insert_edge(unreachable, a, 1);
+
+    let paths = dijkstra(&sg, a, None, |_, &w| w);
+    assert_eq!(paths.distances[a], 0);
+    assert_eq!(paths.distances[b], 3);
+    assert_eq!(paths.distances[d], 8);
+    assert!(!paths.distances.contains_key(unreachable));
+    assert_eq!(paths.path_to(d), Some(vec![a, c, b, d]));
+    assert_eq!(paths.path_to(a), Some(vec![a]));
+    assert_eq!(paths.path_to(unreachable), None);
+    assert_eq!(paths.path_edges_to(b).unwrap().len(), 2);
+    assert_eq!(paths.path_edges_to(d).unwrap()[1..], [cb, bd]);
+
+    // costs can ignore the edge values
+    let hops = dijkstra(
+        &sg,
+        c,
+        None,
+        |ek, _| if ek == ab || ek == ca { 10 } else { 1 },
+    );
+    assert_eq!(hops.distances[a], 10);
+    assert_eq!(hops.distances[d], 2);
+}
+
+#[test]
+fn dijkstra_goal_and_undirected() {
+    let mut ug = UnSlotGraph::new_undirected();
+    let nodes: Vec<_> = (0..5).map(|i| ug.insert_node(i)).collect();
+    for pair in nodes.windows(2) {
+        ug.insert_edge(pair[0], pair[1], 1);
+    }
+
+    let paths = dijkstra(&ug, nodes[4], Some(nodes[2]), |_, &w| w);
+    assert_eq!(paths.distances[nodes[2]], 2);
+    assert_eq!(
+        paths.path_to(nodes[2]),
+        Some(vec![nodes[4], nodes[3], nodes[2]])
+    );
+    assert!(!paths.distances.contains_key(nodes[0]));
+
+    ug.remove_node(nodes[0]);
+    assert!(dijkstra(&ug, nodes[0], None, |_, &w| w)
+        .distances
+        .is_empty());
+}
+
+#[test]
+fn astar_grid() {
+    // a 5x5 grid with a wall in the middle column, open only at the bottom
+    let mut ug = UnSlotGraph::new_undirected();
+    let mut cells = Vec::new();
+    for y in 0..5i32 {
+        for x in 0..5i32 {
+            cells.push(ug.insert_node((x, y)));
+        }
+    }
+    for y in 0..5 {
+        for x in 0..5 {
+            let wall = |x: usize, y: usize| x == 2 && y < 4;
+            if wall(x, y) {
+                continue;
+            }
+            if x < 4 && !wall(x + 1, y) {
+                ug.insert_edge(cells[y * 5 + x], cells[y * 5 + x + 1], ());
+            }
+            if y < 4 && !wall(x, y + 1) {
+                ug.insert_edge(cells[y * 5 + x], cells[(y + 1) * 5 + x], ());
+            }
+        }
+    }
+
+    let goal = cells[4];
+    let (gx, gy) = (4, 0);
+    let (cost, path) = astar(
+        &ug,
+        cells[0],
+        |nk| nk == goal,
+        |_, _| 1,
+        |nk| {
+            let &(x, y) = ug.get_node(nk).unwrap();
+            (x - gx).abs() + (y - gy).abs()
+        },
+    )
+    .unwrap();
+    assert_eq!(cost, 12);
+    assert_eq!(path.len(), 13);
+    assert_eq!((path[0], path[12]), (cells[0], goal));
+    assert!(path.contains(&cells[22]));
+
+    assert_eq!(astar(&ug, cells[0], |_| false, |_, _| 1, |_| 0), None);
+}
+
+#[test]
+fn bellman_ford_negative_edges() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let d = sg.insert_node(());
+    sg.insert_edge(a, b, 4);
+    sg.insert_edge(a, c, 2);
+    sg.insert_edge(b, c, -3);
+    sg.insert_edge(c, d, 2);
+
+    let paths = bellman_ford(&sg, a, |_, &w| w).unwrap();
+    assert_eq!(paths.distances[c], 1);
+    assert_eq!(paths.distances[d], 3);
+    assert_eq!(paths.path_to(d), Some(vec![a, b, c, d]));
+
+    // a negative cycle not reachable from the start does not matter
+    let e = sg.insert_node(());
+    sg.insert_edge(e, e, -1);
+    assert!(bellman_ford(&sg, a, |_, &w| w).is_ok());
+
+    sg.insert_edge(d, b, -1);
+    let cycle = bellman_ford(&sg, a, |_, &w| w).unwrap_err();
+    let nodes = cycle.nodes();
+    assert_eq!(nodes.len(), 3);
+    let first = nodes.iter().position(|&nk| nk == b).unwrap();
+    assert_eq!(nodes[(first + 1) % 3], c);
+    assert_eq!(nodes[(first + 2) % 3], d);
+
+    assert_eq!(bellman_ford(&sg, e, |_, &w| w).unwrap_err().nodes(), [e]);
+}
@@ -10,3 +10,90 @@ fn edge_iter() {
     assert_eq!(edge_iter.next(), Some((e1, &"e1")));
     assert_eq!(edge_iter.next(), None);
 }
+
+#[test]
+fn edge_iter_full() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node("n1");
+    let n2 = sg.insert_node("n2");
+    sg.insert_edge(n1, n2, "e1");
+    sg.insert_edge(n2, n1, "e2");
+    sg.insert_edge(n2, n2, "e3");
+    assert_eq!(sg.iter_edges_full().count(), 3);
+    for (ek, from, to, value) in sg.iter_edges_full() {
+        assert_eq!(sg.get_edge_nodes(ek), Some((from, to)));
+        assert_eq!(sg.get_edge(ek), Some(value));
+    }
+}
+
+#[test]
+fn edge_iter_full_mut() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node(());
+    let n2 = sg.insert_node(());
+    let e1 = sg.insert_edge(n1, n2, 1);
+    for (_ek, from, to, value) in sg.iter_edges_full_mut() {
+        assert_eq!((from, to), (n1, n2));
+        *value += 1;
+    }
+    assert_eq!(sg.get_edge(e1), Some(&2));
+}
+
+#[test]
+fn index_edges() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node(());
+    let keys: Vec<_> = (0..5).map(|i| sg.insert_edge(n1, n1, i)).collect();
+    sg.remove_edge(keys[3]);
+    let (forward, reverse) = sg.index_edges();
+    assert_eq!(reverse.len(), sg.edge_len());
+    for (key, _) in sg.iter_edges() {
+        assert_eq!(reverse[forward[key]], key);
+    }
+}
+
+#[test]
+fn reweight_edges() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node(());
+    let n2 = sg.insert_node(());
+    let n3 = sg.insert_node(());
+    let e1 = sg.insert_edge(n1, n2, 0);
+    let e2 = sg.insert_edge(n1, n3, 0);
+    let e3 = sg.insert_edge(n2, n3, 0);
+    let e4 = sg.insert_edge(n3, n3, 0);
+
+    let mut out_degree = slotmap::SecondaryMap::new();
+    for (_, (from, _)) in sg.iter_edge_nodes() {
+        *out_degree.entry(from).unwrap().or_insert(0) += 1;
+    }
+    sg.reweight_edges(|_, from, _, value| *value = out_degree[from]);
+
+    assert_eq!(sg.get_edge(e1), Some(&2));
+    assert_eq!(sg.get_edge(e2), Some(&2));
+    assert_eq!(sg.get_edge(e3), Some(&1));
+    assert_eq!(sg.get_edge(e4), Some(&1));
+}
+
+#[test]
+fn remove_edges_where() {
+    let mut sg = SlotGraph::new();
+    let n1 = sg.insert_node(());
+    let n2 = sg.insert_node(());
+    let n3 = sg.insert_node(());
+    let e1 = sg.insert_edge(n1, n2, "e1");
+    let e2 = sg.insert_edge(n1, n3, "e2");
+    let e3 = sg.insert_edge(n2, n1, "e3");
+    let e4 = sg.insert_edge(n3, n3, "e4");
+
+    let mut removed = sg.remove_edges_where(|_, from, _, _| from == n1);
+    removed.sort();
+    let mut expected = vec![(e1, "e1"), (e2, "e2")];
+    expected.sort();
+    assert_eq!(removed, expected);
+    assert_eq!(sg.edge_len(), 2);
+    assert_eq!(sg.get_edge(e1), None);
+    assert_eq!(sg.get_edge(e3), Some(&"e3"));
+    assert_eq!(sg.get_edge(e4), Some(&"e4"));
+    assert_eq!(sg.iter_edges().count(), 2);
+}
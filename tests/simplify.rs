@@ -0,0 +1,41 @@
+use slotgraph::SlotGraph;
+
+#[test]
+fn simplify_long_chain() {
+    let mut sg = SlotGraph::new();
+    let nodes: Vec<_> = (0..6).map(|i| sg.insert_node(i.to_string())).collect();
+    for (i, pair) in nodes.windows(2).enumerate() {
+        sg.insert_edge(pair[0], pair[1], format!("e{i}"));
+    }
+
+    sg.simplify_chains(|a, n, b| format!("{a}-{n}-{b}"));
+    assert_eq!(sg.node_len(), 2);
+    assert_eq!(sg.edge_len(), 1);
+    let (_, from, to, value) = sg.iter_edges_full().next().unwrap();
+    assert_eq!((from, to), (nodes[0], nodes[5]));
+    assert_eq!(value, "e0-1-e1-2-e2-3-e3-4-e4");
+}
+
+#[test]
+fn simplify_keeps_branches_and_self_loops() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(1);
+    let m = sg.insert_node(10);
+    let b = sg.insert_node(1);
+    let c = sg.insert_node(1);
+    let looped = sg.insert_node(100);
+    sg.insert_edge(a, m, 1);
+    sg.insert_edge(m, b, 1);
+    sg.insert_edge(b, c, 1);
+    sg.insert_edge(b, looped, 1);
+    sg.insert_edge(looped, looped, 1);
+
+    sg.simplify_chains(|x, n, y| x + n + y);
+    // only `m` is collapsed: `b` branches and `looped` has a self-loop
+    assert_eq!(sg.get_node(m), None);
+    assert_eq!(sg.node_len(), 4);
+    assert_eq!(sg.edge_len(), 4);
+    assert!(sg
+        .iter_edges_full()
+        .any(|(_, from, to, &value)| (from, to, value) == (a, b, 12)));
+}
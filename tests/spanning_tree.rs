@@ -0,0 +1,71 @@
+use slotgraph::{
+    algo::{min_spanning_tree, min_spanning_tree_prim},
+    UnSlotGraph,
+};
+
+#[test]
+fn min_spanning_forest() {
+    let mut ug = UnSlotGraph::new_undirected();
+    let a = ug.insert_node("a");
+    let b = ug.insert_node("b");
+    let c = ug.insert_node("c");
+    let d = ug.insert_node("d");
+    let x = ug.insert_node("x");
+    let y = ug.insert_node("y");
+    let ab = ug.insert_edge(a, b, 1);
+    let bc = ug.insert_edge(b, c, 2);
+    ug.insert_edge(a, c, 3);
+    ug.insert_edge(c, d, 7);
+    let bd = ug.insert_edge(d, b, 4);
+    ug.insert_edge(a, a, 0);
+    ug.insert_edge(x, y, 5);
+    let xy = ug.insert_edge(y, x, 1);
+
+    let tree = min_spanning_tree(&ug, |_, &w| w);
+    assert_eq!(tree, vec![ab, xy, bc, bd]);
+    let total: i32 = tree.iter().map(|&ek| ug.get_edge(ek).unwrap()).sum();
+    assert_eq!(total, 8);
+}
+
+#[test]
+fn min_spanning_forest_prim() {
+    let mut ug = UnSlotGraph::new_undirected();
+    let a = ug.insert_node("a");
+    let b = ug.insert_node("b");
+    let c = ug.insert_node("c");
+    let d = ug.insert_node("d");
+    let x = ug.insert_node("x");
+    let y = ug.insert_node("y");
+    let ab = ug.insert_edge(a, b, 1);
+    let bc = ug.insert_edge(b, c, 2);
+    ug.insert_edge(a, c, 3);
+    ug.insert_edge(c, d, 7);
+    let bd = ug.insert_edge(d, b, 4);
+    ug.insert_edge(a, a, 0);
+    ug.insert_edge(x, y, 5);
+    let xy = ug.insert_edge(y, x, 1);
+
+    let mut weighed = 0;
+    let tree = min_spanning_tree_prim(&ug, |_, &w| {
+        weighed += 1;
+        w
+    });
+    assert_eq!(tree, vec![ab, bc, bd, xy]);
+    assert!(weighed <= ug.edge_len());
+}
+
+#[test]
+fn min_spanning_tree_float_weights() {
+    // road lengths in kilometres, with an unknown length that must not be chosen
+    let mut ug = UnSlotGraph::new_undirected();
+    let a = ug.insert_node(());
+    let b = ug.insert_node(());
+    let c = ug.insert_node(());
+    let ab = ug.insert_edge(a, b, 2.5);
+    ug.insert_edge(b, c, f64::NAN);
+    let ac = ug.insert_edge(a, c, 4.25);
+    ug.insert_edge(c, b, 9.0);
+
+    assert_eq!(min_spanning_tree(&ug, |_, &w| w), vec![ab, ac]);
+    assert_eq!(min_spanning_tree_prim(&ug, |_, &w| w), vec![ab, ac]);
+}
@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+
+use slotgraph::SlotGraph;
+
+#[test]
+fn transpose_view() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("b");
+    let c = sg.insert_node("c");
+    let e1 = sg.insert_edge(a, b, "e1");
+    let e2 = sg.insert_edge(a, c, "e2");
+    let e3 = sg.insert_edge(c, b, "e3");
+
+    let view = sg.transpose_view();
+    for n in [a, b, c] {
+        let successors: HashSet<_> = view.successors(n).collect();
+        let predecessors: HashSet<_> = view.predecessors(n).collect();
+        assert_eq!(successors, sg.predecessors(n).collect());
+        assert_eq!(predecessors, sg.successors(n).collect());
+        let out_edges: HashSet<_> = view.out_edges(n).collect();
+        assert_eq!(out_edges, sg.in_edges(n).collect());
+    }
+    assert_eq!(
+        view.successors(b).collect::<HashSet<_>>(),
+        HashSet::from([a, c])
+    );
+    assert_eq!(
+        view.out_edges(b).collect::<HashSet<_>>(),
+        HashSet::from([e1, e3])
+    );
+    assert_eq!(view.in_edges(c).collect::<Vec<_>>(), vec![e3]);
+    assert_eq!(view.get_edge_nodes(e2), Some((c, a)));
+    assert_eq!(view.get_edge(e2), Some(&"e2"));
+    assert_eq!(view.get_node(a), Some(&"a"));
+    assert_eq!(view.edge_len(), 3);
+}
+
+#[test]
+fn reverse_in_place() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("b");
+    let c = sg.insert_node("c");
+    let e1 = sg.insert_edge(a, b, "e1");
+    let e2 = sg.insert_edge(a, c, "e2");
+    let e3 = sg.insert_edge(c, c, "e3");
+    let original = sg.clone();
+
+    sg.reverse();
+    assert_eq!(sg.get_edge_nodes(e1), Some((b, a)));
+    assert_eq!(sg.get_edge_nodes(e3), Some((c, c)));
+    assert_eq!(sg.get_edge(e2), Some(&"e2"));
+    let view = original.transpose_view();
+    for n in [a, b, c] {
+        assert_eq!(
+            sg.successors(n).collect::<Vec<_>>(),
+            view.successors(n).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            sg.in_edges(n).collect::<Vec<_>>(),
+            original.out_edges(n).collect::<Vec<_>>()
+        );
+    }
+
+    sg.reverse();
+    assert_eq!(sg.get_edge_nodes(e1), Some((a, b)));
+    assert_eq!(sg.successors(a).collect::<Vec<_>>(), vec![b, c]);
+}
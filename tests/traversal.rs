@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use slotgraph::{
+    algo::{Bfs, Dfs, DfsPostOrder},
+    SlotGraph, UnSlotGraph,
+};
+
+#[test]
+fn for_each_edge_bfs_levels() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(0);
+    let b = sg.insert_node(1);
+    let c = sg.insert_node(1);
+    let d = sg.insert_node(2);
+    let e = sg.insert_node(2);
+    let unreachable = sg.insert_node(0);
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(a, c, ());
+    sg.insert_edge(c, e, ());
+    sg.insert_edge(d, a, ());
+    sg.insert_edge(b, d, ());
+    sg.insert_edge(c, b, ());
+    sg.insert_edge(e, e, ());
+    sg.insert_edge(unreachable, a, ());
+
+    let mut order = Vec::new();
+    sg.for_each_edge_bfs(a, |ek, from, to| order.push((ek, from, to)));
+
+    assert_eq!(order.len(), 7);
+    let mut seen = HashMap::new();
+    for &(ek, from, to) in &order {
+        assert_eq!(sg.get_edge_nodes(ek), Some((from, to)));
+        assert!(seen.insert(ek, ()).is_none());
+    }
+    // edges are grouped by the BFS level of the node they point from
+    let levels: Vec<_> = order
+        .iter()
+        .map(|&(_, from, _)| *sg.get_node(from).unwrap())
+        .collect();
+    assert!(levels.windows(2).all(|w| w[0] <= w[1]));
+    assert_eq!(levels, vec![0, 0, 1, 1, 1, 2, 2]);
+}
+
+#[test]
+fn for_each_edge_bfs_undirected_once() {
+    let mut sg = UnSlotGraph::new_undirected();
+    let a = sg.insert_node(0);
+    let b = sg.insert_node(1);
+    let c = sg.insert_node(1);
+    let d = sg.insert_node(2);
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(c, a, ());
+    sg.insert_edge(b, c, ());
+    sg.insert_edge(d, b, ());
+    sg.insert_edge(d, d, ());
+
+    let mut order = Vec::new();
+    sg.for_each_edge_bfs(a, |ek, from, to| order.push((ek, from, to)));
+
+    assert_eq!(order.len(), 5);
+    let mut seen = HashMap::new();
+    for &(ek, from, to) in &order {
+        assert!(seen.insert(ek, ()).is_none());
+        let (x, y) = sg.get_edge_nodes(ek).unwrap();
+        assert!((x, y) == (from, to) || (y, x) == (from, to));
+    }
+    // every edge is reported from the endpoint visited first
+    let levels: Vec<_> = order
+        .iter()
+        .map(|&(_, from, _)| *sg.get_node(from).unwrap())
+        .collect();
+    assert_eq!(levels, vec![0, 0, 1, 1, 2]);
+}
+
+#[test]
+fn bfs_from_seeds() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let d = sg.insert_node(());
+    let e = sg.insert_node(());
+    sg.insert_edge(a, c, ());
+    sg.insert_edge(b, d, ());
+    sg.insert_edge(c, e, ());
+    sg.insert_edge(d, c, ());
+    sg.insert_edge(e, a, ());
+
+    assert_eq!(Bfs::new(&sg, a).collect::<Vec<_>>(), vec![a, c, e]);
+    assert_eq!(
+        Bfs::from_seeds(&sg, [b, a, b]).collect::<Vec<_>>(),
+        vec![b, a, d, c, e]
+    );
+
+    // stopping early leaves the rest undiscovered
+    let mut bfs = Bfs::new(&sg, b);
+    assert_eq!(bfs.next(), Some(b));
+    assert!(bfs.is_discovered(d));
+    assert!(!bfs.is_discovered(c));
+
+    sg.remove_node(b);
+    assert_eq!(Bfs::new(&sg, b).next(), None);
+
+    let mut ug = UnSlotGraph::new_undirected();
+    let x = ug.insert_node(());
+    let y = ug.insert_node(());
+    ug.insert_edge(x, y, ());
+    assert_eq!(Bfs::new(&ug, y).collect::<Vec<_>>(), vec![y, x]);
+}
+
+#[test]
+fn dfs_orders() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node(());
+    let b = sg.insert_node(());
+    let c = sg.insert_node(());
+    let d = sg.insert_node(());
+    let e = sg.insert_node(());
+    sg.insert_edge(a, b, ());
+    sg.insert_edge(a, c, ());
+    sg.insert_edge(b, d, ());
+    sg.insert_edge(c, d, ());
+    sg.insert_edge(d, a, ());
+    sg.insert_edge(e, c, ());
+
+    assert_eq!(Dfs::new(&sg, a).collect::<Vec<_>>(), vec![a, b, d, c]);
+    assert_eq!(
+        DfsPostOrder::new(&sg, a).collect::<Vec<_>>(),
+        vec![d, b, c, a]
+    );
+    assert_eq!(
+        Dfs::from_seeds(&sg, [c, e]).collect::<Vec<_>>(),
+        vec![c, d, a, b, e]
+    );
+    assert_eq!(
+        DfsPostOrder::from_seeds(&sg, [c, e]).collect::<Vec<_>>(),
+        vec![b, a, d, c, e]
+    );
+
+    let mut post = DfsPostOrder::new(&sg, a);
+    assert_eq!(post.next(), Some(d));
+    assert!(post.is_discovered(b));
+    assert!(!post.is_discovered(c));
+}
+
+#[test]
+fn dfs_post_order_reversed_is_topological() {
+    let mut sg = SlotGraph::new();
+    let nodes: Vec<_> = (0..6).map(|i| sg.insert_node(i)).collect();
+    for (from, to) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (5, 4)] {
+        sg.insert_edge(nodes[from], nodes[to], ());
+    }
+
+    let mut order: Vec<_> = DfsPostOrder::from_seeds(&sg, nodes.iter().copied()).collect();
+    order.reverse();
+    assert_eq!(order.len(), 6);
+    let position: HashMap<_, _> = order.iter().enumerate().map(|(i, &nk)| (nk, i)).collect();
+    for (_, from, to, _) in sg.iter_edges_full() {
+        assert!(position[&from] < position[&to]);
+    }
+}
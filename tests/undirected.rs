@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+
+use slotgraph::{SlotGraph, UnSlotGraph};
+
+#[test]
+fn undirected_neighbors() {
+    let mut sg = SlotGraph::new();
+    let n = sg.insert_node(());
+    let pred = sg.insert_node(());
+    let succ = sg.insert_node(());
+    let other = sg.insert_node(());
+    let e1 = sg.insert_edge(pred, n, ());
+    let e2 = sg.insert_edge(n, succ, ());
+    let e3 = sg.insert_edge(succ, n, ());
+    sg.insert_edge(other, pred, ());
+
+    let ug = sg.as_undirected();
+    let neighbors: Vec<_> = ug.neighbors(n).collect();
+    assert_eq!(neighbors.len(), 2);
+    assert_eq!(
+        neighbors.into_iter().collect::<HashSet<_>>(),
+        HashSet::from([pred, succ])
+    );
+    assert_eq!(ug.degree(n), Some(3));
+    assert_eq!(
+        ug.edges(n).collect::<HashSet<_>>(),
+        HashSet::from([e1, e2, e3])
+    );
+    assert_eq!(ug.neighbors(other).collect::<Vec<_>>(), vec![pred]);
+}
+
+#[test]
+fn undirected_self_loop() {
+    let mut sg = SlotGraph::new();
+    let n = sg.insert_node(());
+    sg.insert_edge(n, n, ());
+
+    let ug = sg.as_undirected();
+    assert_eq!(ug.neighbors(n).collect::<Vec<_>>(), vec![n]);
+    assert_eq!(ug.degree(n), Some(2));
+    assert_eq!(ug.edges(n).count(), 1);
+}
+
+#[test]
+fn un_slot_graph() {
+    let mut ug = UnSlotGraph::new_undirected();
+    let a = ug.insert_node("a");
+    let b = ug.insert_node("b");
+    let c = ug.insert_node("c");
+    let ab = ug.insert_edge(a, b, 1);
+    let bc = ug.insert_edge(b, c, 2);
+
+    assert!(!ug.is_directed());
+    assert_eq!(ug.neighbors(b).collect::<Vec<_>>(), vec![a, c]);
+    assert_eq!(ug.successors(b).collect::<Vec<_>>(), vec![a, c]);
+    assert_eq!(ug.predecessors(a).collect::<Vec<_>>(), vec![b]);
+    assert_eq!(ug.edges_of(c).collect::<Vec<_>>(), vec![bc]);
+    assert_eq!(ug.out_degree(b), Some(2));
+    assert_eq!(ug.edge_len(), 2);
+
+    assert_eq!(ug.shortest_path(c, a), Some(vec![c, b, a]));
+    assert_eq!(ug.shortest_path_edges(c, a), Some(vec![bc, ab]));
+    assert!(ug.has_path(c, a));
+    assert_eq!(ug.component_sizes(), vec![3]);
+
+    assert_eq!(ug.remove_node(b), Some("b"));
+    assert_eq!(ug.get_edge(ab), None);
+    assert_eq!(ug.edge_len(), 0);
+    assert_eq!(ug.neighbors(a).count(), 0);
+    assert_eq!(ug.neighbors(c).count(), 0);
+}
+
+#[test]
+fn un_slot_graph_merge() {
+    let mut ug = UnSlotGraph::new_undirected();
+    let a = ug.insert_node(1);
+    let b = ug.insert_node(2);
+    let c = ug.insert_node(3);
+    let ab = ug.insert_edge(a, b, ());
+    let cb = ug.insert_edge(c, b, ());
+
+    assert_eq!(ug.merge_nodes(a, b, |x, y| x + y), Some(a));
+    assert_eq!(ug.get_edge_nodes(ab), Some((a, a)));
+    assert_eq!(ug.get_edge_nodes(cb), Some((c, a)));
+    assert_eq!(ug.edges_of(a).collect::<Vec<_>>(), vec![ab, cb]);
+    assert_eq!(ug.neighbors(a).collect::<Vec<_>>(), vec![a, c]);
+    assert_eq!(ug.neighbors(c).collect::<Vec<_>>(), vec![a]);
+
+    ug.remove_edge(ab);
+    assert_eq!(ug.edges_of(a).collect::<Vec<_>>(), vec![cb]);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "petgraph")]
+
+use petgraph::{
+    algo::dijkstra,
+    visit::{Dfs, EdgeRef, IntoEdgeReferences, IntoEdges, IntoNodeIdentifiers, NodeCount},
+};
+use slotgraph::{SlotGraph, UnSlotGraph};
+
+#[test]
+fn petgraph_algorithms() {
+    let mut sg = SlotGraph::new();
+    let a = sg.insert_node("a");
+    let b = sg.insert_node("b");
+    let c = sg.insert_node("c");
+    let d = sg.insert_node("d");
+    sg.insert_edge(a, b, 1);
+    sg.insert_edge(b, c, 2);
+    sg.insert_edge(a, c, 5);
+    sg.insert_edge(d, a, 1);
+
+    assert_eq!(sg.node_count(), 4);
+    assert_eq!((&sg).node_identifiers().count(), 4);
+    assert_eq!((&sg).edge_references().count(), 4);
+
+    let costs = dijkstra(&sg, a, None, |e| *e.weight());
+    assert_eq!(costs.len(), 3);
+    assert_eq!(costs[&c], 3);
+    assert!(!costs.contains_key(&d));
+
+    let mut dfs = Dfs::new(&sg, a);
+    let mut visited = Vec::new();
+    while let Some(nk) = dfs.next(&sg) {
+        visited.push(nk);
+    }
+    assert_eq!(visited.len(), 3);
+    assert_eq!(visited[0], a);
+}
+
+#[test]
+fn undirected_edges_point_away() {
+    let mut ug = UnSlotGraph::new_undirected();
+    let a = ug.insert_node(());
+    let b = ug.insert_node(());
+    let e = ug.insert_edge(a, b, 7);
+
+    let edge = (&ug).edges(b).next().unwrap();
+    assert_eq!((edge.id(), edge.source(), edge.target()), (e, b, a));
+    assert_eq!(dijkstra(&ug, b, None, |e| *e.weight())[&a], 7);
+}